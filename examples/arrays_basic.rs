@@ -11,8 +11,8 @@ fn main() {
     let a = vector![1, 2, 3, 4];
     let b = vector![5, 6, 7, 8];
 
-    let sum = a.clone() + b.clone();
-    let prod = a.clone() * b.clone();
+    let sum = a + b;
+    let prod = a * b;
 
     let scaled = a.scalar_mul(3);
     let shifted = a.scalar_add(10);
@@ -0,0 +1,86 @@
+//! Element-wise activation functions for float vectors and matrices.
+//!
+//! All functions return new values and leave the input untouched.
+//! `softmax` subtracts the maximum before exponentiating so large inputs do
+//! not overflow, and `sigmoid` only exponentiates non-positive values.
+
+use crate::tensor::{AllowedFloatTypes, Matrix, Vector};
+
+// e^-|x| is at most one, so the denominator cannot overflow even for types
+// without infinities
+fn sigmoid<T: AllowedFloatTypes>(x: T) -> T {
+    if x >= T::zero() {
+        T::one() / (T::one() + (T::zero() - x).exp())
+    } else {
+        let e = x.exp();
+        e / (T::one() + e)
+    }
+}
+
+fn relu<T: AllowedFloatTypes>(x: T) -> T {
+    if x > T::zero() {
+        x
+    } else {
+        T::zero()
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Vector<T, N> {
+    /// Normalized exponentials `e^x_i / sum_j e^x_j`.
+    pub fn softmax(&self) -> Self {
+        if N == 0 {
+            return *self;
+        }
+        let mut max = self[0];
+        for &x in self.iter() {
+            if x > max {
+                max = x;
+            }
+        }
+
+        let exps = self.map(|x| (x - max).exp());
+        let sum = exps.iter().fold(T::zero(), |acc, &x| acc + x);
+        exps.map(|x| x / sum)
+    }
+
+    /// Logistic function `1 / (1 + e^-x)` applied element-wise.
+    pub fn sigmoid(&self) -> Self {
+        self.map(sigmoid)
+    }
+
+    /// Rectified linear unit `max(x, 0)` applied element-wise.
+    pub fn relu(&self) -> Self {
+        self.map(relu)
+    }
+
+    /// Hyperbolic tangent applied element-wise.
+    pub fn tanh(&self) -> Self {
+        self.map(T::tanh)
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Applies [`Vector::softmax`] to every row.
+    pub fn softmax_rows(&self) -> Self {
+        let mut result_data = Vec::with_capacity(self.rows);
+        for i in 0..self.rows {
+            result_data.push(self[i].softmax());
+        }
+        Matrix::from_vectors(result_data)
+    }
+
+    /// Logistic function applied element-wise.
+    pub fn sigmoid(&self) -> Self {
+        self.map(sigmoid)
+    }
+
+    /// Rectified linear unit applied element-wise.
+    pub fn relu(&self) -> Self {
+        self.map(relu)
+    }
+
+    /// Hyperbolic tangent applied element-wise.
+    pub fn tanh(&self) -> Self {
+        self.map(T::tanh)
+    }
+}
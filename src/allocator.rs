@@ -0,0 +1,457 @@
+//! Matrices and tensors stored in a caller-chosen allocator.
+//!
+//! Requires the `allocator` feature. The allocator API is still unstable in
+//! `std`, so this uses the stable polyfill from `allocator-api2`; arena and
+//! embedded allocators implementing its [`Allocator`] trait (e.g. `bumpalo`
+//! with its `allocator-api2` feature) can be passed directly.
+//!
+//! [`AllocMatrix`] and [`AllocTensor`] keep all elements in a single
+//! row-major allocation. The element-wise operations work in place, and
+//! products and `map` allocate their result from a clone of the operand's
+//! allocator (`&A` for a borrowed arena), so no intermediate goes through the
+//! global allocator. Products accumulate with Kahan summation like `*` on
+//! [`Matrix`]. For everything else, convert with [`AllocMatrix::to_matrix`]
+//! and [`AllocTensor::to_tensor`].
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+pub use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::vec::Vec;
+use std::ops::{Index, IndexMut};
+
+fn zeros_in<T: AllowedNumericTypes, A: Allocator>(len: usize, alloc: A) -> Vec<T, A> {
+    let mut data = Vec::with_capacity_in(len, alloc);
+    data.resize(len, T::zero());
+    data
+}
+
+fn copy_in<T: Copy, A: Allocator>(src: &[T], alloc: A) -> Vec<T, A> {
+    let mut data = Vec::with_capacity_in(src.len(), alloc);
+    data.extend_from_slice(src);
+    data
+}
+
+fn check_shape(
+    expected: (usize, usize, usize),
+    found: (usize, usize, usize),
+    operation: &str,
+) -> Result<(), TensorError> {
+    if expected != found {
+        let show = |(d, r, c): (usize, usize, usize)| match d {
+            0 => format!("{}x{}", r, c),
+            _ => format!("{}x{}x{}", d, r, c),
+        };
+        return Err(TensorError::DimensionMismatch {
+            expected: show(expected),
+            found: show(found),
+            operation: operation.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn zip_in_place<T: Copy>(lhs: &mut [T], rhs: &[T], f: impl Fn(T, T) -> T) {
+    for (x, &y) in lhs.iter_mut().zip(rhs) {
+        *x = f(*x, y);
+    }
+}
+
+// `out = lhs * rhs` for a row-major `lhs` with `n` columns and `rhs` with
+// `m`. Each row accumulates in increasing `k` with the Kahan compensations
+// in `comp`, which holds `m` elements
+fn mat_mul_slices<T: AllowedNumericTypes>(
+    lhs: &[T],
+    n: usize,
+    rhs: &[T],
+    m: usize,
+    out: &mut [T],
+    comp: &mut [T],
+) {
+    out.fill(T::zero());
+    if n == 0 || m == 0 {
+        return;
+    }
+    for (row, out_row) in lhs.chunks_exact(n).zip(out.chunks_exact_mut(m)) {
+        comp.fill(T::zero());
+        for (&x, rhs_row) in row.iter().zip(rhs.chunks_exact(m)) {
+            T::kahan_axpy_slices(out_row, comp, x, rhs_row);
+        }
+    }
+}
+
+/// `rows x N` matrix whose storage lives in the allocator `A`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocMatrix<T: AllowedNumericTypes, const N: usize, A: Allocator = Global> {
+    data: Vec<T, A>,
+    rows: usize,
+}
+
+impl<T: AllowedNumericTypes, const N: usize> AllocMatrix<T, N> {
+    /// A zero-filled matrix in the global allocator.
+    pub fn new(rows: usize) -> Self {
+        Self::new_in(rows, Global)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator> AllocMatrix<T, N, A> {
+    /// A zero-filled `rows x N` matrix allocated once from `alloc`.
+    pub fn new_in(rows: usize, alloc: A) -> Self {
+        AllocMatrix {
+            data: zeros_in(rows * N, alloc),
+            rows,
+        }
+    }
+
+    /// Copies `m` into storage allocated from `alloc`.
+    pub fn from_matrix_in(m: &Matrix<T, N>, alloc: A) -> Self {
+        AllocMatrix {
+            data: copy_in(m.as_slice(), alloc),
+            rows: m.rows,
+        }
+    }
+
+    /// Copies into an ordinary [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<T, N> {
+        Matrix::from_vectors(
+            (0..self.rows)
+                .map(|r| Vector::from_slice(&self[r]).expect("rows have N elements"))
+                .collect(),
+        )
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, N)
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn get(&self, row: usize) -> Result<&[T], TensorError> {
+        if row >= self.rows {
+            return Err(TensorError::OutOfBounds {
+                index: row.to_string(),
+                size: self.rows.to_string(),
+            });
+        }
+        Ok(&self.data[row * N..(row + 1) * N])
+    }
+
+    fn check_same_shape(
+        &self,
+        other: &AllocMatrix<T, N, impl Allocator>,
+        operation: &str,
+    ) -> Result<(), TensorError> {
+        check_shape((0, self.rows, N), (0, other.rows, N), operation)
+    }
+
+    pub fn add_in_place(
+        &mut self,
+        other: &AllocMatrix<T, N, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, "AllocMatrix add_in_place")?;
+        zip_in_place(&mut self.data, &other.data, |a, b| a + b);
+        Ok(())
+    }
+
+    pub fn sub_in_place(
+        &mut self,
+        other: &AllocMatrix<T, N, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, "AllocMatrix sub_in_place")?;
+        zip_in_place(&mut self.data, &other.data, |a, b| a - b);
+        Ok(())
+    }
+
+    pub fn hadamard_in_place(
+        &mut self,
+        other: &AllocMatrix<T, N, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, "AllocMatrix hadamard_in_place")?;
+        zip_in_place(&mut self.data, &other.data, |a, b| a * b);
+        Ok(())
+    }
+
+    pub fn scale_in_place(&mut self, scalar: T) {
+        self.data.iter_mut().for_each(|x| *x = *x * scalar);
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Matrix product written into `out`, which must already have one row
+    /// per row of `self`. The Kahan compensations are allocated from `A`.
+    pub fn mat_mul_into<const M: usize>(
+        &self,
+        rhs: &AllocMatrix<T, M, impl Allocator>,
+        out: &mut AllocMatrix<T, M, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        check_shape((0, N, M), (0, rhs.rows, M), "AllocMatrix mat_mul_into")?;
+        check_shape(
+            (0, self.rows, M),
+            (0, out.rows, M),
+            "AllocMatrix mat_mul_into",
+        )?;
+        let mut comp = zeros_in(M, self.allocator());
+        mat_mul_slices(&self.data, N, &rhs.data, M, &mut out.data, &mut comp);
+        Ok(())
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator + Clone> AllocMatrix<T, N, A> {
+    /// Applies `f` to every element, allocating the result from `A`.
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> AllocMatrix<U, N, A> {
+        let mut data = Vec::with_capacity_in(self.data.len(), self.allocator().clone());
+        data.extend(self.data.iter().copied().map(f));
+        AllocMatrix {
+            data,
+            rows: self.rows,
+        }
+    }
+
+    /// Matrix product allocated from `A`; `rhs` must have `N` rows.
+    pub fn mat_mul<const M: usize>(
+        &self,
+        rhs: &AllocMatrix<T, M, impl Allocator>,
+    ) -> Result<AllocMatrix<T, M, A>, TensorError> {
+        let mut out = AllocMatrix::new_in(self.rows, self.allocator().clone());
+        self.mat_mul_into(rhs, &mut out)?;
+        Ok(out)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator> Index<usize> for AllocMatrix<T, N, A> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &Self::Output {
+        assert!(
+            row < self.rows,
+            "row {} out of bounds for {} rows",
+            row,
+            self.rows
+        );
+        &self.data[row * N..(row + 1) * N]
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator> IndexMut<usize>
+    for AllocMatrix<T, N, A>
+{
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        assert!(
+            row < self.rows,
+            "row {} out of bounds for {} rows",
+            row,
+            self.rows
+        );
+        &mut self.data[row * N..(row + 1) * N]
+    }
+}
+
+/// `depths x rows x N` tensor whose storage lives in the allocator `A`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocTensor<T: AllowedNumericTypes, const N: usize, A: Allocator = Global> {
+    data: Vec<T, A>,
+    depths: usize,
+    rows: usize,
+}
+
+impl<T: AllowedNumericTypes, const N: usize> AllocTensor<T, N> {
+    /// A zero-filled tensor in the global allocator.
+    pub fn new(depths: usize, rows: usize) -> Self {
+        Self::new_in(depths, rows, Global)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator> AllocTensor<T, N, A> {
+    /// A zero-filled `depths x rows x N` tensor allocated once from `alloc`.
+    pub fn new_in(depths: usize, rows: usize, alloc: A) -> Self {
+        AllocTensor {
+            data: zeros_in(depths * rows * N, alloc),
+            depths,
+            rows,
+        }
+    }
+
+    /// Copies `t` into storage allocated from `alloc`.
+    pub fn from_tensor_in(t: &Tensor<T, N>, alloc: A) -> Self {
+        let (depths, rows, _) = t.shape();
+        AllocTensor {
+            data: copy_in(t.as_slice(), alloc),
+            depths,
+            rows,
+        }
+    }
+
+    /// Copies into an ordinary [`Tensor`].
+    pub fn to_tensor(&self) -> Tensor<T, N> {
+        Tensor::from_vec(self.depths, self.rows, self.data.to_vec())
+            .expect("depths x rows x N elements")
+    }
+
+    pub fn shape(&self) -> (usize, usize, usize) {
+        (self.depths, self.rows, N)
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    /// Elements in depth, row, column order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Depth `depth` as a row-major `rows x N` slice.
+    pub fn depth(&self, depth: usize) -> Result<&[T], TensorError> {
+        if depth >= self.depths {
+            return Err(TensorError::OutOfBounds {
+                index: depth.to_string(),
+                size: self.depths.to_string(),
+            });
+        }
+        let len = self.rows * N;
+        Ok(&self.data[depth * len..(depth + 1) * len])
+    }
+
+    fn check_same_shape(
+        &self,
+        other: &AllocTensor<T, N, impl Allocator>,
+        operation: &str,
+    ) -> Result<(), TensorError> {
+        check_shape(
+            (self.depths, self.rows, N),
+            (other.depths, other.rows, N),
+            operation,
+        )
+    }
+
+    pub fn add_in_place(
+        &mut self,
+        other: &AllocTensor<T, N, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, "AllocTensor add_in_place")?;
+        zip_in_place(&mut self.data, &other.data, |a, b| a + b);
+        Ok(())
+    }
+
+    pub fn sub_in_place(
+        &mut self,
+        other: &AllocTensor<T, N, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, "AllocTensor sub_in_place")?;
+        zip_in_place(&mut self.data, &other.data, |a, b| a - b);
+        Ok(())
+    }
+
+    pub fn hadamard_in_place(
+        &mut self,
+        other: &AllocTensor<T, N, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, "AllocTensor hadamard_in_place")?;
+        zip_in_place(&mut self.data, &other.data, |a, b| a * b);
+        Ok(())
+    }
+
+    pub fn scale_in_place(&mut self, scalar: T) {
+        self.data.iter_mut().for_each(|x| *x = *x * scalar);
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Multiplies every depth by the shared matrix `rhs`, writing into `out`,
+    /// which must already be `depths x rows x M`.
+    pub fn batched_mat_mul_into<const M: usize>(
+        &self,
+        rhs: &AllocMatrix<T, M, impl Allocator>,
+        out: &mut AllocTensor<T, M, impl Allocator>,
+    ) -> Result<(), TensorError> {
+        let operation = "AllocTensor batched_mat_mul_into";
+        check_shape((0, N, M), (0, rhs.rows, M), operation)?;
+        check_shape(
+            (self.depths, self.rows, M),
+            (out.depths, out.rows, M),
+            operation,
+        )?;
+        let mut comp = zeros_in(M, self.allocator());
+        // The depths are stacked row-major, so they multiply as one tall matrix
+        mat_mul_slices(&self.data, N, &rhs.data, M, &mut out.data, &mut comp);
+        Ok(())
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator + Clone> AllocTensor<T, N, A> {
+    /// Applies `f` to every element, allocating the result from `A`.
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> AllocTensor<U, N, A> {
+        let mut data = Vec::with_capacity_in(self.data.len(), self.allocator().clone());
+        data.extend(self.data.iter().copied().map(f));
+        AllocTensor {
+            data,
+            depths: self.depths,
+            rows: self.rows,
+        }
+    }
+
+    /// Every depth times the shared matrix `rhs`, allocated from `A`.
+    pub fn batched_mat_mul<const M: usize>(
+        &self,
+        rhs: &AllocMatrix<T, M, impl Allocator>,
+    ) -> Result<AllocTensor<T, M, A>, TensorError> {
+        let mut out = AllocTensor::new_in(self.depths, self.rows, self.allocator().clone());
+        self.batched_mat_mul_into(rhs, &mut out)?;
+        Ok(out)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator> Index<(usize, usize)>
+    for AllocTensor<T, N, A>
+{
+    type Output = [T];
+
+    /// Row `row` of depth `depth`.
+    fn index(&self, (depth, row): (usize, usize)) -> &Self::Output {
+        assert!(
+            depth < self.depths && row < self.rows,
+            "index ({}, {}) out of bounds for {}x{}",
+            depth,
+            row,
+            self.depths,
+            self.rows
+        );
+        let start = (depth * self.rows + row) * N;
+        &self.data[start..start + N]
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, A: Allocator> IndexMut<(usize, usize)>
+    for AllocTensor<T, N, A>
+{
+    fn index_mut(&mut self, (depth, row): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            depth < self.depths && row < self.rows,
+            "index ({}, {}) out of bounds for {}x{}",
+            depth,
+            row,
+            self.depths,
+            self.rows
+        );
+        let start = (depth * self.rows + row) * N;
+        &mut self.data[start..start + N]
+    }
+}
@@ -1,49 +1,157 @@
+use crate::conv::conv_output_len;
 use crate::error::TensorError;
-use crate::tensor::{AllowedNumericTypes, Matrix};
+use crate::tensor::{AllowedNumericTypes, CastFrom, Matrix, Tensor, Vector};
+use crate::workspace::Workspace;
 use std::ops::Mul;
 
-fn mat_mul_impl<T: AllowedNumericTypes, const N: usize, const M: usize>(
-    lhs: &Matrix<T, N>,
-    rhs: &Matrix<T, M>,
-) -> Result<Matrix<T, M>, TensorError> {
-    // Shape check: (lhs.rows x N) * (rhs.rows x M) where rhs.rows must equal N
-    if rhs.shape().0 != N {
+// Shape check: (lhs.rows x N) * (rhs.rows x M) where rhs.rows must equal N
+fn check_mat_mul_shape<const N: usize, const M: usize>(
+    rhs_rows: usize,
+    operation: &str,
+) -> Result<(), TensorError> {
+    if rhs_rows != N {
         return Err(TensorError::DimensionMismatch {
             expected: format!("{}x{}", N, M),
-            found: format!("{}x{}", rhs.shape().0, M),
-            operation: "Matrix multiplication".to_string(),
+            found: format!("{}x{}", rhs_rows, M),
+            operation: operation.to_string(),
         });
     }
+    Ok(())
+}
+
+// Output rows per block, and the column and inner-dimension extents of one
+// tile; a tile of `rhs` (TILE_K x TILE_COLS) is reused by every row of the
+// block while it is still in cache
+const TILE_ROWS: usize = 32;
+const TILE_K: usize = 64;
+const TILE_COLS: usize = 256;
 
-    let (lhs_rows, _) = lhs.shape();
-    let mut result: Matrix<T, M> = Matrix::new(lhs_rows);
-
-    for i in 0..lhs_rows {
-        for j in 0..M {
-            // Kahan summation for improved numerical stability
-            let mut sum = T::zero();
-            let mut c = T::zero();
-            for k in 0..N {
-                let product = lhs[i][k] * rhs[k][j];
-                let y = product - c;
-                let t = sum + y;
-                c = (t - sum) - y;
-                sum = t;
+// Inner and output widths up to which products are computed directly
+const SMALL_PRODUCT: usize = 4;
+
+// Multiply-adds from which products and batched mat-vecs are split across
+// threads with the `parallel` feature
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_WORK: usize = 1 << 16;
+
+// Computes the output rows `out = lhs * rhs` for one block of rows, tile by
+// tile. Every element accumulates its products in increasing `k` with Kahan
+// summation, so the result does not depend on the tile sizes. `comp` holds
+// the `lhs.len() x M` compensations.
+fn mat_mul_block<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    lhs: &[Vector<T, N>],
+    rhs: &Matrix<T, M>,
+    out: &mut [Vector<T, M>],
+    comp: &mut [T],
+) {
+    comp.fill(T::zero());
+    for j0 in (0..M).step_by(TILE_COLS) {
+        let j1 = (j0 + TILE_COLS).min(M);
+        for k0 in (0..N).step_by(TILE_K) {
+            let k1 = (k0 + TILE_K).min(N);
+            let rhs_rows = &rhs.as_rows()[k0..k1];
+            let comp_rows = comp.chunks_mut(M.max(1));
+            for ((row, out_row), comp_row) in lhs.iter().zip(out.iter_mut()).zip(comp_rows) {
+                for (&x, rhs_row) in row.data[k0..k1].iter().zip(rhs_rows) {
+                    T::kahan_axpy_slices(
+                        &mut out_row.data[j0..j1],
+                        &mut comp_row[j0..j1],
+                        x,
+                        &rhs_row.data[j0..j1],
+                    );
+                }
             }
-            result[i][j] = sum;
         }
     }
+}
 
+fn mat_mul_impl<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    lhs: &Matrix<T, N>,
+    rhs: &Matrix<T, M>,
+    workspace: &mut Workspace<T>,
+) -> Result<Matrix<T, M>, TensorError> {
+    check_mat_mul_shape::<N, M>(rhs.shape().0, "Matrix multiplication")?;
+    #[cfg(feature = "profiling")]
+    crate::profile::record("matmul", 2 * lhs.rows * N * M, 1);
+
+    // Small products write each element once; the tiled kernel accumulates
+    // into a zeroed output
+    if N <= SMALL_PRODUCT && M <= SMALL_PRODUCT {
+        return Ok(Matrix::from_fn(lhs.rows, |i, j| {
+            small_product_element(&lhs[i], rhs, j)
+        }));
+    }
+    let mut result: Matrix<T, M> = Matrix::new(lhs.rows);
+    mat_mul_rows(lhs, rhs, &mut result, workspace);
     Ok(result)
 }
 
+// Element `j` of `row * rhs`, summed directly
+fn small_product_element<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    row: &Vector<T, N>,
+    rhs: &Matrix<T, M>,
+    j: usize,
+) -> T {
+    row.data
+        .iter()
+        .zip(rhs.as_rows())
+        .fold(T::zero(), |acc, (&a, b)| acc + a * b.data[j])
+}
+
+// Accumulates `lhs * rhs` into the zeroed, correctly shaped `out`, taking
+// the Kahan compensations from `workspace`
+fn mat_mul_rows<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    lhs: &Matrix<T, N>,
+    rhs: &Matrix<T, M>,
+    out: &mut Matrix<T, M>,
+    workspace: &mut Workspace<T>,
+) {
+    // Products of at most 4x4 blocks skip the tiling and the Kahan
+    // correction, whose bookkeeping costs more than the few terms it covers
+    if N <= SMALL_PRODUCT && M <= SMALL_PRODUCT {
+        for (out_row, row) in out.as_rows_mut().iter_mut().zip(lhs.as_rows()) {
+            for (j, x) in out_row.data.iter_mut().enumerate() {
+                *x = small_product_element(row, rhs, j);
+            }
+        }
+        return;
+    }
+    // Blocks of output rows are independent, so the parallel product matches
+    // the serial one exactly
+    #[cfg(feature = "parallel")]
+    if lhs.rows * N * M >= PARALLEL_MIN_WORK {
+        use rayon::prelude::*;
+
+        out.as_rows_mut()
+            .par_chunks_mut(TILE_ROWS)
+            .zip(lhs.as_rows().par_chunks(TILE_ROWS))
+            .zip(
+                workspace
+                    .scratch(lhs.rows * M)
+                    .par_chunks_mut(TILE_ROWS * M),
+            )
+            .for_each(|((out_block, lhs_block), comp)| {
+                mat_mul_block(lhs_block, rhs, out_block, comp)
+            });
+        return;
+    }
+    let comp = workspace.scratch(lhs.rows.min(TILE_ROWS) * M);
+    for (lhs_block, out_block) in lhs
+        .as_rows()
+        .chunks(TILE_ROWS)
+        .zip(out.as_rows_mut().chunks_mut(TILE_ROWS))
+    {
+        mat_mul_block(lhs_block, rhs, out_block, &mut comp[..lhs_block.len() * M]);
+    }
+}
+
 impl<'b, T: AllowedNumericTypes, const N: usize, const M: usize> Mul<&'b Matrix<T, M>>
     for &Matrix<T, N>
 {
     type Output = Result<Matrix<T, M>, TensorError>;
 
     fn mul(self, rhs: &'b Matrix<T, M>) -> Self::Output {
-        mat_mul_impl(self, rhs)
+        mat_mul_impl(self, rhs, &mut Workspace::new())
     }
 }
 
@@ -51,7 +159,7 @@ impl<T: AllowedNumericTypes, const N: usize, const M: usize> Mul<Matrix<T, M>> f
     type Output = Result<Matrix<T, M>, TensorError>;
 
     fn mul(self, rhs: Matrix<T, M>) -> Self::Output {
-        mat_mul_impl(self, &rhs)
+        mat_mul_impl(self, &rhs, &mut Workspace::new())
     }
 }
 
@@ -61,7 +169,7 @@ impl<'b, T: AllowedNumericTypes, const N: usize, const M: usize> Mul<&'b Matrix<
     type Output = Result<Matrix<T, M>, TensorError>;
 
     fn mul(self, rhs: &'b Matrix<T, M>) -> Self::Output {
-        mat_mul_impl(&self, rhs)
+        mat_mul_impl(&self, rhs, &mut Workspace::new())
     }
 }
 
@@ -69,6 +177,299 @@ impl<T: AllowedNumericTypes, const N: usize, const M: usize> Mul<Matrix<T, M>> f
     type Output = Result<Matrix<T, M>, TensorError>;
 
     fn mul(self, rhs: Matrix<T, M>) -> Self::Output {
-        mat_mul_impl(&self, &rhs)
+        mat_mul_impl(&self, &rhs, &mut Workspace::new())
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    // Accumulates the product in `A`, widening each element as it is read
+    fn widened_product<A, const M: usize>(
+        &self,
+        rhs: &Matrix<T, M>,
+        operation: &str,
+    ) -> Result<Matrix<A, M>, TensorError>
+    where
+        A: AllowedNumericTypes + From<T>,
+    {
+        check_mat_mul_shape::<N, M>(rhs.rows, operation)?;
+        let mut result: Matrix<A, M> = Matrix::new(self.rows);
+        for (out, row) in result.as_rows_mut().iter_mut().zip(self.as_rows()) {
+            for (&x, rhs_row) in row.iter().zip(rhs.as_rows()) {
+                let x = A::from(x);
+                for (acc, &y) in out.data.iter_mut().zip(rhs_row.iter()) {
+                    *acc = *acc + x * A::from(y);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Matrix product with every element widened to `A` before multiplying,
+    /// so `Matrix<i8, N> x Matrix<i8, M>` can accumulate in `i32` without
+    /// overflow or converting the inputs up front.
+    pub fn mat_mul_widening<A, const M: usize>(
+        &self,
+        rhs: &Matrix<T, M>,
+    ) -> Result<Matrix<A, M>, TensorError>
+    where
+        A: AllowedNumericTypes + From<T>,
+    {
+        self.widened_product(rhs, "Matrix widening multiplication")
+    }
+
+    /// Matrix product accumulated in the wider type `A` and converted back to
+    /// `T` with [`CastFrom`], e.g. `f32` storage with `f64` accumulation.
+    /// Integer results are exact when they fit and saturate at `T`'s bounds
+    /// otherwise.
+    ///
+    /// Plain summation in `f64` is usually more accurate for `f32` inputs
+    /// than the Kahan-compensated `f32` summation of `*`, and cheaper.
+    pub fn mat_mul_accumulate<A, const M: usize>(
+        &self,
+        rhs: &Matrix<T, M>,
+    ) -> Result<Matrix<T, M>, TensorError>
+    where
+        A: AllowedNumericTypes + From<T>,
+        T: CastFrom<A>,
+    {
+        let wide: Matrix<A, M> =
+            self.widened_product(rhs, "Matrix mixed-precision multiplication")?;
+        Ok(wide.map(T::cast_from))
+    }
+
+    /// Matrix product written into `out`, reusing its allocation; `out` must
+    /// already have one row per row of `self`.
+    pub fn mat_mul_into<const M: usize>(
+        &self,
+        rhs: &Matrix<T, M>,
+        out: &mut Matrix<T, M>,
+    ) -> Result<(), TensorError> {
+        check_mat_mul_shape::<N, M>(rhs.rows, "Matrix mat_mul_into")?;
+        if out.rows != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, M),
+                found: format!("{}x{}", out.rows, M),
+                operation: "Matrix mat_mul_into".to_string(),
+            });
+        }
+        #[cfg(feature = "profiling")]
+        crate::profile::record("matmul", 2 * self.rows * N * M, 0);
+        out.as_mut_slice().fill(T::zero());
+        mat_mul_rows(self, rhs, out, &mut Workspace::new());
+        Ok(())
+    }
+
+    /// Matrix product taking the scratch memory of the tiled kernel from
+    /// `workspace`, so repeated products allocate only their result.
+    pub fn mat_mul_in<const M: usize>(
+        &self,
+        rhs: &Matrix<T, M>,
+        workspace: &mut Workspace<T>,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        mat_mul_impl(self, rhs, workspace)
+    }
+
+    /// Gram matrix `self * self^T` of row inner products.
+    ///
+    /// `M` must equal the number of rows.
+    pub fn gram<const M: usize>(&self) -> Result<Matrix<T, M>, TensorError> {
+        let transposed: Matrix<T, M> = self.transpose()?;
+        mat_mul_impl(self, &transposed, &mut Workspace::new())
+    }
+}
+
+// Shared shape checks for im2col/col2im; returns the output grid size
+fn im2col_grid(
+    (depths, rows, cols): (usize, usize, usize),
+    kernel_shape: (usize, usize),
+    stride: usize,
+    padding: usize,
+    patch_len: usize,
+    operation: &str,
+) -> Result<(usize, usize), TensorError> {
+    let (kh, kw) = kernel_shape;
+    let out_rows = conv_output_len(rows, kh, stride, padding, operation)?;
+    let out_cols = conv_output_len(cols, kw, stride, padding, operation)?;
+    if depths * kh * kw != patch_len {
+        return Err(TensorError::DimensionMismatch {
+            expected: format!("{}x{}", out_rows * out_cols, depths * kh * kw),
+            found: format!("{}x{}", out_rows * out_cols, patch_len),
+            operation: operation.to_string(),
+        });
+    }
+    Ok((out_rows, out_cols))
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    /// Unfolds every `kh x kw` patch (across all depths) of the zero-padded
+    /// tensor into one row, so a convolution becomes a single matmul.
+    ///
+    /// Rows are output positions in row-major order; columns are ordered by
+    /// depth, then kernel row, then kernel column, matching a flattened
+    /// `depths x kh x kw` filter. `M` must equal `depths * kh * kw`.
+    pub fn im2col<const M: usize>(
+        &self,
+        kernel_shape: (usize, usize),
+        stride: usize,
+        padding: usize,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        let (kh, kw) = kernel_shape;
+        let (out_rows, out_cols) = im2col_grid(
+            (self.depths, self.rows, N),
+            kernel_shape,
+            stride,
+            padding,
+            M,
+            "im2col",
+        )?;
+
+        let mut result: Matrix<T, M> = Matrix::new(out_rows * out_cols);
+        for (p, row) in result.as_rows_mut().iter_mut().enumerate() {
+            let (r, c) = (p / out_cols, p % out_cols);
+            for (k, x) in row.iter_mut().enumerate() {
+                let (d, i, j) = (k / (kh * kw), k / kw % kh, k % kw);
+                let y = (r * stride + i).checked_sub(padding);
+                let x_col = (c * stride + j).checked_sub(padding);
+                if let (Some(y), Some(x_col)) = (y, x_col) {
+                    if y < self.rows && x_col < N {
+                        *x = self[(d, y, x_col)];
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: AllowedNumericTypes, const M: usize> Matrix<T, M> {
+    /// Inverse of [`Tensor::im2col`]: folds patch rows back into a tensor of
+    /// shape `(depths, rows, N)`, summing elements covered by overlapping
+    /// patches and dropping the padding.
+    pub fn col2im<const N: usize>(
+        &self,
+        shape: (usize, usize),
+        kernel_shape: (usize, usize),
+        stride: usize,
+        padding: usize,
+    ) -> Result<Tensor<T, N>, TensorError> {
+        let (depths, rows) = shape;
+        let (kh, kw) = kernel_shape;
+        let (out_rows, out_cols) = im2col_grid(
+            (depths, rows, N),
+            kernel_shape,
+            stride,
+            padding,
+            M,
+            "col2im",
+        )?;
+        if self.rows != out_rows * out_cols {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", out_rows * out_cols, M),
+                found: format!("{}x{}", self.rows, M),
+                operation: "col2im".to_string(),
+            });
+        }
+
+        let mut result: Tensor<T, N> = Tensor::new(depths, rows);
+        for (p, row) in self.as_rows().iter().enumerate() {
+            let (r, c) = (p / out_cols, p % out_cols);
+            for (k, &x) in row.iter().enumerate() {
+                let (d, i, j) = (k / (kh * kw), k / kw % kh, k % kw);
+                let y = (r * stride + i).checked_sub(padding);
+                let x_col = (c * stride + j).checked_sub(padding);
+                if let (Some(y), Some(x_col)) = (y, x_col) {
+                    if y < rows && x_col < N {
+                        let target = &mut result[(d, y, x_col)];
+                        *target = *target + x;
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+// Row `d` of a batched mat-vec result: the rows `m` of depth `d` applied to `v`
+fn mat_vec_row<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    m: &[Vector<T, N>],
+    v: &Vector<T, N>,
+    out: &mut Vector<T, M>,
+) {
+    for (x, row) in out.iter_mut().zip(m) {
+        *x = row.dot(v);
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    fn check_batched_mat_vec<const M: usize>(&self, operation: &str) -> Result<(), TensorError> {
+        if M != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.depths, self.rows),
+                found: format!("{}x{}", self.depths, M),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_batch_vectors(&self, vecs: &Matrix<T, N>, operation: &str) -> Result<(), TensorError> {
+        if vecs.rows != self.depths {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.depths, N),
+                found: format!("{}x{}", vecs.rows, N),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    // Row `d` of the result is depth `d` applied to `vector_for(d)`; large
+    // batches run one depth per task with the `parallel` feature
+    fn batched_mat_vec<'a, const M: usize>(
+        &self,
+        vector_for: impl Fn(usize) -> &'a Vector<T, N> + Sync,
+        operation: &str,
+    ) -> Result<Matrix<T, M>, TensorError>
+    where
+        T: 'a,
+    {
+        self.check_batched_mat_vec::<M>(operation)?;
+        let mut result: Matrix<T, M> = Matrix::new(self.depths);
+        #[cfg(feature = "parallel")]
+        if self.depths * self.rows * N >= PARALLEL_MIN_WORK {
+            use rayon::prelude::*;
+
+            result
+                .as_rows_mut()
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(d, out)| mat_vec_row(&self[d], vector_for(d), out));
+            return Ok(result);
+        }
+        for (d, out) in result.as_rows_mut().iter_mut().enumerate() {
+            mat_vec_row(&self[d], vector_for(d), out);
+        }
+        Ok(result)
+    }
+
+    /// Multiplies every depth matrix by the same vector.
+    ///
+    /// Row `d` of the `depths x M` result is `self[d] * vec`; `M` must equal
+    /// the number of rows.
+    pub fn batched_mat_vec_mul<const M: usize>(
+        &self,
+        vec: &Vector<T, N>,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        self.batched_mat_vec(|_| vec, "Tensor batched_mat_vec_mul")
+    }
+
+    /// Multiplies depth `d` by row `d` of `vecs`, which must have one row per
+    /// depth. `M` must equal the number of rows.
+    pub fn batched_mat_vec_mul_each<const M: usize>(
+        &self,
+        vecs: &Matrix<T, N>,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        self.check_batch_vectors(vecs, "Tensor batched_mat_vec_mul_each")?;
+        self.batched_mat_vec(|d| &vecs[d], "Tensor batched_mat_vec_mul_each")
     }
 }
@@ -0,0 +1,348 @@
+//! Reverse-mode automatic differentiation over matrices.
+//!
+//! Enabled by the `autodiff` feature. A [`Tape`] records every operation
+//! performed on its [`Var`]s; calling [`Var::backward`] on a result walks
+//! the tape in reverse and returns the gradient of that result's sum with
+//! respect to every recorded value. One backward pass yields all gradients,
+//! which suits functions of many parameters, such as training losses.
+//!
+//! ```
+//! use tensor_algebra_in_rust::autodiff::Tape;
+//! use tensor_algebra_in_rust::matrix;
+//!
+//! let tape = Tape::new();
+//! let w = tape.var(matrix![1.0, 2.0; 3.0, 4.0]);
+//! let x = tape.var(matrix![1.0; -1.0]);
+//! let loss = w.matmul(&x).unwrap().sum();
+//! let grads = loss.backward();
+//! assert_eq!(grads.wrt(&w), matrix![1.0, -1.0; 1.0, -1.0]);
+//! ```
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedFloatTypes, Matrix};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+#[derive(Clone, Copy)]
+enum Op<T> {
+    Leaf,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Hadamard(usize, usize),
+    MatMul(usize, usize),
+    ScalarMul(usize, T),
+    Tanh(usize),
+    Sum(usize),
+}
+
+// A recorded value, stored row-major so one tape can hold every width
+struct Node<T> {
+    rows: usize,
+    cols: usize,
+    value: Vec<T>,
+    op: Op<T>,
+}
+
+/// Records operations on [`Var`]s for a later backward pass.
+pub struct Tape<T> {
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+/// A matrix recorded on a [`Tape`], with `N` columns.
+pub struct Var<'t, T, const N: usize> {
+    tape: &'t Tape<T>,
+    index: usize,
+    _cols: PhantomData<[T; N]>,
+}
+
+impl<T, const N: usize> Clone for Var<'_, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for Var<'_, T, N> {}
+
+/// Gradients from one backward pass, looked up by [`Var`].
+pub struct Gradients<T> {
+    grads: Vec<Vec<T>>,
+}
+
+impl<T: AllowedFloatTypes> Default for Tape<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AllowedFloatTypes> Tape<T> {
+    pub fn new() -> Self {
+        Tape {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Records `value` as an input to differentiate against.
+    pub fn var<const N: usize>(&self, value: Matrix<T, N>) -> Var<'_, T, N> {
+        self.push(value.rows, N, value.data, Op::Leaf)
+    }
+
+    /// Number of values recorded so far.
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push<const N: usize>(
+        &self,
+        rows: usize,
+        cols: usize,
+        value: Vec<T>,
+        op: Op<T>,
+    ) -> Var<'_, T, N> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node {
+            rows,
+            cols,
+            value,
+            op,
+        });
+        Var {
+            tape: self,
+            index: nodes.len() - 1,
+            _cols: PhantomData,
+        }
+    }
+}
+
+// Row-major product of an `r x k` and a `k x m` matrix
+fn matmul<T: AllowedFloatTypes>(a: &[T], b: &[T], r: usize, k: usize, m: usize) -> Vec<T> {
+    let mut out = vec![T::zero(); r * m];
+    for i in 0..r {
+        for p in 0..k {
+            let x = a[i * k + p];
+            for (o, &y) in out[i * m..(i + 1) * m]
+                .iter_mut()
+                .zip(&b[p * m..(p + 1) * m])
+            {
+                *o = *o + x * y;
+            }
+        }
+    }
+    out
+}
+
+// Row-major transpose of an `r x c` matrix
+fn transpose<T: AllowedFloatTypes>(a: &[T], r: usize, c: usize) -> Vec<T> {
+    (0..r * c).map(|i| a[(i % r) * c + i / r]).collect()
+}
+
+impl<'t, T: AllowedFloatTypes, const N: usize> Var<'t, T, N> {
+    /// The recorded value.
+    pub fn value(&self) -> Matrix<T, N> {
+        let nodes = self.tape.nodes.borrow();
+        let node = &nodes[self.index];
+        Matrix::from_vec(node.rows, node.value.clone()).expect("nodes hold rows * N elements")
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.tape.nodes.borrow()[self.index].rows, N)
+    }
+
+    fn elementwise(
+        &self,
+        other: &Self,
+        operation: &str,
+        f: impl Fn(T, T) -> T,
+        op: Op<T>,
+    ) -> Result<Self, TensorError> {
+        let (rows, other_rows) = (self.shape().0, other.shape().0);
+        if rows != other_rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", rows, N),
+                found: format!("{}x{}", other_rows, N),
+                operation: operation.to_string(),
+            });
+        }
+        let value = {
+            let nodes = self.tape.nodes.borrow();
+            let (a, b) = (&nodes[self.index].value, &nodes[other.index].value);
+            a.iter().zip(b).map(|(&x, &y)| f(x, y)).collect()
+        };
+        Ok(self.tape.push(rows, N, value, op))
+    }
+
+    pub fn add(&self, other: &Self) -> Result<Self, TensorError> {
+        self.elementwise(
+            other,
+            "Var addition",
+            |x, y| x + y,
+            Op::Add(self.index, other.index),
+        )
+    }
+
+    pub fn sub(&self, other: &Self) -> Result<Self, TensorError> {
+        self.elementwise(
+            other,
+            "Var subtraction",
+            |x, y| x - y,
+            Op::Sub(self.index, other.index),
+        )
+    }
+
+    /// Element-wise product.
+    pub fn hadamard(&self, other: &Self) -> Result<Self, TensorError> {
+        self.elementwise(
+            other,
+            "Var hadamard product",
+            |x, y| x * y,
+            Op::Hadamard(self.index, other.index),
+        )
+    }
+
+    /// Matrix product; `other` must have `N` rows.
+    pub fn matmul<const M: usize>(
+        &self,
+        other: &Var<'t, T, M>,
+    ) -> Result<Var<'t, T, M>, TensorError> {
+        let (rows, other_rows) = (self.shape().0, other.shape().0);
+        if other_rows != N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", N, M),
+                found: format!("{}x{}", other_rows, M),
+                operation: "Var matrix multiplication".to_string(),
+            });
+        }
+        let value = {
+            let nodes = self.tape.nodes.borrow();
+            matmul(
+                &nodes[self.index].value,
+                &nodes[other.index].value,
+                rows,
+                N,
+                M,
+            )
+        };
+        Ok(self
+            .tape
+            .push(rows, M, value, Op::MatMul(self.index, other.index)))
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Self {
+        let (rows, _) = self.shape();
+        let value = self.tape.nodes.borrow()[self.index]
+            .value
+            .iter()
+            .map(|&x| x * scalar)
+            .collect();
+        self.tape
+            .push(rows, N, value, Op::ScalarMul(self.index, scalar))
+    }
+
+    /// Hyperbolic tangent applied element-wise.
+    pub fn tanh(&self) -> Self {
+        let (rows, _) = self.shape();
+        let value = self.tape.nodes.borrow()[self.index]
+            .value
+            .iter()
+            .map(|&x| x.tanh())
+            .collect();
+        self.tape.push(rows, N, value, Op::Tanh(self.index))
+    }
+
+    /// Sum of all elements, as a `1x1` matrix.
+    pub fn sum(&self) -> Var<'t, T, 1> {
+        let total = self.tape.nodes.borrow()[self.index]
+            .value
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + x);
+        self.tape.push(1, 1, vec![total], Op::Sum(self.index))
+    }
+
+    /// Gradients of the sum of this value with respect to everything
+    /// recorded before it.
+    pub fn backward(&self) -> Gradients<T> {
+        let nodes = self.tape.nodes.borrow();
+        let mut grads: Vec<Vec<T>> = nodes
+            .iter()
+            .map(|n| vec![T::zero(); n.rows * n.cols])
+            .collect();
+        grads[self.index] = vec![T::one(); nodes[self.index].value.len()];
+
+        for i in (0..=self.index).rev() {
+            let node = &nodes[i];
+            let g = std::mem::take(&mut grads[i]);
+            let mut accumulate = |target: usize, delta: &[T]| {
+                for (x, &d) in grads[target].iter_mut().zip(delta) {
+                    *x = *x + d;
+                }
+            };
+            match node.op {
+                Op::Leaf => {}
+                Op::Add(a, b) => {
+                    accumulate(a, &g);
+                    accumulate(b, &g);
+                }
+                Op::Sub(a, b) => {
+                    accumulate(a, &g);
+                    let negated: Vec<T> = g.iter().map(|&x| T::zero() - x).collect();
+                    accumulate(b, &negated);
+                }
+                Op::Hadamard(a, b) => {
+                    let da: Vec<T> = g
+                        .iter()
+                        .zip(&nodes[b].value)
+                        .map(|(&x, &y)| x * y)
+                        .collect();
+                    let db: Vec<T> = g
+                        .iter()
+                        .zip(&nodes[a].value)
+                        .map(|(&x, &y)| x * y)
+                        .collect();
+                    accumulate(a, &da);
+                    accumulate(b, &db);
+                }
+                Op::MatMul(a, b) => {
+                    // C = A B with A: r x k, B: k x m
+                    let (r, k, m) = (nodes[a].rows, nodes[a].cols, node.cols);
+                    let b_t = transpose(&nodes[b].value, k, m);
+                    let a_t = transpose(&nodes[a].value, r, k);
+                    accumulate(a, &matmul(&g, &b_t, r, m, k));
+                    accumulate(b, &matmul(&a_t, &g, k, r, m));
+                }
+                Op::ScalarMul(a, scalar) => {
+                    let da: Vec<T> = g.iter().map(|&x| x * scalar).collect();
+                    accumulate(a, &da);
+                }
+                Op::Tanh(a) => {
+                    let da: Vec<T> = g
+                        .iter()
+                        .zip(&node.value)
+                        .map(|(&x, &t)| x * (T::one() - t * t))
+                        .collect();
+                    accumulate(a, &da);
+                }
+                Op::Sum(a) => {
+                    let len = nodes[a].value.len();
+                    accumulate(a, &vec![g[0]; len]);
+                }
+            }
+            grads[i] = g;
+        }
+        Gradients { grads }
+    }
+}
+
+impl<T: AllowedFloatTypes> Gradients<T> {
+    /// The gradient with respect to `var`, shaped like its value. Values
+    /// recorded after the differentiated one get a zero gradient.
+    pub fn wrt<const N: usize>(&self, var: &Var<'_, T, N>) -> Matrix<T, N> {
+        let rows = var.shape().0;
+        match self.grads.get(var.index) {
+            Some(grad) => Matrix::from_vec(rows, grad.clone()).expect("gradients match their node"),
+            None => Matrix::new(rows),
+        }
+    }
+}
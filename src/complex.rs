@@ -0,0 +1,70 @@
+//! Complex element types.
+//!
+//! Enabled by the `complex` feature. [`Complex<f32>`] and [`Complex<f64>`]
+//! implement [`AllowedNumericTypes`], so every generic operation works on
+//! complex vectors and matrices. `dot` and `transpose` stay bilinear; the
+//! conjugating versions are [`Vector::hermitian_dot`] and
+//! [`Matrix::conj_transpose`].
+//!
+//! Complex numbers are unordered, so they do not implement
+//! [`crate::tensor::AllowedFloatTypes`] and the factorizations in
+//! [`crate::linalg`] are unavailable.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+pub use num_complex::Complex;
+
+macro_rules! complex_impls {
+    ($($float:ty),+) => {
+        $(
+            /// `from_f64` builds a real value; `to_f64` keeps the real part.
+            impl AllowedNumericTypes for Complex<$float> {
+                fn zero() -> Self {
+                    Complex::new(0.0, 0.0)
+                }
+                fn one() -> Self {
+                    Complex::new(1.0, 0.0)
+                }
+                fn is_zero(&self) -> bool {
+                    self.re == 0.0 && self.im == 0.0
+                }
+                fn from_f64(value: f64) -> Self {
+                    Complex::new(value as $float, 0.0)
+                }
+                fn to_f64(self) -> f64 {
+                    self.re as f64
+                }
+            }
+
+            impl<const N: usize> Vector<Complex<$float>, N> {
+                /// Element-wise complex conjugate.
+                pub fn conj(&self) -> Self {
+                    self.map(|z| z.conj())
+                }
+
+                /// Hermitian inner product `sum(conj(self[i]) * other[i])`,
+                /// which is real and non-negative for `v.hermitian_dot(&v)`.
+                pub fn hermitian_dot(&self, other: &Self) -> Complex<$float> {
+                    self.conj().dot(other)
+                }
+            }
+
+            impl<const N: usize> Matrix<Complex<$float>, N> {
+                /// Element-wise complex conjugate.
+                pub fn conj(&self) -> Self {
+                    self.map(|z| z.conj())
+                }
+
+                /// Conjugate (Hermitian) transpose; `M` must equal the
+                /// number of rows.
+                pub fn conj_transpose<const M: usize>(
+                    &self,
+                ) -> Result<Matrix<Complex<$float>, M>, TensorError> {
+                    self.conj().transpose()
+                }
+            }
+        )+
+    };
+}
+
+complex_impls!(f32, f64);
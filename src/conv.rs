@@ -0,0 +1,246 @@
+//! Strided 2-D convolution with zero padding, and pooling.
+//!
+//! Like the stencils in [`crate::stencil`], "convolution" here is the CNN
+//! convention of cross-correlation: the kernel is not flipped. The output
+//! width is a const generic checked at runtime and must equal
+//! `(N + 2 * padding - K) / stride + 1` for an `N`-wide input and a `K`-wide
+//! kernel; the output height follows the same formula on rows.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+use crate::tensor_impl::count_as;
+
+// Output length along one axis, or an error if the window does not fit
+pub(crate) fn conv_output_len(
+    input: usize,
+    window: usize,
+    stride: usize,
+    padding: usize,
+    operation: &str,
+) -> Result<usize, TensorError> {
+    if stride == 0 || window == 0 {
+        return Err(TensorError::InvalidOperation(format!(
+            "{}: stride and window size must be nonzero",
+            operation
+        )));
+    }
+    let padded = input + 2 * padding;
+    if window > padded {
+        return Err(TensorError::InvalidOperation(format!(
+            "{}: window of {} does not fit padded input of {}",
+            operation, window, padded
+        )));
+    }
+    Ok((padded - window) / stride + 1)
+}
+
+pub(crate) fn check_output_width<const M: usize>(
+    rows: usize,
+    cols: usize,
+    operation: &str,
+) -> Result<(), TensorError> {
+    if cols != M {
+        return Err(TensorError::DimensionMismatch {
+            expected: format!("{}x{}", rows, cols),
+            found: format!("{}x{}", rows, M),
+            operation: operation.to_string(),
+        });
+    }
+    Ok(())
+}
+
+// `kernel` slid over the zero-padded rows of one matrix or depth slice
+fn conv2d_rows<T: AllowedNumericTypes, const N: usize, const K: usize, const M: usize>(
+    input: &[Vector<T, N>],
+    kernel: &Matrix<T, K>,
+    stride: usize,
+    padding: usize,
+) -> Result<Matrix<T, M>, TensorError> {
+    let out_rows = conv_output_len(input.len(), kernel.rows, stride, padding, "conv2d")?;
+    let out_cols = conv_output_len(N, K, stride, padding, "conv2d")?;
+    check_output_width::<M>(out_rows, out_cols, "conv2d")?;
+
+    let mut out = Matrix::new(out_rows);
+    for r in 0..out_rows {
+        for c in 0..M {
+            let mut sum = T::zero();
+            for i in 0..kernel.rows {
+                let Some(y) = (r * stride + i).checked_sub(padding) else {
+                    continue;
+                };
+                if y >= input.len() {
+                    continue;
+                }
+                for j in 0..K {
+                    let Some(x) = (c * stride + j).checked_sub(padding) else {
+                        continue;
+                    };
+                    if x < N {
+                        sum = sum + kernel[i][j] * input[y][x];
+                    }
+                }
+            }
+            out[r][c] = sum;
+        }
+    }
+    Ok(out)
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Slides `kernel` over the zero-padded matrix with the given `stride`,
+    /// producing a `out_rows x M` matrix of window dot products.
+    pub fn conv2d<const K: usize, const M: usize>(
+        &self,
+        kernel: &Matrix<T, K>,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        conv2d_rows(self.as_rows(), kernel, stride, padding)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    /// Applies [`Matrix::conv2d`] with the same kernel to every depth.
+    pub fn conv2d<const K: usize, const M: usize>(
+        &self,
+        kernel: &Matrix<T, K>,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Tensor<T, M>, TensorError> {
+        // Checked up front so an empty batch fails like a full one
+        let out_rows = conv_output_len(self.rows, kernel.rows, stride, padding, "conv2d")?;
+        let out_cols = conv_output_len(N, K, stride, padding, "conv2d")?;
+        check_output_width::<M>(out_rows, out_cols, "conv2d")?;
+
+        let mut data = Vec::with_capacity(self.depths * out_rows * M);
+        for d in 0..self.depths {
+            data.extend(conv2d_rows::<T, N, K, M>(&self[d], kernel, stride, padding)?.into_vec());
+        }
+        Ok(Tensor {
+            data,
+            depths: self.depths,
+            rows: out_rows,
+        })
+    }
+}
+
+// Output rows of pooling `rows x N` inputs into `M` columns
+fn pool_output_rows<const N: usize, const M: usize>(
+    rows: usize,
+    window: usize,
+    stride: usize,
+    operation: &str,
+) -> Result<usize, TensorError> {
+    let out_rows = conv_output_len(rows, window, stride, 0, operation)?;
+    let out_cols = conv_output_len(N, window, stride, 0, operation)?;
+    check_output_width::<M>(out_rows, out_cols, operation)?;
+    Ok(out_rows)
+}
+
+// Applies `reduce` to every `window x window` block, stepping by `stride`
+fn pool<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    m: &[Vector<T, N>],
+    window: usize,
+    stride: usize,
+    operation: &str,
+    reduce: impl Fn(&mut dyn Iterator<Item = T>) -> T,
+) -> Result<Matrix<T, M>, TensorError> {
+    let out_rows = pool_output_rows::<N, M>(m.len(), window, stride, operation)?;
+    Ok(Matrix::from_fn(out_rows, |r, c| {
+        let (y, x) = (r * stride, c * stride);
+        let mut block = (y..y + window).flat_map(|i| m[i].iter().skip(x).take(window).copied());
+        reduce(&mut block)
+    }))
+}
+
+fn max_pool_rows<T: AllowedNumericTypes + PartialOrd, const N: usize, const M: usize>(
+    m: &[Vector<T, N>],
+    window: usize,
+    stride: usize,
+) -> Result<Matrix<T, M>, TensorError> {
+    pool(m, window, stride, "max_pool", |block| {
+        let first = block.next().expect("pooling windows are non-empty");
+        block.fold(first, |acc, x| if x > acc { x } else { acc })
+    })
+}
+
+// Averages with the window size `count` computed by the caller
+fn avg_pool_rows<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    m: &[Vector<T, N>],
+    window: usize,
+    stride: usize,
+    count: T,
+) -> Result<Matrix<T, M>, TensorError> {
+    pool(m, window, stride, "avg_pool", |block| {
+        block.fold(T::zero(), |acc, x| acc + x) / count
+    })
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Matrix<T, N> {
+    /// Maximum over each `window x window` block, stepping by `stride`.
+    /// The output width `M` must equal `(N - window) / stride + 1`.
+    pub fn max_pool<const M: usize>(
+        &self,
+        window: usize,
+        stride: usize,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        max_pool_rows(self.as_rows(), window, stride)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Mean over each `window x window` block, stepping by `stride`.
+    /// Integer element types use truncating division; fails if `T` cannot
+    /// hold the window size, e.g. a 16x16 window of `u8`.
+    pub fn avg_pool<const M: usize>(
+        &self,
+        window: usize,
+        stride: usize,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        pool_output_rows::<N, M>(self.rows, window, stride, "avg_pool")?;
+        let count = count_as(window * window, "avg_pool")?;
+        avg_pool_rows(self.as_rows(), window, stride, count)
+    }
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Tensor<T, N> {
+    /// Applies [`Matrix::max_pool`] to every depth.
+    pub fn max_pool<const M: usize>(
+        &self,
+        window: usize,
+        stride: usize,
+    ) -> Result<Tensor<T, M>, TensorError> {
+        // Checked up front so an empty batch fails like a full one
+        let out_rows = pool_output_rows::<N, M>(self.rows, window, stride, "max_pool")?;
+        let mut data = Vec::with_capacity(self.depths * out_rows * M);
+        for d in 0..self.depths {
+            data.extend(max_pool_rows::<T, N, M>(&self[d], window, stride)?.into_vec());
+        }
+        Ok(Tensor {
+            data,
+            depths: self.depths,
+            rows: out_rows,
+        })
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    /// Applies [`Matrix::avg_pool`] to every depth.
+    pub fn avg_pool<const M: usize>(
+        &self,
+        window: usize,
+        stride: usize,
+    ) -> Result<Tensor<T, M>, TensorError> {
+        let out_rows = pool_output_rows::<N, M>(self.rows, window, stride, "avg_pool")?;
+        let count = count_as(window * window, "avg_pool")?;
+        let mut data = Vec::with_capacity(self.depths * out_rows * M);
+        for d in 0..self.depths {
+            data.extend(avg_pool_rows::<T, N, M>(&self[d], window, stride, count)?.into_vec());
+        }
+        Ok(Tensor {
+            data,
+            depths: self.depths,
+            rows: out_rows,
+        })
+    }
+}
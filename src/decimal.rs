@@ -0,0 +1,68 @@
+//! Decimal element type.
+//!
+//! Enabled by the `decimal` feature. [`Decimal`] implements both numeric
+//! traits, so sums, scaling and solves on money-like values stay in base 10
+//! with 28 significant digits. `from_f64` rounds to the nearest decimal,
+//! saturates at the type's bounds and maps NaN to zero. Decimal has no NaN
+//! or infinity, so `sqrt` of a negative value and overflowing `exp` panic,
+//! like integer overflow does.
+
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, MathematicalOps};
+
+impl AllowedNumericTypes for Decimal {
+    fn zero() -> Self {
+        Decimal::ZERO
+    }
+    fn one() -> Self {
+        Decimal::ONE
+    }
+    fn is_zero(&self) -> bool {
+        Decimal::is_zero(self)
+    }
+    fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            return Decimal::ZERO;
+        }
+        <Decimal as FromPrimitive>::from_f64(value).unwrap_or(if value > 0.0 {
+            Decimal::MAX
+        } else {
+            Decimal::MIN
+        })
+    }
+    fn to_f64(self) -> f64 {
+        ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
+    }
+}
+
+impl AllowedFloatTypes for Decimal {
+    fn epsilon() -> Self {
+        // One unit in the last place at the largest scale
+        Decimal::new(1, 28)
+    }
+    fn abs(self) -> Self {
+        Decimal::abs(&self)
+    }
+    fn sqrt(self) -> Self {
+        MathematicalOps::sqrt(&self).expect("square root of a negative Decimal")
+    }
+    /// Saturates to `Decimal::MAX` when the result overflows and to zero
+    /// when it is too small to represent.
+    fn exp(self) -> Self {
+        match self.checked_exp() {
+            Some(e) => e,
+            None if self > Decimal::ZERO => Decimal::MAX,
+            None => Decimal::ZERO,
+        }
+    }
+    fn tanh(self) -> Self {
+        // tanh saturates to +-1 well before e^(2x) overflows
+        let two_x = self + self;
+        match two_x.checked_exp() {
+            Some(e) => (e - Decimal::ONE) / (e + Decimal::ONE),
+            None if self > Decimal::ZERO => Decimal::ONE,
+            None => -Decimal::ONE,
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! `Display` for vectors, matrices and tensors, with NumPy-style print
+//! options.
+//!
+//! Large values are summarized: an axis longer than its limit shows only its
+//! first and last `edge_items` entries around a `...` marker. The global
+//! options set with [`set_print_options`] apply to `{}` formatting;
+//! `display_with` formats a single value with explicit options.
+
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+use std::fmt;
+use std::sync::RwLock;
+
+/// Controls how values are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Rows (and tensor depths) shown in full before summarizing.
+    pub max_rows: usize,
+    /// Columns (and vector elements) shown in full before summarizing.
+    pub max_cols: usize,
+    /// Entries kept at each end of a summarized axis.
+    pub edge_items: usize,
+    /// Digits after the decimal point for floats; `None` prints the
+    /// shortest exact representation. Ignored by integers.
+    pub precision: Option<usize>,
+}
+
+impl PrintOptions {
+    pub const DEFAULT: PrintOptions = PrintOptions {
+        max_rows: 10,
+        max_cols: 10,
+        edge_items: 3,
+        precision: None,
+    };
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+static PRINT_OPTIONS: RwLock<PrintOptions> = RwLock::new(PrintOptions::DEFAULT);
+
+/// Sets the options used by `Display` for all values.
+pub fn set_print_options(options: PrintOptions) {
+    *PRINT_OPTIONS.write().unwrap_or_else(|e| e.into_inner()) = options;
+}
+
+/// The options currently used by `Display`.
+pub fn print_options() -> PrintOptions {
+    *PRINT_OPTIONS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A value paired with the options to print it with.
+pub struct Formatted<'a, V> {
+    value: &'a V,
+    options: PrintOptions,
+}
+
+// Indices to show along an axis of `len`, with `None` marking the ellipsis
+fn visible(len: usize, max: usize, edge: usize) -> Vec<Option<usize>> {
+    if len <= max || 2 * edge >= len {
+        return (0..len).map(Some).collect();
+    }
+    (0..edge)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - edge..len).map(Some))
+        .collect()
+}
+
+fn format_element<T: fmt::Display>(x: &T, options: &PrintOptions) -> String {
+    match options.precision {
+        Some(p) => format!("{:.*}", p, x),
+        None => format!("{}", x),
+    }
+}
+
+// Formats the visible elements of each row, right-aligned to a shared width
+fn format_rows<T, const N: usize>(
+    rows: &[Option<&Vector<T, N>>],
+    options: &PrintOptions,
+) -> Vec<String>
+where
+    T: AllowedNumericTypes + fmt::Display,
+{
+    let cols = visible(N, options.max_cols, options.edge_items);
+    let cells: Vec<Option<Vec<String>>> = rows
+        .iter()
+        .map(|row| {
+            row.map(|v| {
+                cols.iter()
+                    .map(|c| c.map_or("...".to_string(), |c| format_element(&v[c], options)))
+                    .collect()
+            })
+        })
+        .collect();
+    let width = cells
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|s| s.len())
+        .max()
+        .unwrap_or(0);
+    cells
+        .into_iter()
+        .map(|row| match row {
+            Some(row) => {
+                let padded: Vec<String> = row
+                    .iter()
+                    .map(|s| format!("{:>w$}", s, w = width))
+                    .collect();
+                format!("[{}]", padded.join(", "))
+            }
+            None => "...".to_string(),
+        })
+        .collect()
+}
+
+fn write_matrix<T, const N: usize>(
+    f: &mut fmt::Formatter,
+    m: &[Vector<T, N>],
+    options: &PrintOptions,
+    indent: &str,
+) -> fmt::Result
+where
+    T: AllowedNumericTypes + fmt::Display,
+{
+    let rows: Vec<Option<&Vector<T, N>>> = visible(m.len(), options.max_rows, options.edge_items)
+        .into_iter()
+        .map(|r| r.map(|r| &m[r]))
+        .collect();
+    let lines = format_rows(&rows, options);
+    write!(f, "[")?;
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",\n{} ", indent)?;
+        }
+        write!(f, "{}", line)?;
+    }
+    write!(f, "]")
+}
+
+impl<T: AllowedNumericTypes + fmt::Display, const N: usize> fmt::Display
+    for Formatted<'_, Vector<T, N>>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line = format_rows(&[Some(self.value)], &self.options);
+        write!(f, "{}", line[0])
+    }
+}
+
+impl<T: AllowedNumericTypes + fmt::Display, const N: usize> fmt::Display
+    for Formatted<'_, Matrix<T, N>>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_matrix(f, self.value.as_rows(), &self.options, "")
+    }
+}
+
+impl<T: AllowedNumericTypes + fmt::Display, const N: usize> fmt::Display
+    for Formatted<'_, Tensor<T, N>>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let t = self.value;
+        let depths = visible(t.depths, self.options.max_rows, self.options.edge_items);
+        write!(f, "[")?;
+        for (i, d) in depths.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",\n\n ")?;
+            }
+            match d {
+                Some(d) => write_matrix(f, &t[*d], &self.options, " ")?,
+                None => write!(f, "...")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+macro_rules! display_impls {
+    ($($ty:ident),+) => {
+        $(
+            impl<T: AllowedNumericTypes + fmt::Display, const N: usize> $ty<T, N> {
+                /// Formats with explicit options instead of the global ones.
+                pub fn display_with(&self, options: PrintOptions) -> Formatted<'_, Self> {
+                    Formatted { value: self, options }
+                }
+            }
+
+            impl<T: AllowedNumericTypes + fmt::Display, const N: usize> fmt::Display for $ty<T, N> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.display_with(print_options()).fmt(f)
+                }
+            }
+        )+
+    };
+}
+
+display_impls!(Vector, Matrix, Tensor);
@@ -0,0 +1,305 @@
+//! Seeded random number generation and sampling distributions.
+//!
+//! Everything here is implemented in-crate so generated data is reproducible
+//! from a `u64` seed on every platform. The generator is xoshiro256**, seeded
+//! through SplitMix64.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+
+/// Small, fast pseudo-random generator (xoshiro256**).
+///
+/// Not suitable for cryptographic use.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// Creates a generator whose output is fully determined by `seed`.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut next = || {
+            sm = sm.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = sm;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Rng {
+            state: [next(), next(), next(), next()],
+        }
+    }
+
+    /// Next raw 64-bit output.
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Uniform sample from `[0, 1)` with 53 bits of precision.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    // Uniform sample from (0, 1], safe to pass to `ln`
+    fn next_f64_open(&mut self) -> f64 {
+        1.0 - self.next_f64()
+    }
+
+    fn standard_normal(&mut self) -> f64 {
+        let u1 = self.next_f64_open();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    // Marsaglia-Tsang sampler for Gamma(shape, 1)
+    fn gamma(&mut self, shape: f64) -> f64 {
+        if shape < 1.0 {
+            let u = self.next_f64_open();
+            return self.gamma(shape + 1.0) * u.powf(1.0 / shape);
+        }
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let x = self.standard_normal();
+            let v = 1.0 + c * x;
+            if v <= 0.0 {
+                continue;
+            }
+            let v = v * v * v;
+            let u = self.next_f64_open();
+            if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return d * v;
+            }
+        }
+    }
+}
+
+/// A distribution that can fill rows of samples.
+///
+/// Univariate distributions fill every slot independently; multivariate
+/// ones such as [`Dirichlet`] treat the whole row as one draw.
+pub trait Distribution {
+    /// Writes one row of samples into `out`.
+    fn fill(&self, rng: &mut Rng, out: &mut [f64]) -> Result<(), TensorError>;
+}
+
+fn invalid(msg: &str) -> TensorError {
+    TensorError::InvalidOperation(msg.to_string())
+}
+
+/// Continuous uniform distribution on `[low, high)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uniform {
+    low: f64,
+    high: f64,
+}
+
+impl Uniform {
+    pub fn new(low: f64, high: f64) -> Result<Self, TensorError> {
+        if !(low.is_finite() && high.is_finite() && low < high) {
+            return Err(invalid("Uniform requires finite low < high"));
+        }
+        Ok(Uniform { low, high })
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        self.low + (self.high - self.low) * rng.next_f64()
+    }
+}
+
+/// Normal distribution with the given mean and standard deviation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    pub fn new(mean: f64, std_dev: f64) -> Result<Self, TensorError> {
+        if !(mean.is_finite() && std_dev.is_finite() && std_dev >= 0.0) {
+            return Err(invalid("Normal requires a finite mean and std_dev >= 0"));
+        }
+        Ok(Normal { mean, std_dev })
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        self.mean + self.std_dev * rng.standard_normal()
+    }
+}
+
+/// Bernoulli distribution producing `1.0` with probability `p`, else `0.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    pub fn new(p: f64) -> Result<Self, TensorError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(invalid("Bernoulli requires 0 <= p <= 1"));
+        }
+        Ok(Bernoulli { p })
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        if rng.next_f64() < self.p {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Exponential distribution with rate `lambda`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential {
+    lambda: f64,
+}
+
+impl Exponential {
+    pub fn new(lambda: f64) -> Result<Self, TensorError> {
+        if !(lambda.is_finite() && lambda > 0.0) {
+            return Err(invalid("Exponential requires a finite lambda > 0"));
+        }
+        Ok(Exponential { lambda })
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        -rng.next_f64_open().ln() / self.lambda
+    }
+}
+
+/// Dirichlet distribution over the probability simplex.
+///
+/// Each filled row is one point whose entries are positive and sum to one,
+/// so the row length must match the number of concentration parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+}
+
+impl Dirichlet {
+    pub fn new(alpha: Vec<f64>) -> Result<Self, TensorError> {
+        if alpha.is_empty() || !alpha.iter().all(|a| a.is_finite() && *a > 0.0) {
+            return Err(invalid("Dirichlet requires at least one finite alpha > 0"));
+        }
+        Ok(Dirichlet { alpha })
+    }
+
+    /// Dirichlet with `k` equal concentration parameters.
+    pub fn symmetric(alpha: f64, k: usize) -> Result<Self, TensorError> {
+        Self::new(vec![alpha; k])
+    }
+}
+
+macro_rules! impl_univariate {
+    ($($dist:ty),*) => {
+        $(
+            impl Distribution for $dist {
+                fn fill(&self, rng: &mut Rng, out: &mut [f64]) -> Result<(), TensorError> {
+                    for x in out.iter_mut() {
+                        *x = self.sample(rng);
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_univariate!(Uniform, Normal, Bernoulli, Exponential);
+
+impl Distribution for Dirichlet {
+    fn fill(&self, rng: &mut Rng, out: &mut [f64]) -> Result<(), TensorError> {
+        if out.len() != self.alpha.len() {
+            return Err(TensorError::DimensionMismatch {
+                expected: self.alpha.len().to_string(),
+                found: out.len().to_string(),
+                operation: "Dirichlet sampling".to_string(),
+            });
+        }
+        let mut sum = 0.0;
+        for (x, &a) in out.iter_mut().zip(self.alpha.iter()) {
+            *x = rng.gamma(a);
+            sum += *x;
+        }
+        for x in out.iter_mut() {
+            *x /= sum;
+        }
+        Ok(())
+    }
+}
+
+fn random_row<T: AllowedNumericTypes, const N: usize, D: Distribution>(
+    dist: &D,
+    rng: &mut Rng,
+) -> Result<Vector<T, N>, TensorError> {
+    let mut row = [0.0; N];
+    dist.fill(rng, &mut row)?;
+    Ok(Vector::from(row.map(T::from_f64)))
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Creates a `rows x N` matrix of samples drawn from `dist`.
+    ///
+    /// The same `seed` always produces the same matrix.
+    pub fn random_from<D: Distribution>(
+        dist: &D,
+        rows: usize,
+        seed: u64,
+    ) -> Result<Self, TensorError> {
+        let mut rng = Rng::seed_from_u64(seed);
+        let mut data = Vec::with_capacity(rows);
+        for _ in 0..rows {
+            data.push(random_row(dist, &mut rng)?);
+        }
+        Ok(Matrix::from_vectors(data))
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    /// Creates a tensor of shape `(depths, rows, N)` with samples drawn from
+    /// `dist`, where `shape` is `(depths, rows)`.
+    ///
+    /// The same `seed` always produces the same tensor.
+    pub fn random_from<D: Distribution>(
+        dist: &D,
+        shape: (usize, usize),
+        seed: u64,
+    ) -> Result<Self, TensorError> {
+        let (depths, rows) = shape;
+        let mut rng = Rng::seed_from_u64(seed);
+        let mut data = Vec::with_capacity(depths * rows * N);
+        for _ in 0..depths * rows {
+            data.extend_from_slice(random_row::<T, N, D>(dist, &mut rng)?.as_slice());
+        }
+        Ok(Tensor { data, depths, rows })
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N>
+where
+    rand::distr::StandardUniform: rand::distr::Distribution<T>,
+{
+    /// Creates a tensor of shape `(depths, rows, N)` filled from a `rand`
+    /// generator using its standard distribution: `[0, 1)` for floats and
+    /// the full range for integers.
+    pub fn random<R: rand::Rng + ?Sized>(depths: usize, rows: usize, rng: &mut R) -> Self {
+        let mut t = Tensor::new(depths, rows);
+        for x in t.iter_flat_mut() {
+            *x = rng.random();
+        }
+        t
+    }
+}
@@ -0,0 +1,164 @@
+//! Forward-mode automatic differentiation with dual numbers.
+//!
+//! A [`Dual`] carries a value and its derivative with respect to one input.
+//! It implements both numeric traits, so any generic routine in the crate
+//! (dot products, matrix products, activations, norms) propagates
+//! derivatives without changes. [`gradient`] seeds each input in turn, which
+//! costs one evaluation per input; prefer reverse mode for many inputs.
+
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes, Vector};
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A value `value + deriv * e` with `e^2 = 0`.
+///
+/// Comparisons, including `==` and [`AllowedNumericTypes::is_zero`], look at
+/// the value only, so branches (max, pivoting, zero checks) go the way the
+/// plain computation would.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<T> {
+    pub value: T,
+    pub deriv: T,
+}
+
+impl<T: AllowedFloatTypes> Dual<T> {
+    pub fn new(value: T, deriv: T) -> Self {
+        Dual { value, deriv }
+    }
+
+    /// A constant, whose derivative is zero.
+    pub fn constant(value: T) -> Self {
+        Dual::new(value, T::zero())
+    }
+
+    /// The input being differentiated against, whose derivative is one.
+    pub fn variable(value: T) -> Self {
+        Dual::new(value, T::one())
+    }
+}
+
+impl<T: AllowedFloatTypes> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Dual::new(self.value + rhs.value, self.deriv + rhs.deriv)
+    }
+}
+
+impl<T: AllowedFloatTypes> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Dual::new(self.value - rhs.value, self.deriv - rhs.deriv)
+    }
+}
+
+impl<T: AllowedFloatTypes> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Dual::new(
+            self.value * rhs.value,
+            self.deriv * rhs.value + self.value * rhs.deriv,
+        )
+    }
+}
+
+impl<T: AllowedFloatTypes> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Dual::new(
+            self.value / rhs.value,
+            (self.deriv * rhs.value - self.value * rhs.deriv) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl<T: AllowedFloatTypes> PartialEq for Dual<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: AllowedFloatTypes> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: AllowedFloatTypes> AllowedNumericTypes for Dual<T> {
+    fn zero() -> Self {
+        Dual::constant(T::zero())
+    }
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+    fn from_f64(value: f64) -> Self {
+        Dual::constant(T::from_f64(value))
+    }
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+}
+
+impl<T: AllowedFloatTypes> AllowedFloatTypes for Dual<T> {
+    fn epsilon() -> Self {
+        Dual::constant(T::epsilon())
+    }
+    fn abs(self) -> Self {
+        if self.value < T::zero() {
+            Dual::new(T::zero() - self.value, T::zero() - self.deriv)
+        } else {
+            self
+        }
+    }
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        let two = T::one() + T::one();
+        Dual::new(root, self.deriv / (two * root))
+    }
+    fn exp(self) -> Self {
+        let e = self.value.exp();
+        Dual::new(e, self.deriv * e)
+    }
+    fn tanh(self) -> Self {
+        let t = self.value.tanh();
+        Dual::new(t, self.deriv * (T::one() - t * t))
+    }
+}
+
+/// Value and derivative of `f` at `x`.
+pub fn derivative<T, F>(f: F, x: T) -> (T, T)
+where
+    T: AllowedFloatTypes,
+    F: Fn(Dual<T>) -> Dual<T>,
+{
+    let y = f(Dual::variable(x));
+    (y.value, y.deriv)
+}
+
+/// Value and gradient of `f` at `x`, using one forward pass per element.
+pub fn gradient<T, F, const N: usize>(f: F, x: &Vector<T, N>) -> (T, Vector<T, N>)
+where
+    T: AllowedFloatTypes,
+    F: Fn(&Vector<Dual<T>, N>) -> Dual<T>,
+{
+    let mut value = T::zero();
+    let mut grad = Vector::new();
+    for i in 0..N {
+        let seeded = Vector::from(std::array::from_fn(|j| {
+            Dual::new(x[j], if i == j { T::one() } else { T::zero() })
+        }));
+        let y = f(&seeded);
+        value = y.value;
+        grad[i] = y.deriv;
+    }
+    if N == 0 {
+        value = f(&Vector::new()).value;
+    }
+    (value, grad)
+}
@@ -0,0 +1,398 @@
+//! Vectors and matrices whose width is only known at runtime.
+//!
+//! [`DynVector`] and [`DynMatrix`] carry every dimension as a value instead
+//! of a const generic, so they can hold data such as CSV columns or user
+//! input. Shapes are checked when operations run, and `TryFrom`/`From`
+//! convert to and from the fixed-width [`Vector`] and [`Matrix`].
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+/// A vector with a runtime length.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynVector<T: AllowedNumericTypes> {
+    data: Vec<T>,
+}
+
+/// A row-major `rows x cols` matrix with runtime dimensions.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynMatrix<T: AllowedNumericTypes> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+fn check_len(expected: usize, found: usize, operation: &str) -> Result<(), TensorError> {
+    if expected != found {
+        return Err(TensorError::DimensionMismatch {
+            expected: expected.to_string(),
+            found: found.to_string(),
+            operation: operation.to_string(),
+        });
+    }
+    Ok(())
+}
+
+impl<T: AllowedNumericTypes> DynVector<T> {
+    pub fn zeros(len: usize) -> Self {
+        DynVector {
+            data: vec![T::zero(); len],
+        }
+    }
+
+    pub fn from_vec(data: Vec<T>) -> Self {
+        DynVector { data }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data.get_mut(index)
+    }
+
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> DynVector<U> {
+        DynVector {
+            data: self.data.iter().copied().map(f).collect(),
+        }
+    }
+
+    fn zip_with(
+        &self,
+        other: &Self,
+        operation: &str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Self, TensorError> {
+        check_len(self.len(), other.len(), operation)?;
+        Ok(DynVector {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Self {
+        self.map(|x| x * scalar)
+    }
+
+    pub fn scalar_div(&self, scalar: T) -> Result<Self, TensorError> {
+        if scalar.is_zero() {
+            return Err(TensorError::DivisionByZero);
+        }
+        Ok(self.map(|x| x / scalar))
+    }
+
+    pub fn hadamard_product(&self, other: &Self) -> Result<Self, TensorError> {
+        self.zip_with(other, "DynVector hadamard product", |a, b| a * b)
+    }
+
+    pub fn dot(&self, other: &Self) -> Result<T, TensorError> {
+        check_len(self.len(), other.len(), "DynVector dot")?;
+        Ok(T::dot_slices(&self.data, &other.data))
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Converts to a [`Vector`]; the length must equal `N`.
+    pub fn to_vector<const N: usize>(&self) -> Result<Vector<T, N>, TensorError> {
+        Vector::from_slice(&self.data)
+    }
+}
+
+impl<T: AllowedNumericTypes> DynMatrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        DynMatrix {
+            data: vec![T::zero(); rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Wraps row-major `data`, which must hold `rows * cols` elements.
+    pub fn from_vec(data: Vec<T>, rows: usize, cols: usize) -> Result<Self, TensorError> {
+        if data.len() != rows * cols {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements for {}x{}", rows * cols, rows, cols),
+                found: format!("{} elements", data.len()),
+                operation: "DynMatrix from_vec".to_string(),
+            });
+        }
+        Ok(DynMatrix { data, rows, cols })
+    }
+
+    /// Builds a matrix from rows of equal length.
+    pub fn from_rows(rows: &[Vec<T>]) -> Result<Self, TensorError> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            check_len(cols, row.len(), "DynMatrix from_rows")?;
+            data.extend_from_slice(row);
+        }
+        Ok(DynMatrix {
+            data,
+            rows: rows.len(),
+            cols,
+        })
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut result = Self::zeros(size, size);
+        for i in 0..size {
+            result[(i, i)] = T::one();
+        }
+        result
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.data[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        (row < self.rows && col < self.cols).then(|| &mut self.data[row * self.cols + col])
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.rows).then(|| &self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> DynMatrix<U> {
+        DynMatrix {
+            data: self.data.iter().copied().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    fn check_same_shape(&self, other: &Self, operation: &str) -> Result<(), TensorError> {
+        if self.shape() != other.shape() {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, self.cols),
+                found: format!("{}x{}", other.rows, other.cols),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn zip_with(
+        &self,
+        other: &Self,
+        operation: &str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Self, TensorError> {
+        self.check_same_shape(other, operation)?;
+        Ok(DynMatrix {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Self {
+        self.map(|x| x * scalar)
+    }
+
+    pub fn scalar_div(&self, scalar: T) -> Result<Self, TensorError> {
+        if scalar.is_zero() {
+            return Err(TensorError::DivisionByZero);
+        }
+        Ok(self.map(|x| x / scalar))
+    }
+
+    pub fn hadamard_product(&self, other: &Self) -> Result<Self, TensorError> {
+        self.zip_with(other, "DynMatrix hadamard product", |a, b| a * b)
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            data.extend((0..self.rows).map(|i| self.data[i * self.cols + j]));
+        }
+        DynMatrix {
+            data,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Matrix product; `rhs` must have as many rows as `self` has columns.
+    pub fn mat_mul(&self, rhs: &Self) -> Result<Self, TensorError> {
+        if rhs.rows != self.cols {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.cols, rhs.cols),
+                found: format!("{}x{}", rhs.rows, rhs.cols),
+                operation: "DynMatrix multiplication".to_string(),
+            });
+        }
+        let mut result = Self::zeros(self.rows, rhs.cols);
+        if rhs.cols == 0 {
+            return Ok(result);
+        }
+        for (out, row) in result
+            .data
+            .chunks_mut(rhs.cols)
+            .zip(self.data.chunks(self.cols.max(1)))
+        {
+            for (&x, rhs_row) in row.iter().zip(rhs.data.chunks(rhs.cols)) {
+                T::axpy_slices(out, x, rhs_row);
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn mat_vec_mul(&self, vec: &DynVector<T>) -> Result<DynVector<T>, TensorError> {
+        check_len(self.cols, vec.len(), "DynMatrix mat_vec_mul")?;
+        Ok(DynVector {
+            data: (0..self.rows)
+                .map(|i| T::dot_slices(&self.data[i * self.cols..(i + 1) * self.cols], &vec.data))
+                .collect(),
+        })
+    }
+
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Converts to a [`Matrix`]; the column count must equal `N`.
+    pub fn to_matrix<const N: usize>(&self) -> Result<Matrix<T, N>, TensorError> {
+        if self.cols != N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", self.rows, self.cols),
+                operation: "DynMatrix to_matrix".to_string(),
+            });
+        }
+        Ok(Matrix::from_fn(self.rows, |i, j| self.data[i * N + j]))
+    }
+}
+
+impl<T: AllowedNumericTypes> Index<usize> for DynVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T: AllowedNumericTypes> IndexMut<usize> for DynVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T: AllowedNumericTypes> Index<(usize, usize)> for DynMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col).expect("DynMatrix index out of bounds")
+    }
+}
+
+impl<T: AllowedNumericTypes> IndexMut<(usize, usize)> for DynMatrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col)
+            .expect("DynMatrix index out of bounds")
+    }
+}
+
+// Element-wise operators on borrowed operands, returning `Result` since the
+// shapes are only known at runtime
+macro_rules! dyn_elementwise_op {
+    ($ty:ident, $trait:ident, $method:ident, $op:tt, $name:literal) => {
+        impl<'b, T: AllowedNumericTypes> $trait<&'b $ty<T>> for &$ty<T> {
+            type Output = Result<$ty<T>, TensorError>;
+
+            fn $method(self, rhs: &'b $ty<T>) -> Self::Output {
+                self.zip_with(rhs, $name, |a, b| a $op b)
+            }
+        }
+    };
+}
+
+dyn_elementwise_op!(DynVector, Add, add, +, "DynVector addition");
+dyn_elementwise_op!(DynVector, Sub, sub, -, "DynVector subtraction");
+dyn_elementwise_op!(DynMatrix, Add, add, +, "DynMatrix addition");
+dyn_elementwise_op!(DynMatrix, Sub, sub, -, "DynMatrix subtraction");
+
+impl<'b, T: AllowedNumericTypes> Mul<&'b DynMatrix<T>> for &DynMatrix<T> {
+    type Output = Result<DynMatrix<T>, TensorError>;
+
+    fn mul(self, rhs: &'b DynMatrix<T>) -> Self::Output {
+        self.mat_mul(rhs)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> From<&Vector<T, N>> for DynVector<T> {
+    fn from(v: &Vector<T, N>) -> Self {
+        DynVector {
+            data: v.iter().copied().collect(),
+        }
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> From<&Matrix<T, N>> for DynMatrix<T> {
+    fn from(m: &Matrix<T, N>) -> Self {
+        DynMatrix {
+            data: m.as_slice().to_vec(),
+            rows: m.rows,
+            cols: N,
+        }
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> TryFrom<&DynVector<T>> for Vector<T, N> {
+    type Error = TensorError;
+
+    fn try_from(v: &DynVector<T>) -> Result<Self, Self::Error> {
+        v.to_vector()
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> TryFrom<&DynMatrix<T>> for Matrix<T, N> {
+    type Error = TensorError;
+
+    fn try_from(m: &DynMatrix<T>) -> Result<Self, Self::Error> {
+        m.to_matrix()
+    }
+}
@@ -0,0 +1,201 @@
+//! Lazy element-wise matrix expressions.
+//!
+//! [`Matrix::lazy`] wraps a matrix in a [`Lazy`] expression. Combining
+//! expressions with `+`, `-`, [`Lazy::hadamard`], [`Lazy::scalar_mul`] or
+//! [`Lazy::map`] only builds a tree of borrowed operands; [`Lazy::eval`]
+//! checks the shapes once and computes every element of the result in a
+//! single pass, without allocating intermediate matrices:
+//!
+//! ```
+//! use tensor_algebra_in_rust::matrix;
+//! use tensor_algebra_in_rust::tensor::Matrix;
+//!
+//! let a: Matrix<f64, 2> = matrix![1.0, 2.0; 3.0, 4.0];
+//! let b: Matrix<f64, 2> = matrix![0.5, 0.5; 2.0, 1.0];
+//! let c: Matrix<f64, 2> = matrix![2.0, 4.0; 1.0, 0.0];
+//!
+//! let fused = (a.lazy().scalar_mul(2.0) + b.lazy().hadamard(c.lazy())).eval()?;
+//! assert_eq!(fused, matrix![3.0, 6.0; 8.0, 8.0]);
+//! # Ok::<(), tensor_algebra_in_rust::error::TensorError>(())
+//! ```
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix};
+use std::ops::{Add, Sub};
+
+/// An element-wise expression over `rows x N` matrices.
+pub trait Expr<const N: usize> {
+    type Elem: AllowedNumericTypes;
+
+    /// Number of rows, or an error if the operands' shapes disagree.
+    fn rows(&self) -> Result<usize, TensorError>;
+
+    /// Element `(row, col)`; only called after [`Expr::rows`] succeeded.
+    fn at(&self, row: usize, col: usize) -> Self::Elem;
+}
+
+/// A lazily evaluated element-wise expression producing a `rows x N`
+/// matrix.
+#[derive(Clone, Copy, Debug)]
+pub struct Lazy<E, const N: usize>(E);
+
+/// An expression applying `f` to every element of `inner`.
+#[derive(Clone, Copy, Debug)]
+pub struct Unary<E, F> {
+    inner: E,
+    f: F,
+}
+
+/// An expression combining corresponding elements of `lhs` and `rhs`.
+#[derive(Clone, Copy, Debug)]
+pub struct Binary<A, B, F> {
+    lhs: A,
+    rhs: B,
+    f: F,
+    operation: &'static str,
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Expr<N> for &Matrix<T, N> {
+    type Elem = T;
+
+    fn rows(&self) -> Result<usize, TensorError> {
+        Ok(self.rows)
+    }
+
+    fn at(&self, row: usize, col: usize) -> T {
+        self.data[row * N + col]
+    }
+}
+
+impl<E, F, const N: usize> Expr<N> for Unary<E, F>
+where
+    E: Expr<N>,
+    F: Fn(E::Elem) -> E::Elem,
+{
+    type Elem = E::Elem;
+
+    fn rows(&self) -> Result<usize, TensorError> {
+        self.inner.rows()
+    }
+
+    fn at(&self, row: usize, col: usize) -> E::Elem {
+        (self.f)(self.inner.at(row, col))
+    }
+}
+
+impl<A, B, F, const N: usize> Expr<N> for Binary<A, B, F>
+where
+    A: Expr<N>,
+    B: Expr<N, Elem = A::Elem>,
+    F: Fn(A::Elem, A::Elem) -> A::Elem,
+{
+    type Elem = A::Elem;
+
+    fn rows(&self) -> Result<usize, TensorError> {
+        let (lhs, rhs) = (self.lhs.rows()?, self.rhs.rows()?);
+        if lhs != rhs {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", lhs, N),
+                found: format!("{}x{}", rhs, N),
+                operation: self.operation.to_string(),
+            });
+        }
+        Ok(lhs)
+    }
+
+    fn at(&self, row: usize, col: usize) -> A::Elem {
+        (self.f)(self.lhs.at(row, col), self.rhs.at(row, col))
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Starts a lazy element-wise expression borrowing this matrix.
+    pub fn lazy(&self) -> Lazy<&Matrix<T, N>, N> {
+        Lazy(self)
+    }
+}
+
+type BinaryFn<T> = fn(T, T) -> T;
+
+impl<E: Expr<N>, const N: usize> Lazy<E, N> {
+    /// Applies `f` to every element.
+    pub fn map<F>(self, f: F) -> Lazy<Unary<E, F>, N>
+    where
+        F: Fn(E::Elem) -> E::Elem,
+    {
+        Lazy(Unary { inner: self.0, f })
+    }
+
+    /// Multiplies every element by `scalar`.
+    pub fn scalar_mul(self, scalar: E::Elem) -> Lazy<Unary<E, impl Fn(E::Elem) -> E::Elem>, N> {
+        self.map(move |x| x * scalar)
+    }
+
+    /// Combines corresponding elements with `f`.
+    pub fn zip_with<B, F>(self, other: Lazy<B, N>, f: F) -> Lazy<Binary<E, B, F>, N>
+    where
+        B: Expr<N, Elem = E::Elem>,
+        F: Fn(E::Elem, E::Elem) -> E::Elem,
+    {
+        Lazy(Binary {
+            lhs: self.0,
+            rhs: other.0,
+            f,
+            operation: "Lazy zip_with",
+        })
+    }
+
+    /// Element-wise product.
+    pub fn hadamard<B>(self, other: Lazy<B, N>) -> Lazy<Binary<E, B, BinaryFn<E::Elem>>, N>
+    where
+        B: Expr<N, Elem = E::Elem>,
+    {
+        Lazy(Binary {
+            lhs: self.0,
+            rhs: other.0,
+            f: |a, b| a * b,
+            operation: "Matrix hadamard product",
+        })
+    }
+
+    /// Computes the expression in one pass; fails if any two operands have
+    /// different numbers of rows.
+    pub fn eval(&self) -> Result<Matrix<E::Elem, N>, TensorError> {
+        let rows = self.0.rows()?;
+        Ok(Matrix::from_fn(rows, |i, j| self.0.at(i, j)))
+    }
+}
+
+impl<A, B, const N: usize> Add<Lazy<B, N>> for Lazy<A, N>
+where
+    A: Expr<N>,
+    B: Expr<N, Elem = A::Elem>,
+{
+    type Output = Lazy<Binary<A, B, BinaryFn<A::Elem>>, N>;
+
+    fn add(self, rhs: Lazy<B, N>) -> Self::Output {
+        Lazy(Binary {
+            lhs: self.0,
+            rhs: rhs.0,
+            f: |a, b| a + b,
+            operation: "Matrix addition",
+        })
+    }
+}
+
+impl<A, B, const N: usize> Sub<Lazy<B, N>> for Lazy<A, N>
+where
+    A: Expr<N>,
+    B: Expr<N, Elem = A::Elem>,
+{
+    type Output = Lazy<Binary<A, B, BinaryFn<A::Elem>>, N>;
+
+    fn sub(self, rhs: Lazy<B, N>) -> Self::Output {
+        Lazy(Binary {
+            lhs: self.0,
+            rhs: rhs.0,
+            f: |a, b| a - b,
+            operation: "Matrix subtraction",
+        })
+    }
+}
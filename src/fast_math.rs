@@ -0,0 +1,72 @@
+//! Unrolled `f32`/`f64` kernels behind the `fast-math` feature.
+//!
+//! These back the [`AllowedNumericTypes::dot_slices`] and
+//! [`AllowedNumericTypes::axpy_slices`] overrides for the primitive floats
+//! when the `simd` feature is off. Dot products keep four independent
+//! accumulators so consecutive multiply-adds do not wait on each other, and
+//! use fused multiply-add when the target has it (`-C target-feature=+fma`);
+//! without hardware FMA, `mul_add` would be a slow library call, so a plain
+//! multiply and add is used instead. Matrix products drop their Kahan
+//! compensation and accumulate through the same axpy kernel, which is also
+//! the case when `simd` is on.
+//!
+//! [`AllowedNumericTypes::dot_slices`]: crate::tensor::AllowedNumericTypes::dot_slices
+//! [`AllowedNumericTypes::axpy_slices`]: crate::tensor::AllowedNumericTypes::axpy_slices
+
+const LANES: usize = 4;
+
+macro_rules! unrolled_kernels {
+    ($float:ty, $dot:ident, $axpy:ident) => {
+        pub(crate) fn $dot(a: &[$float], b: &[$float]) -> $float {
+            let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+            let tail = a_chunks
+                .remainder()
+                .iter()
+                .zip(b_chunks.remainder())
+                .fold(0.0, |acc, (&x, &y)| madd(x, y, acc));
+            let mut sums = [0.0; LANES];
+            for (x, y) in a_chunks.zip(b_chunks) {
+                for ((s, &x), &y) in sums.iter_mut().zip(x).zip(y) {
+                    *s = madd(x, y, *s);
+                }
+            }
+            (sums[0] + sums[1]) + (sums[2] + sums[3]) + tail
+        }
+
+        pub(crate) fn $axpy(acc: &mut [$float], alpha: $float, x: &[$float]) {
+            for (a, &x) in acc.iter_mut().zip(x) {
+                *a = madd(alpha, x, *a);
+            }
+        }
+    };
+}
+
+// `a * b + c`, fused when the target supports it
+#[inline(always)]
+fn madd<F: MulAdd>(a: F, b: F, c: F) -> F {
+    F::madd(a, b, c)
+}
+
+trait MulAdd: Copy {
+    fn madd(a: Self, b: Self, c: Self) -> Self;
+}
+
+macro_rules! mul_add_impl {
+    ($float:ty) => {
+        impl MulAdd for $float {
+            #[inline(always)]
+            fn madd(a: Self, b: Self, c: Self) -> Self {
+                #[cfg(target_feature = "fma")]
+                return a.mul_add(b, c);
+                #[cfg(not(target_feature = "fma"))]
+                return a * b + c;
+            }
+        }
+    };
+}
+
+mul_add_impl!(f32);
+mul_add_impl!(f64);
+
+unrolled_kernels!(f32, dot_f32, axpy_f32);
+unrolled_kernels!(f64, dot_f64, axpy_f64);
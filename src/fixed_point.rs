@@ -0,0 +1,67 @@
+//! Fixed-point element types.
+//!
+//! Enabled by the `fixed` feature. The signed types of the `fixed` crate
+//! (`FixedI8` through `FixedI64`, e.g. `I16F16`) implement both numeric
+//! traits, so arithmetic is deterministic and float-free. `from_f64`
+//! saturates at the type's bounds and maps NaN to zero, matching the
+//! primitive integers. `epsilon` is the smallest step (`DELTA`) and `sqrt`
+//! is computed in fixed point (and panics on negative input); only `exp`
+//! and `tanh` round-trip through `f64`. Types without integer bits cannot
+//! hold `one`, which saturates to their maximum.
+
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes};
+use fixed::types::extra::{LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use fixed::{FixedI16, FixedI32, FixedI64, FixedI8};
+
+macro_rules! fixed_impls {
+    ($($fixed:ident: $bound:ident),+) => {
+        $(
+            impl<Frac: $bound + Send + Sync> AllowedNumericTypes for $fixed<Frac> {
+                fn zero() -> Self {
+                    Self::ZERO
+                }
+                fn one() -> Self {
+                    Self::saturating_from_num(1)
+                }
+                fn is_zero(&self) -> bool {
+                    *self == Self::ZERO
+                }
+                fn from_f64(value: f64) -> Self {
+                    if value.is_nan() {
+                        Self::ZERO
+                    } else {
+                        Self::saturating_from_num(value)
+                    }
+                }
+                fn to_f64(self) -> f64 {
+                    self.to_num()
+                }
+            }
+
+            impl<Frac: $bound + Send + Sync> AllowedFloatTypes for $fixed<Frac> {
+                fn epsilon() -> Self {
+                    Self::DELTA
+                }
+                fn abs(self) -> Self {
+                    self.saturating_abs()
+                }
+                fn sqrt(self) -> Self {
+                    $fixed::sqrt(self)
+                }
+                fn exp(self) -> Self {
+                    Self::from_f64(self.to_f64().exp())
+                }
+                fn tanh(self) -> Self {
+                    Self::from_f64(self.to_f64().tanh())
+                }
+            }
+        )+
+    };
+}
+
+fixed_impls!(
+    FixedI8: LeEqU8,
+    FixedI16: LeEqU16,
+    FixedI32: LeEqU32,
+    FixedI64: LeEqU64
+);
@@ -0,0 +1,53 @@
+//! Half-precision element types.
+//!
+//! Enabled by the `half` feature. [`half::f16`] and [`half::bf16`] implement
+//! both numeric traits, so weights can be stored at half the size of `f32`
+//! with the same API. Arithmetic happens in the half type itself; the
+//! transcendental functions round-trip through `f32`.
+
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes};
+use half::{bf16, f16};
+
+macro_rules! half_impls {
+    ($($ty:ty),+) => {
+        $(
+            impl AllowedNumericTypes for $ty {
+                fn zero() -> Self {
+                    <$ty>::ZERO
+                }
+                fn one() -> Self {
+                    <$ty>::ONE
+                }
+                fn is_zero(&self) -> bool {
+                    *self == <$ty>::ZERO
+                }
+                fn from_f64(value: f64) -> Self {
+                    <$ty>::from_f64(value)
+                }
+                fn to_f64(self) -> f64 {
+                    <$ty>::to_f64(self)
+                }
+            }
+
+            impl AllowedFloatTypes for $ty {
+                fn epsilon() -> Self {
+                    <$ty>::EPSILON
+                }
+                fn abs(self) -> Self {
+                    <$ty>::from_bits(self.to_bits() & 0x7fff)
+                }
+                fn sqrt(self) -> Self {
+                    <$ty>::from_f32(self.to_f32().sqrt())
+                }
+                fn exp(self) -> Self {
+                    <$ty>::from_f32(self.to_f32().exp())
+                }
+                fn tanh(self) -> Self {
+                    <$ty>::from_f32(self.to_f32().tanh())
+                }
+            }
+        )+
+    };
+}
+
+half_impls!(f16, bf16);
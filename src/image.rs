@@ -0,0 +1,423 @@
+//! Image-style operations on matrices.
+//!
+//! A matrix is treated as a single-channel image with row index `y` and
+//! column index `x`. Coordinates are computed in `f64`; sampled values keep
+//! the element type.
+//!
+//! Summed-area tables give O(1) rectangle sums for box filtering. They are
+//! accumulated in a caller-chosen type, e.g. `u32` for `u8` images, since
+//! the sums quickly outgrow the element type.
+//! Morphology works on grayscale values and therefore also on the 0/1 masks
+//! produced by `threshold`, which can then be split into blobs with
+//! [`label_connected_components`].
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes, CastFrom, Matrix};
+use crate::tensor_impl::count_as;
+
+/// Pixel adjacency used for connected component labeling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Horizontal and vertical neighbours.
+    Four,
+    /// Horizontal, vertical and diagonal neighbours.
+    Eight,
+}
+
+/// Sampling method for geometric transforms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Nearest pixel.
+    Nearest,
+    /// Weighted average of the four surrounding pixels.
+    Bilinear,
+}
+
+fn bilinear<T: AllowedFloatTypes, const N: usize>(m: &Matrix<T, N>, y: f64, x: f64) -> T {
+    let (y0, x0) = (y.floor(), x.floor());
+    let (fy, fx) = (y - y0, x - x0);
+    let (y0, x0) = (y0 as usize, x0 as usize);
+    let y1 = (y0 + 1).min(m.rows - 1);
+    let x1 = (x0 + 1).min(N - 1);
+
+    let w = |v: f64| T::from_f64(v);
+    let top = m[y0][x0] * w(1.0 - fx) + m[y0][x1] * w(fx);
+    let bottom = m[y1][x0] * w(1.0 - fx) + m[y1][x1] * w(fx);
+    top * w(1.0 - fy) + bottom * w(fy)
+}
+
+// Distance outside the image still treated as on the edge, absorbing
+// rounding error in transformed coordinates
+const EDGE_TOL: f64 = 1e-9;
+
+// Samples at (y, x); points outside the image read as zero
+fn sample<T: AllowedFloatTypes, const N: usize>(
+    m: &Matrix<T, N>,
+    y: f64,
+    x: f64,
+    interpolation: Interpolation,
+) -> T {
+    let max_y = m.rows as f64 - 1.0;
+    let max_x = N as f64 - 1.0;
+    let (y, x) = match interpolation {
+        Interpolation::Nearest => (y.round(), x.round()),
+        Interpolation::Bilinear => (y, x),
+    };
+    if y < -EDGE_TOL || x < -EDGE_TOL || y > max_y + EDGE_TOL || x > max_x + EDGE_TOL {
+        return T::zero();
+    }
+    let (y, x) = (y.clamp(0.0, max_y), x.clamp(0.0, max_x));
+    match interpolation {
+        Interpolation::Nearest => m[y as usize][x as usize],
+        Interpolation::Bilinear => bilinear(m, y, x),
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Resizes to `new_rows x M` with bilinear interpolation.
+    ///
+    /// Pixel centers are aligned (the OpenCV convention) and edges are
+    /// replicated. Returns `InvalidOperation` when resizing an empty matrix
+    /// to a non-empty one.
+    pub fn resize_bilinear<const M: usize>(
+        &self,
+        new_rows: usize,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        let mut result = Matrix::new(new_rows);
+        if new_rows == 0 || M == 0 {
+            return Ok(result);
+        }
+        if self.rows == 0 || N == 0 {
+            return Err(TensorError::InvalidOperation(
+                "cannot resize an empty matrix".to_string(),
+            ));
+        }
+
+        let scale_y = self.rows as f64 / new_rows as f64;
+        let scale_x = N as f64 / M as f64;
+        for r in 0..new_rows {
+            let y = ((r as f64 + 0.5) * scale_y - 0.5).clamp(0.0, self.rows as f64 - 1.0);
+            for c in 0..M {
+                let x = ((c as f64 + 0.5) * scale_x - 0.5).clamp(0.0, N as f64 - 1.0);
+                result[r][c] = bilinear(self, y, x);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Applies the affine map `[x', y'] = A [x, y] + t` given as the 2x3
+    /// matrix `[a00 a01 tx; a10 a11 ty]` in `(x = column, y = row)`
+    /// coordinates.
+    ///
+    /// The output has the same shape as the input; pixels that map from
+    /// outside the source are zero. Returns `DimensionMismatch` if the
+    /// transform does not have two rows and `InvalidOperation` if it is
+    /// singular.
+    pub fn warp_affine(
+        &self,
+        transform: &Matrix<T, 3>,
+        interpolation: Interpolation,
+    ) -> Result<Self, TensorError> {
+        if transform.rows != 2 {
+            return Err(TensorError::DimensionMismatch {
+                expected: "2x3".to_string(),
+                found: format!("{}x3", transform.rows),
+                operation: "warp_affine".to_string(),
+            });
+        }
+        let t = |r: usize, c: usize| transform[r][c].to_f64();
+        let (a, b, tx) = (t(0, 0), t(0, 1), t(0, 2));
+        let (c, d, ty) = (t(1, 0), t(1, 1), t(1, 2));
+        let det = a * d - b * c;
+        if det == 0.0 || !det.is_finite() {
+            return Err(TensorError::InvalidOperation(
+                "affine transform is singular".to_string(),
+            ));
+        }
+
+        // Inverse mapping from output pixels back into the source
+        let (ia, ib, ic, id) = (d / det, -b / det, -c / det, a / det);
+        Ok(Matrix::from_fn(self.rows, |r, col| {
+            let (x, y) = (col as f64 - tx, r as f64 - ty);
+            let sx = ia * x + ib * y;
+            let sy = ic * x + id * y;
+            sample(self, sy, sx, interpolation)
+        }))
+    }
+
+    /// Rotates counter-clockwise by `theta` degrees about the image center,
+    /// keeping the same shape. Corners rotated in from outside are zero.
+    /// Returns `InvalidOperation` if `theta` is not finite.
+    pub fn rotate_degrees(
+        &self,
+        theta: f64,
+        interpolation: Interpolation,
+    ) -> Result<Self, TensorError> {
+        if !theta.is_finite() {
+            return Err(TensorError::InvalidOperation(format!(
+                "rotation angle {} is not finite",
+                theta
+            )));
+        }
+        let (sin, cos) = theta.to_radians().sin_cos();
+        let cx = (N as f64 - 1.0) / 2.0;
+        let cy = (self.rows as f64 - 1.0) / 2.0;
+
+        // With y pointing down, a visually counter-clockwise turn uses -theta
+        let a = cos;
+        let b = sin;
+        let c = -sin;
+        let d = cos;
+        let tx = cx - a * cx - b * cy;
+        let ty = cy - c * cx - d * cy;
+
+        let w = T::from_f64;
+        let transform =
+            Matrix::from_vectors(vec![[w(a), w(b), w(tx)].into(), [w(c), w(d), w(ty)].into()]);
+        self.warp_affine(&transform, interpolation)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Summed-area table accumulated in `A`: element `(r, c)` holds the sum
+    /// of all elements in rows `0..=r` and columns `0..=c`. `A` must hold the
+    /// sum of the whole matrix; `A = T` keeps the element type.
+    pub fn integral_image<A: AllowedNumericTypes + From<T>>(&self) -> Matrix<A, N> {
+        let mut result = Matrix::new(self.rows);
+        for r in 0..self.rows {
+            let mut row_sum = A::zero();
+            for c in 0..N {
+                row_sum = row_sum + A::from(self[r][c]);
+                result[r][c] = if r > 0 {
+                    result[r - 1][c] + row_sum
+                } else {
+                    row_sum
+                };
+            }
+        }
+        result
+    }
+
+    /// Mean over the `(2 * radius + 1)^2` window centered on each element,
+    /// computed in O(1) per element from the integral image accumulated in
+    /// `A`.
+    ///
+    /// Windows are clipped at the edges and averaged over the elements that
+    /// remain. Integer element types use truncating division. Fails if `A`
+    /// cannot count the elements of a window.
+    pub fn box_filter<A>(&self, radius: usize) -> Result<Self, TensorError>
+    where
+        A: AllowedNumericTypes + From<T>,
+        T: CastFrom<A>,
+    {
+        let sat = self.integral_image::<A>();
+        // Sum of the inclusive rectangle, ordered to stay non-negative for
+        // unsigned accumulators
+        let rect = |r0: usize, c0: usize, r1: usize, c1: usize| {
+            let mut sum = sat[r1][c1];
+            if r0 > 0 && c0 > 0 {
+                sum = sum + sat[r0 - 1][c0 - 1];
+            }
+            if r0 > 0 {
+                sum = sum - sat[r0 - 1][c1];
+            }
+            if c0 > 0 {
+                sum = sum - sat[r1][c0 - 1];
+            }
+            sum
+        };
+
+        let mut result = Matrix::new(self.rows);
+        for r in 0..self.rows {
+            let (r0, r1) = (r.saturating_sub(radius), (r + radius).min(self.rows - 1));
+            for c in 0..N {
+                let (c0, c1) = (c.saturating_sub(radius), (c + radius).min(N - 1));
+                let count = count_as::<A>((r1 - r0 + 1) * (c1 - c0 + 1), "box_filter")?;
+                result[r][c] = T::cast_from(rect(r0, c0, r1, c1) / count);
+            }
+        }
+        Ok(result)
+    }
+}
+
+// Offsets (dy, dx) of the nonzero entries of an odd-sized structuring element
+fn structuring_offsets<T: AllowedNumericTypes, const K: usize>(
+    kernel: &Matrix<T, K>,
+) -> Result<Vec<(isize, isize)>, TensorError> {
+    if kernel.rows.is_multiple_of(2) || K.is_multiple_of(2) {
+        return Err(TensorError::InvalidOperation(format!(
+            "structuring element must have odd dimensions, got {}x{}",
+            kernel.rows, K
+        )));
+    }
+    let (cy, cx) = ((kernel.rows / 2) as isize, (K / 2) as isize);
+    let mut offsets = Vec::new();
+    for i in 0..kernel.rows {
+        for j in 0..K {
+            if !kernel[i][j].is_zero() {
+                offsets.push((i as isize - cy, j as isize - cx));
+            }
+        }
+    }
+    if offsets.is_empty() {
+        return Err(TensorError::InvalidOperation(
+            "structuring element has no active entries".to_string(),
+        ));
+    }
+    Ok(offsets)
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Matrix<T, N> {
+    /// Binary mask with `T::one()` where the element is greater than `t` and
+    /// `T::zero()` elsewhere.
+    pub fn threshold(&self, t: T) -> Self {
+        self.map(|x| if x > t { T::one() } else { T::zero() })
+    }
+
+    // Reduces each neighbourhood under the structuring element with `pick`,
+    // ignoring neighbours outside the matrix
+    fn morph<const K: usize>(
+        &self,
+        kernel: &Matrix<T, K>,
+        pick: impl Fn(T, T) -> bool,
+    ) -> Result<Self, TensorError> {
+        let offsets = structuring_offsets(kernel)?;
+        let mut result = Matrix::new(self.rows);
+        for r in 0..self.rows {
+            for c in 0..N {
+                let mut best = self[r][c];
+                for &(dy, dx) in offsets.iter() {
+                    let (y, x) = (r as isize + dy, c as isize + dx);
+                    if y < 0 || x < 0 || y >= self.rows as isize || x >= N as isize {
+                        continue;
+                    }
+                    let v = self[y as usize][x as usize];
+                    if pick(v, best) {
+                        best = v;
+                    }
+                }
+                result[r][c] = best;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Morphological erosion: minimum over the nonzero entries of the
+    /// odd-sized structuring element `kernel`, centered on each element.
+    ///
+    /// Neighbours outside the matrix are ignored.
+    pub fn erode<const K: usize>(&self, kernel: &Matrix<T, K>) -> Result<Self, TensorError> {
+        self.morph(kernel, |v, best| v < best)
+    }
+
+    /// Morphological dilation: maximum over the nonzero entries of the
+    /// odd-sized structuring element `kernel`, centered on each element.
+    ///
+    /// Neighbours outside the matrix are ignored.
+    pub fn dilate<const K: usize>(&self, kernel: &Matrix<T, K>) -> Result<Self, TensorError> {
+        self.morph(kernel, |v, best| v > best)
+    }
+
+    /// Erosion followed by dilation; removes specks smaller than `kernel`.
+    pub fn opening<const K: usize>(&self, kernel: &Matrix<T, K>) -> Result<Self, TensorError> {
+        self.erode(kernel)?.dilate(kernel)
+    }
+
+    /// Dilation followed by erosion; fills holes smaller than `kernel`.
+    pub fn closing<const K: usize>(&self, kernel: &Matrix<T, K>) -> Result<Self, TensorError> {
+        self.dilate(kernel)?.erode(kernel)
+    }
+}
+
+// Disjoint-set forest with path halving and union by smaller root
+struct UnionFind {
+    parent: Vec<u32>,
+}
+
+impl UnionFind {
+    fn make_set(&mut self) -> u32 {
+        let id = self.parent.len() as u32;
+        self.parent.push(id);
+        id
+    }
+
+    fn find(&mut self, mut x: u32) -> u32 {
+        while self.parent[x as usize] != x {
+            let grandparent = self.parent[self.parent[x as usize] as usize];
+            self.parent[x as usize] = grandparent;
+            x = grandparent;
+        }
+        x
+    }
+
+    fn union(&mut self, a: u32, b: u32) -> u32 {
+        let (a, b) = (self.find(a), self.find(b));
+        let (root, child) = if a < b { (a, b) } else { (b, a) };
+        self.parent[child as usize] = root;
+        root
+    }
+}
+
+/// Labels the connected regions of nonzero elements in `mask`.
+///
+/// Returns a label matrix where background is `0` and components are
+/// numbered `1..=count` in raster order of their first element, together
+/// with `count`.
+pub fn label_connected_components<T: AllowedNumericTypes, const N: usize>(
+    mask: &Matrix<T, N>,
+    connectivity: Connectivity,
+) -> (Matrix<u32, N>, u32) {
+    let rows = mask.rows;
+    let mut labels: Matrix<u32, N> = Matrix::new(rows);
+    // Provisional label 0 is reserved for the background
+    let mut sets = UnionFind { parent: vec![0] };
+
+    // First pass: provisional labels from already-visited neighbours
+    for r in 0..rows {
+        for c in 0..N {
+            if mask[r][c].is_zero() {
+                continue;
+            }
+            let mut neighbours = [0u32; 4];
+            if c > 0 {
+                neighbours[0] = labels[r][c - 1];
+            }
+            if r > 0 {
+                neighbours[1] = labels[r - 1][c];
+                if connectivity == Connectivity::Eight {
+                    if c > 0 {
+                        neighbours[2] = labels[r - 1][c - 1];
+                    }
+                    if c + 1 < N {
+                        neighbours[3] = labels[r - 1][c + 1];
+                    }
+                }
+            }
+
+            let mut label = 0;
+            for &n in neighbours.iter().filter(|&&n| n != 0) {
+                label = if label == 0 { n } else { sets.union(label, n) };
+            }
+            labels[r][c] = if label == 0 { sets.make_set() } else { label };
+        }
+    }
+
+    // Second pass: resolve equivalences into consecutive final labels
+    let mut final_label = vec![0u32; sets.parent.len()];
+    let mut count = 0;
+    for r in 0..rows {
+        for c in 0..N {
+            let provisional = labels[r][c];
+            if provisional == 0 {
+                continue;
+            }
+            let root = sets.find(provisional) as usize;
+            if final_label[root] == 0 {
+                count += 1;
+                final_label[root] = count;
+            }
+            labels[r][c] = final_label[root];
+        }
+    }
+    (labels, count)
+}
@@ -0,0 +1,126 @@
+//! Conversions to and from graphics math types.
+//!
+//! Enabled by the `glam` and `cgmath` features. Vectors convert directly.
+//! Matrices keep their mathematical orientation: row `i` of a [`Matrix`]
+//! becomes row `i` of the column-major graphics matrix. Converting a
+//! [`Matrix`] checks that it is square, so that direction uses `TryFrom`.
+
+use crate::error::TensorError;
+use crate::tensor::{Matrix, Vector};
+
+fn check_square<const N: usize>(m: &Matrix<f32, N>, target: &str) -> Result<(), TensorError> {
+    if m.rows != N {
+        return Err(TensorError::DimensionMismatch {
+            expected: format!("{}x{}", N, N),
+            found: format!("{}x{}", m.rows, N),
+            operation: format!("conversion to {}", target),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::*;
+    use glam::{Mat3, Mat4, Vec2, Vec3, Vec4};
+
+    macro_rules! glam_vector {
+        ($glam:ty, $n:literal) => {
+            impl From<$glam> for Vector<f32, $n> {
+                fn from(v: $glam) -> Self {
+                    Vector::from(v.to_array())
+                }
+            }
+
+            impl From<Vector<f32, $n>> for $glam {
+                fn from(v: Vector<f32, $n>) -> Self {
+                    <$glam>::from_array(v.data)
+                }
+            }
+        };
+    }
+
+    glam_vector!(Vec2, 2);
+    glam_vector!(Vec3, 3);
+    glam_vector!(Vec4, 4);
+
+    macro_rules! glam_matrix {
+        ($glam:ty, $n:literal) => {
+            impl From<$glam> for Matrix<f32, $n> {
+                fn from(m: $glam) -> Self {
+                    let rows = m.transpose().to_cols_array_2d();
+                    Matrix::from_vectors(rows.into_iter().map(Vector::from).collect())
+                }
+            }
+
+            impl TryFrom<&Matrix<f32, $n>> for $glam {
+                type Error = TensorError;
+
+                fn try_from(m: &Matrix<f32, $n>) -> Result<Self, Self::Error> {
+                    check_square(m, stringify!($glam))?;
+                    let rows: [[f32; $n]; $n] = std::array::from_fn(|i| m[i].data);
+                    Ok(<$glam>::from_cols_array_2d(&rows).transpose())
+                }
+            }
+        };
+    }
+
+    glam_matrix!(Mat3, 3);
+    glam_matrix!(Mat4, 4);
+}
+
+#[cfg(feature = "cgmath")]
+mod cgmath_impls {
+    use super::*;
+    use cgmath::{Matrix3, Matrix4, Vector2, Vector3, Vector4};
+
+    macro_rules! cgmath_vector {
+        ($cg:ident, $n:literal) => {
+            impl From<$cg<f32>> for Vector<f32, $n> {
+                fn from(v: $cg<f32>) -> Self {
+                    let array: [f32; $n] = v.into();
+                    Vector::from(array)
+                }
+            }
+
+            impl From<Vector<f32, $n>> for $cg<f32> {
+                fn from(v: Vector<f32, $n>) -> Self {
+                    v.data.into()
+                }
+            }
+        };
+    }
+
+    cgmath_vector!(Vector2, 2);
+    cgmath_vector!(Vector3, 3);
+    cgmath_vector!(Vector4, 4);
+
+    macro_rules! cgmath_matrix {
+        ($cg:ident, $n:literal) => {
+            impl From<$cg<f32>> for Matrix<f32, $n> {
+                fn from(m: $cg<f32>) -> Self {
+                    // cgmath indexes as m[column][row]
+                    Matrix::from_vectors(
+                        (0..$n)
+                            .map(|i| Vector::from(std::array::from_fn(|j| m[j][i])))
+                            .collect(),
+                    )
+                }
+            }
+
+            impl TryFrom<&Matrix<f32, $n>> for $cg<f32> {
+                type Error = TensorError;
+
+                fn try_from(m: &Matrix<f32, $n>) -> Result<Self, Self::Error> {
+                    check_square(m, stringify!($cg))?;
+                    let cols: [[f32; $n]; $n] =
+                        std::array::from_fn(|j| std::array::from_fn(|i| m[i][j]));
+                    Ok(cols.into())
+                }
+            }
+        };
+    }
+
+    cgmath_matrix!(Matrix3, 3);
+    cgmath_matrix!(Matrix4, 4);
+}
@@ -0,0 +1,33 @@
+//! Reading and writing matrices in file formats used by other tools.
+
+pub mod binary;
+pub mod csv;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod npy;
+pub mod npz;
+
+use crate::error::TensorError;
+use std::io::Read;
+
+fn io_error(format: &str, e: std::io::Error) -> TensorError {
+    TensorError::Other(format!("{} I/O error: {}", format, e))
+}
+
+// Reads exactly `len` bytes. The buffer grows with the data actually read,
+// so a corrupt length in a header cannot force a huge allocation up front
+pub(crate) fn read_exactly<R: Read>(
+    reader: &mut R,
+    len: usize,
+    format: &str,
+) -> Result<Vec<u8>, TensorError> {
+    let mut bytes = Vec::new();
+    reader
+        .take(len as u64)
+        .read_to_end(&mut bytes)
+        .map_err(|e| io_error(format, e))?;
+    if bytes.len() != len {
+        return Err(io_error(format, std::io::ErrorKind::UnexpectedEof.into()));
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,215 @@
+//! A compact binary format for fast checkpoints.
+//!
+//! A file is a fixed header followed by the raw elements in row-major
+//! order:
+//!
+//! | bytes | contents |
+//! |-------|----------|
+//! | 8     | magic `b"TALGBIN\0"` |
+//! | 1     | format version, currently 1 |
+//! | 1     | byte order of the dimensions and elements: 0 little, 1 big |
+//! | 2     | dtype tag as in NumPy, e.g. `b"f8"` |
+//! | 1     | number of dimensions |
+//! | 8 each | dimensions as `u64` |
+//!
+//! [`write_binary`] takes the byte order as an [`Endian`]; [`read_binary`]
+//! accepts either and checks the dtype and shape against the requested
+//! type. Element types are those of [`NpyElement`].
+
+use super::npy::{matrix_from_array, shape_string, tensor_from_array, NpyElement};
+use super::{io_error, read_exactly};
+use crate::error::TensorError;
+use crate::tensor::{Matrix, Tensor, Vector};
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+const MAGIC: &[u8; 8] = b"TALGBIN\0";
+const VERSION: u8 = 1;
+
+/// Byte order of the elements and dimensions of a binary file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+    /// The byte order of the machine writing the file.
+    Native,
+}
+
+impl Endian {
+    fn is_little(self) -> bool {
+        match self {
+            Endian::Little => true,
+            Endian::Big => false,
+            Endian::Native => cfg!(target_endian = "little"),
+        }
+    }
+}
+
+/// A vector, matrix or tensor that can be stored in the binary format.
+pub trait BinaryArray: Sized {
+    type Element: NpyElement;
+
+    fn binary_shape(&self) -> Vec<usize>;
+
+    /// The elements in row-major order.
+    fn binary_elements(&self) -> Cow<'_, [Self::Element]>;
+
+    /// Rebuilds the value from a row-major array of `shape`.
+    fn from_binary(shape: &[usize], data: &[Self::Element]) -> Result<Self, TensorError>;
+}
+
+impl<T: NpyElement, const N: usize> BinaryArray for Vector<T, N> {
+    type Element = T;
+
+    fn binary_shape(&self) -> Vec<usize> {
+        vec![N]
+    }
+
+    fn binary_elements(&self) -> Cow<'_, [T]> {
+        Cow::Borrowed(self.as_slice())
+    }
+
+    fn from_binary(shape: &[usize], data: &[T]) -> Result<Self, TensorError> {
+        match shape {
+            [len] if *len == N => Vector::from_slice(data),
+            _ => Err(TensorError::DimensionMismatch {
+                expected: N.to_string(),
+                found: shape_string(shape),
+                operation: "read_binary".to_string(),
+            }),
+        }
+    }
+}
+
+impl<T: NpyElement, const N: usize> BinaryArray for Matrix<T, N> {
+    type Element = T;
+
+    fn binary_shape(&self) -> Vec<usize> {
+        vec![self.rows, N]
+    }
+
+    fn binary_elements(&self) -> Cow<'_, [T]> {
+        Cow::Borrowed(self.as_slice())
+    }
+
+    fn from_binary(shape: &[usize], data: &[T]) -> Result<Self, TensorError> {
+        matrix_from_array(shape, data, "read_binary")
+    }
+}
+
+impl<T: NpyElement, const N: usize> BinaryArray for Tensor<T, N> {
+    type Element = T;
+
+    fn binary_shape(&self) -> Vec<usize> {
+        let (depths, rows, _) = self.shape();
+        vec![depths, rows, N]
+    }
+
+    fn binary_elements(&self) -> Cow<'_, [T]> {
+        Cow::Borrowed(self.as_slice())
+    }
+
+    fn from_binary(shape: &[usize], data: &[T]) -> Result<Self, TensorError> {
+        tensor_from_array(shape, data, "read_binary")
+    }
+}
+
+fn format_error(msg: impl Into<String>) -> TensorError {
+    TensorError::Other(format!("invalid binary data: {}", msg.into()))
+}
+
+// The header of a file holding an array of `shape` with elements of type `E`
+pub(crate) fn binary_header<E: NpyElement>(shape: &[usize], little: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(13 + 8 * shape.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[VERSION, !little as u8]);
+    out.extend_from_slice(E::DTYPE.as_bytes());
+    out.push(shape.len() as u8);
+    for &d in shape {
+        let d = d as u64;
+        out.extend_from_slice(&if little {
+            d.to_le_bytes()
+        } else {
+            d.to_be_bytes()
+        });
+    }
+    out
+}
+
+// Reads and checks a header for elements of type `E`, returning the shape
+// and whether the data is little-endian. The reader is left at the first
+// element.
+pub(crate) fn read_binary_header<E: NpyElement, R: Read>(
+    reader: &mut R,
+) -> Result<(Vec<usize>, bool), TensorError> {
+    let header = read_exactly(reader, 13, "binary")?;
+    if &header[..8] != MAGIC {
+        return Err(format_error("missing magic string"));
+    }
+    if header[8] != VERSION {
+        return Err(format_error(format!("unsupported version {}", header[8])));
+    }
+    let little = match header[9] {
+        0 => true,
+        1 => false,
+        b => return Err(format_error(format!("unknown byte order {}", b))),
+    };
+    let dtype = String::from_utf8_lossy(&header[10..12]);
+    if dtype != E::DTYPE {
+        return Err(TensorError::InvalidOperation(format!(
+            "binary dtype '{}' does not match the element type '{}'",
+            dtype,
+            E::DTYPE
+        )));
+    }
+
+    let dims = read_exactly(reader, 8 * header[12] as usize, "binary")?;
+    let shape = dims
+        .chunks_exact(8)
+        .map(|d| {
+            let d = d.try_into().expect("dimension size");
+            let d = if little {
+                u64::from_le_bytes(d)
+            } else {
+                u64::from_be_bytes(d)
+            };
+            usize::try_from(d)
+        })
+        .collect::<Result<Vec<usize>, _>>()
+        .map_err(|_| format_error("dimension too large"))?;
+    Ok((shape, little))
+}
+
+/// Writes `value` with its dimensions and elements in `endian` byte order.
+pub fn write_binary<V: BinaryArray, W: Write>(
+    writer: &mut W,
+    value: &V,
+    endian: Endian,
+) -> Result<(), TensorError> {
+    let little = endian.is_little();
+    let elements = value.binary_elements();
+    let mut out = binary_header::<V::Element>(&value.binary_shape(), little);
+    out.reserve(elements.len() * <V::Element as NpyElement>::SIZE);
+    for &x in elements.iter() {
+        x.write(&mut out, little);
+    }
+    writer.write_all(&out).map_err(|e| io_error("binary", e))
+}
+
+/// Reads a value written by [`write_binary`] in either byte order.
+pub fn read_binary<V: BinaryArray, R: Read>(reader: &mut R) -> Result<V, TensorError> {
+    let (shape, little) = read_binary_header::<V::Element, _>(reader)?;
+    let size = <V::Element as NpyElement>::SIZE;
+    let len = shape
+        .iter()
+        .try_fold(size, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| format_error("shape too large"))?;
+
+    let bytes = read_exactly(reader, len, "binary")?;
+    let data: Vec<V::Element> = bytes
+        .chunks_exact(size)
+        .map(|b| V::Element::read(b, little))
+        .collect();
+    V::from_binary(&shape, &data)
+}
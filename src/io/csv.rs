@@ -0,0 +1,114 @@
+//! Comma-separated text.
+//!
+//! [`Matrix::from_csv`] reads one matrix row per line, skipping blank lines
+//! and, if asked, a header line. Fields are trimmed and may be wrapped in
+//! double quotes; every row must have exactly `N` fields.
+//! [`Matrix::to_csv`] writes the shortest representation of each element,
+//! so floats read back exactly.
+
+use super::io_error;
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix};
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+/// How fields are separated and whether the first line names the columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// Skip the first non-blank line when reading.
+    pub has_header: bool,
+}
+
+impl CsvOptions {
+    pub const DEFAULT: CsvOptions = CsvOptions {
+        delimiter: ',',
+        has_header: false,
+    };
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+fn parse_field<T: FromStr>(field: &str, line: usize, col: usize) -> Result<T, TensorError> {
+    let field = field.trim();
+    let unquoted = field
+        .strip_prefix('"')
+        .and_then(|f| f.strip_suffix('"'))
+        .unwrap_or(field)
+        .trim();
+    unquoted.parse().map_err(|_| {
+        TensorError::Other(format!(
+            "invalid csv data: line {}, column {}: cannot parse '{}'",
+            line,
+            col + 1,
+            field
+        ))
+    })
+}
+
+impl<T: AllowedNumericTypes + FromStr, const N: usize> Matrix<T, N> {
+    /// Reads a matrix with one row per line.
+    pub fn from_csv<R: BufRead>(reader: R, options: CsvOptions) -> Result<Self, TensorError> {
+        let mut data = Vec::new();
+        let mut skip_header = options.has_header;
+        for (index, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| io_error("csv", e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+            let fields: Vec<&str> = line.split(options.delimiter).collect();
+            if fields.len() != N {
+                return Err(TensorError::DimensionMismatch {
+                    expected: format!("1x{}", N),
+                    found: format!("1x{}", fields.len()),
+                    operation: format!("from_csv (line {})", index + 1),
+                });
+            }
+            for (col, field) in fields.into_iter().enumerate() {
+                data.push(parse_field(field, index + 1, col)?);
+            }
+        }
+        let rows = data.len() / N.max(1);
+        Ok(Matrix::from_fn(rows, |i, j| data[i * N + j]))
+    }
+}
+
+impl<T: AllowedNumericTypes + Display, const N: usize> Matrix<T, N> {
+    /// Writes the matrix comma-separated without a header.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), TensorError> {
+        self.to_csv_with(writer, CsvOptions::DEFAULT, None)
+    }
+
+    /// Writes the matrix with `options.delimiter`, preceded by `header` if
+    /// given.
+    pub fn to_csv_with<W: Write>(
+        &self,
+        mut writer: W,
+        options: CsvOptions,
+        header: Option<&[&str; N]>,
+    ) -> Result<(), TensorError> {
+        let delimiter = options.delimiter.to_string();
+        let mut out = String::new();
+        if let Some(header) = header {
+            out.push_str(&header.join(&delimiter));
+            out.push('\n');
+        }
+        for row in self.as_slice().chunks(N.max(1)) {
+            let fields: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+            out.push_str(&fields.join(&delimiter));
+            out.push('\n');
+        }
+        writer
+            .write_all(out.as_bytes())
+            .map_err(|e| io_error("csv", e))
+    }
+}
@@ -0,0 +1,98 @@
+//! JSON text as nested arrays.
+//!
+//! Enabled by the `serde` feature. Vectors are written as `[1, 2]`, matrices
+//! as arrays of rows and tensors as arrays of depth matrices, the same
+//! representation their `Serialize` impls produce, so a tensor with no
+//! depths reads back with no rows either. Reading reports a row of
+//! the wrong length or ragged depths as a `DimensionMismatch` naming the
+//! offending row, and malformed JSON as `TensorError::Other`. JSON has no
+//! NaN or infinity, so writing an array holding one fails with
+//! `TensorError::InvalidOperation` rather than producing `null`.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn parse<V: DeserializeOwned>(json: &str) -> Result<V, TensorError> {
+    serde_json::from_str(json).map_err(|e| TensorError::Other(format!("invalid json: {}", e)))
+}
+
+fn write<T: AllowedNumericTypes, V: Serialize + ?Sized>(
+    value: &V,
+    elements: &[T],
+    operation: &str,
+) -> Result<String, TensorError> {
+    if let Some(i) = elements.iter().position(|x| !x.to_f64().is_finite()) {
+        return Err(TensorError::InvalidOperation(format!(
+            "{}: element {} is not finite and has no JSON representation",
+            operation, i
+        )));
+    }
+    serde_json::to_string(value).map_err(|e| TensorError::Other(format!("json: {}", e)))
+}
+
+fn row<T: AllowedNumericTypes, const N: usize>(
+    elements: &[T],
+    operation: &str,
+) -> Result<Vector<T, N>, TensorError> {
+    Vector::from_slice(elements).map_err(|_| TensorError::DimensionMismatch {
+        expected: format!("1x{}", N),
+        found: format!("1x{}", elements.len()),
+        operation: operation.to_string(),
+    })
+}
+
+impl<T: AllowedNumericTypes + Serialize + DeserializeOwned, const N: usize> Vector<T, N> {
+    /// Fails on NaN or infinite elements.
+    pub fn to_json(&self) -> Result<String, TensorError> {
+        write(self, self.as_slice(), "Vector::to_json")
+    }
+
+    /// Parses an array of exactly `N` numbers.
+    pub fn from_json(json: &str) -> Result<Self, TensorError> {
+        let elements: Vec<T> = parse(json)?;
+        row(&elements, "Vector::from_json")
+    }
+}
+
+impl<T: AllowedNumericTypes + Serialize + DeserializeOwned, const N: usize> Matrix<T, N> {
+    /// Fails on NaN or infinite elements.
+    pub fn to_json(&self) -> Result<String, TensorError> {
+        write(self, self.as_slice(), "Matrix::to_json")
+    }
+
+    /// Parses an array of rows, each of exactly `N` numbers.
+    pub fn from_json(json: &str) -> Result<Self, TensorError> {
+        let rows: Vec<Vec<T>> = parse(json)?;
+        rows.iter()
+            .enumerate()
+            .map(|(i, r)| row(r, &format!("Matrix::from_json (row {})", i)))
+            .collect::<Result<_, _>>()
+            .map(Matrix::from_vectors)
+    }
+}
+
+impl<T: AllowedNumericTypes + Serialize + DeserializeOwned, const N: usize> Tensor<T, N> {
+    /// Fails on NaN or infinite elements.
+    pub fn to_json(&self) -> Result<String, TensorError> {
+        write(self, self.as_slice(), "Tensor::to_json")
+    }
+
+    /// Parses an array of matrices with equal row counts.
+    pub fn from_json(json: &str) -> Result<Self, TensorError> {
+        let depths: Vec<Vec<Vec<T>>> = parse(json)?;
+        let matrices = depths
+            .iter()
+            .enumerate()
+            .map(|(d, rows)| {
+                rows.iter()
+                    .enumerate()
+                    .map(|(i, r)| row(r, &format!("Tensor::from_json (depth {}, row {})", d, i)))
+                    .collect::<Result<_, _>>()
+                    .map(Matrix::from_vectors)
+            })
+            .collect::<Result<_, _>>()?;
+        Tensor::from_matrices(matrices)
+    }
+}
@@ -0,0 +1,328 @@
+//! NumPy `.npy` files.
+//!
+//! [`write_npy`] stores a [`Matrix`] as a little-endian, C-order 2-D array
+//! that `numpy.load` reads directly. [`read_npy`] accepts either byte order
+//! and either memory order, and checks that the dtype matches the element
+//! type and the second axis has exactly `N` columns. Supported element
+//! types are `f32`, `f64`, `i32` and `i64`.
+
+use super::{io_error, read_exactly};
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Header length alignment required by the format
+const HEADER_ALIGN: usize = 64;
+
+/// An element type with a NumPy dtype.
+pub trait NpyElement: AllowedNumericTypes {
+    /// Kind and size in bytes, as in the `descr` field without its byte
+    /// order, e.g. `"f8"`.
+    const DTYPE: &'static str;
+    const SIZE: usize;
+
+    /// Appends the element's `SIZE` bytes.
+    fn write(self, out: &mut Vec<u8>, little_endian: bool);
+
+    /// Decodes one element from exactly `SIZE` bytes.
+    fn read(bytes: &[u8], little_endian: bool) -> Self;
+}
+
+macro_rules! npy_elements {
+    ($($ty:ty => $dtype:literal),+) => {
+        $(
+            impl NpyElement for $ty {
+                const DTYPE: &'static str = $dtype;
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn write(self, out: &mut Vec<u8>, little_endian: bool) {
+                    if little_endian {
+                        out.extend_from_slice(&self.to_le_bytes());
+                    } else {
+                        out.extend_from_slice(&self.to_be_bytes());
+                    }
+                }
+
+                fn read(bytes: &[u8], little_endian: bool) -> Self {
+                    let bytes = bytes.try_into().expect("element size");
+                    if little_endian {
+                        <$ty>::from_le_bytes(bytes)
+                    } else {
+                        <$ty>::from_be_bytes(bytes)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+npy_elements!(f32 => "f4", f64 => "f8", i32 => "i4", i64 => "i8");
+
+fn format_error(msg: impl Into<String>) -> TensorError {
+    TensorError::Other(format!("invalid npy data: {}", msg.into()))
+}
+
+pub(crate) fn shape_string(shape: &[usize]) -> String {
+    shape
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join("x")
+}
+
+// Writes a complete C-order array of `shape` holding `data`
+pub(crate) fn write_array<T: NpyElement, W: Write>(
+    writer: &mut W,
+    shape: &[usize],
+    data: &[T],
+) -> Result<(), TensorError> {
+    let dims: Vec<String> = shape.iter().map(|d| d.to_string()).collect();
+    let tuple = match dims.len() {
+        1 => format!("({},)", dims[0]),
+        _ => format!("({})", dims.join(", ")),
+    };
+    let mut header = format!(
+        "{{'descr': '<{}', 'fortran_order': False, 'shape': {}, }}",
+        T::DTYPE,
+        tuple
+    );
+
+    // Version 1.0 stores the header length in two bytes, 2.0 in four
+    let (version, len_bytes) = if header.len() + HEADER_ALIGN > u16::MAX as usize {
+        (2, 4)
+    } else {
+        (1, 2)
+    };
+    let prefix = MAGIC.len() + 2 + len_bytes;
+    let padded = (prefix + header.len() + 1).div_ceil(HEADER_ALIGN) * HEADER_ALIGN;
+    header.extend(std::iter::repeat_n(' ', padded - prefix - header.len() - 1));
+    header.push('\n');
+
+    let mut out = Vec::with_capacity(padded + data.len() * T::SIZE);
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        out.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    }
+    out.extend_from_slice(header.as_bytes());
+    for &x in data {
+        x.write(&mut out, true);
+    }
+    writer.write_all(&out).map_err(|e| io_error("npy", e))
+}
+
+// The text following `'key':` in a header dictionary
+fn field<'a>(header: &'a str, key: &str) -> Result<&'a str, TensorError> {
+    [format!("'{}'", key), format!("\"{}\"", key)]
+        .iter()
+        .find_map(|quoted| {
+            header
+                .find(quoted.as_str())
+                .map(|i| &header[i + quoted.len()..])
+        })
+        .and_then(|rest| rest.trim_start().strip_prefix(':'))
+        .map(str::trim_start)
+        .ok_or_else(|| format_error(format!("header has no '{}'", key)))
+}
+
+struct Header {
+    little_endian: bool,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+fn parse_header<T: NpyElement>(header: &str) -> Result<Header, TensorError> {
+    let descr = field(header, "descr")?;
+    let quote = descr
+        .chars()
+        .next()
+        .filter(|&c| c == '\'' || c == '"')
+        .ok_or_else(|| format_error("descr is not a string"))?;
+    let descr = descr[1..]
+        .split(quote)
+        .next()
+        .ok_or_else(|| format_error("unterminated descr"))?;
+    let mut chars = descr.chars();
+    let little_endian = match chars.next() {
+        Some('<') => true,
+        Some('>') => false,
+        Some('=') => cfg!(target_endian = "little"),
+        _ => return Err(format_error(format!("unsupported descr '{}'", descr))),
+    };
+    let dtype = chars.as_str();
+    if dtype != T::DTYPE {
+        return Err(TensorError::InvalidOperation(format!(
+            "npy dtype '{}' does not match the element type '{}'",
+            descr,
+            T::DTYPE
+        )));
+    }
+
+    let fortran_order = match field(header, "fortran_order")? {
+        f if f.starts_with("True") => true,
+        f if f.starts_with("False") => false,
+        _ => return Err(format_error("fortran_order is not a bool")),
+    };
+
+    let shape = field(header, "shape")?
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| format_error("shape is not a tuple"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            d.parse()
+                .map_err(|_| format_error(format!("bad dimension '{}'", d)))
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    Ok(Header {
+        little_endian,
+        fortran_order,
+        shape,
+    })
+}
+
+// Reads a complete array, returning its shape and its elements in C order
+pub(crate) fn read_array<T: NpyElement, R: Read>(
+    reader: &mut R,
+) -> Result<(Vec<usize>, Vec<T>), TensorError> {
+    let mut prefix = [0u8; 8];
+    reader
+        .read_exact(&mut prefix)
+        .map_err(|e| io_error("npy", e))?;
+    if &prefix[..6] != MAGIC {
+        return Err(format_error("missing magic string"));
+    }
+    let header_len = match prefix[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader
+                .read_exact(&mut len)
+                .map_err(|e| io_error("npy", e))?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader
+                .read_exact(&mut len)
+                .map_err(|e| io_error("npy", e))?;
+            u32::from_le_bytes(len) as usize
+        }
+        v => return Err(format_error(format!("unsupported version {}", v))),
+    };
+    let header = read_exactly(reader, header_len, "npy")?;
+    let header = String::from_utf8_lossy(&header);
+    let header = parse_header::<T>(&header)?;
+
+    let len = header
+        .shape
+        .iter()
+        .try_fold(T::SIZE, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| format_error("shape too large"))?;
+    let bytes = read_exactly(reader, len, "npy")?;
+    let mut data: Vec<T> = bytes
+        .chunks_exact(T::SIZE)
+        .map(|b| T::read(b, header.little_endian))
+        .collect();
+
+    if header.fortran_order && header.shape.len() > 1 {
+        data = fortran_to_c(&data, &header.shape);
+    }
+    Ok((header.shape, data))
+}
+
+// Reorders a column-major array of `shape` into row-major order
+fn fortran_to_c<T: Copy>(data: &[T], shape: &[usize]) -> Vec<T> {
+    let mut strides = vec![1; shape.len()];
+    for axis in 1..shape.len() {
+        strides[axis] = strides[axis - 1] * shape[axis - 1];
+    }
+    (0..data.len())
+        .map(|mut k| {
+            // Split the C-order index into per-axis indices, last axis first
+            let mut offset = 0;
+            for (&d, &stride) in shape.iter().zip(strides.iter()).rev() {
+                offset += (k % d) * stride;
+                k /= d;
+            }
+            data[offset]
+        })
+        .collect()
+}
+
+/// Writes `m` as a `rows x N` `.npy` array.
+pub fn write_npy<T: NpyElement, const N: usize, W: Write>(
+    writer: &mut W,
+    m: &Matrix<T, N>,
+) -> Result<(), TensorError> {
+    write_array(writer, &[m.rows, N], m.as_slice())
+}
+
+/// Reads a 2-D `.npy` array with `N` columns.
+pub fn read_npy<T: NpyElement, const N: usize, R: Read>(
+    reader: &mut R,
+) -> Result<Matrix<T, N>, TensorError> {
+    let (shape, data) = read_array::<T, R>(reader)?;
+    matrix_from_array(&shape, &data, "read_npy")
+}
+
+// A `rows x N` matrix from a C-order array, which must be 2-D with `N`
+// columns
+pub(crate) fn matrix_from_array<T: NpyElement, const N: usize>(
+    shape: &[usize],
+    data: &[T],
+    operation: &str,
+) -> Result<Matrix<T, N>, TensorError> {
+    match shape[..] {
+        [rows, cols] if cols == N => Ok(Matrix::from_fn(rows, |i, j| data[i * N + j])),
+        _ => Err(TensorError::DimensionMismatch {
+            expected: format!("?x{}", N),
+            found: shape_string(shape),
+            operation: operation.to_string(),
+        }),
+    }
+}
+
+// A `depths x rows x N` tensor from a C-order array, which must be 3-D with
+// `N` columns
+pub(crate) fn tensor_from_array<T: NpyElement, const N: usize>(
+    shape: &[usize],
+    data: &[T],
+    operation: &str,
+) -> Result<Tensor<T, N>, TensorError> {
+    match shape[..] {
+        [depths, rows, cols] if cols == N => Tensor::from_vec(depths, rows, data.to_vec()),
+        _ => Err(TensorError::DimensionMismatch {
+            expected: format!("?x?x{}", N),
+            found: shape_string(shape),
+            operation: operation.to_string(),
+        }),
+    }
+}
+
+/// Saves `m` to the `.npy` file at `path`.
+pub fn save_npy<T: NpyElement, const N: usize>(
+    path: impl AsRef<Path>,
+    m: &Matrix<T, N>,
+) -> Result<(), TensorError> {
+    let file = File::create(path).map_err(|e| io_error("npy", e))?;
+    let mut writer = BufWriter::new(file);
+    write_npy(&mut writer, m)?;
+    writer.flush().map_err(|e| io_error("npy", e))
+}
+
+/// Loads a matrix from the `.npy` file at `path`.
+pub fn load_npy<T: NpyElement, const N: usize>(
+    path: impl AsRef<Path>,
+) -> Result<Matrix<T, N>, TensorError> {
+    let file = File::open(path).map_err(|e| io_error("npy", e))?;
+    read_npy(&mut BufReader::new(file))
+}
@@ -0,0 +1,274 @@
+//! NumPy `.npz` archives of several named arrays.
+//!
+//! An archive is a zip file holding one `.npy` file per array, as written by
+//! `numpy.savez`. [`save_npz`] stores matrices and tensors under their names;
+//! [`load_npz`] reads every entry and [`NpzArchive::matrix`] and
+//! [`NpzArchive::tensor`] decode them with the same checks as
+//! [`read_npy`](super::npy::read_npy). Entries are stored uncompressed;
+//! archives from `numpy.savez_compressed` are rejected.
+
+use super::io_error;
+use super::npy::{matrix_from_array, read_array, tensor_from_array, write_array, NpyElement};
+use crate::error::TensorError;
+use crate::tensor::{Matrix, Tensor};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::Path;
+
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_DIRECTORY: u32 = 0x0605_4b50;
+const LOCAL_HEADER_LEN: usize = 30;
+const CENTRAL_HEADER_LEN: usize = 46;
+const END_OF_DIRECTORY_LEN: usize = 22;
+
+// Zip version 2.0, the minimum for plain stored entries
+const ZIP_VERSION: u16 = 20;
+// 1980-01-01, the earliest date a zip entry can carry
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+/// A matrix or tensor that can be stored in an archive.
+pub trait NpyArray {
+    /// Appends the array as a complete `.npy` file.
+    fn write_npy_to(&self, out: &mut Vec<u8>) -> Result<(), TensorError>;
+}
+
+impl<T: NpyElement, const N: usize> NpyArray for Matrix<T, N> {
+    fn write_npy_to(&self, out: &mut Vec<u8>) -> Result<(), TensorError> {
+        write_array(out, &[self.rows, N], self.as_slice())
+    }
+}
+
+impl<T: NpyElement, const N: usize> NpyArray for Tensor<T, N> {
+    fn write_npy_to(&self, out: &mut Vec<u8>) -> Result<(), TensorError> {
+        let (depths, rows, _) = self.shape();
+        write_array(out, &[depths, rows, N], self.as_slice())
+    }
+}
+
+fn format_error(msg: impl Into<String>) -> TensorError {
+    TensorError::Other(format!("invalid npz data: {}", msg.into()))
+}
+
+// CRC-32 (IEEE), as required for every zip entry
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn to_u32(n: usize) -> Result<u32, TensorError> {
+    u32::try_from(n).map_err(|_| format_error("archive larger than 4 GiB"))
+}
+
+fn put_u16(out: &mut Vec<u8>, x: u16) {
+    out.extend_from_slice(&x.to_le_bytes());
+}
+
+fn put_u32(out: &mut Vec<u8>, x: u32) {
+    out.extend_from_slice(&x.to_le_bytes());
+}
+
+/// Writes `arrays` as an `.npz` archive; each is stored as `<name>.npy`.
+pub fn write_npz<W: Write>(
+    writer: &mut W,
+    arrays: &[(&str, &dyn NpyArray)],
+) -> Result<(), TensorError> {
+    let mut out = Vec::new();
+    let mut directory = Vec::new();
+    for &(name, array) in arrays {
+        let file_name = format!("{}.npy", name);
+        let mut data = Vec::new();
+        array.write_npy_to(&mut data)?;
+        let (crc, size, offset) = (crc32(&data), to_u32(data.len())?, to_u32(out.len())?);
+        let name_len = u16::try_from(file_name.len())
+            .map_err(|_| format_error(format!("array name '{}' is too long", name)))?;
+
+        // Fields shared by the local and central headers, from the version
+        // needed to extract through the name length
+        let mut common = Vec::new();
+        for x in [ZIP_VERSION, 0, 0, 0, DOS_DATE] {
+            put_u16(&mut common, x);
+        }
+        for x in [crc, size, size] {
+            put_u32(&mut common, x);
+        }
+        put_u16(&mut common, name_len);
+
+        put_u32(&mut out, LOCAL_HEADER);
+        out.extend_from_slice(&common);
+        put_u16(&mut out, 0);
+        out.extend_from_slice(file_name.as_bytes());
+        out.extend_from_slice(&data);
+
+        put_u32(&mut directory, CENTRAL_HEADER);
+        put_u16(&mut directory, ZIP_VERSION);
+        directory.extend_from_slice(&common);
+        for x in [0u16; 4] {
+            put_u16(&mut directory, x);
+        }
+        put_u32(&mut directory, 0);
+        put_u32(&mut directory, offset);
+        directory.extend_from_slice(file_name.as_bytes());
+    }
+
+    let entries = u16::try_from(arrays.len()).map_err(|_| format_error("too many arrays"))?;
+    let (directory_len, directory_offset) = (to_u32(directory.len())?, to_u32(out.len())?);
+    out.extend_from_slice(&directory);
+    put_u32(&mut out, END_OF_DIRECTORY);
+    for x in [0, 0, entries, entries] {
+        put_u16(&mut out, x);
+    }
+    put_u32(&mut out, directory_len);
+    put_u32(&mut out, directory_offset);
+    put_u16(&mut out, 0);
+    writer.write_all(&out).map_err(|e| io_error("npz", e))
+}
+
+/// The arrays of an `.npz` archive, still encoded, keyed by name without the
+/// `.npy` extension.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NpzArchive {
+    entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl NpzArchive {
+    /// Array names in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn entry(&self, name: &str) -> Result<&[u8], TensorError> {
+        self.entries
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| TensorError::InvalidOperation(format!("npz has no array '{}'", name)))
+    }
+
+    /// Decodes the 2-D array `name`, which must have `N` columns.
+    pub fn matrix<T: NpyElement, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Matrix<T, N>, TensorError> {
+        let (shape, data) = read_array::<T, _>(&mut self.entry(name)?)?;
+        matrix_from_array(&shape, &data, "NpzArchive matrix")
+    }
+
+    /// Decodes the 3-D array `name`, whose last axis must have length `N`.
+    pub fn tensor<T: NpyElement, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Tensor<T, N>, TensorError> {
+        let (shape, data) = read_array::<T, _>(&mut self.entry(name)?)?;
+        tensor_from_array(&shape, &data, "NpzArchive tensor")
+    }
+}
+
+// Little-endian integers at `at`, failing on truncated input
+fn get_u16(bytes: &[u8], at: usize) -> Result<u16, TensorError> {
+    bytes
+        .get(at..at + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or_else(|| format_error("truncated archive"))
+}
+
+fn get_u32(bytes: &[u8], at: usize) -> Result<u32, TensorError> {
+    bytes
+        .get(at..at + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| format_error("truncated archive"))
+}
+
+/// Reads every array of an `.npz` archive.
+///
+/// Sizes come from the central directory, so archives written by NumPy
+/// with zip64 local headers or data descriptors are read as well.
+pub fn read_npz<R: Read>(reader: &mut R) -> Result<NpzArchive, TensorError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|e| io_error("npz", e))?;
+
+    // The end record is last, followed only by an optional comment
+    let end = (0..=bytes.len().saturating_sub(END_OF_DIRECTORY_LEN))
+        .rev()
+        .find(|&i| get_u32(&bytes, i) == Ok(END_OF_DIRECTORY))
+        .ok_or_else(|| format_error("no zip end of central directory"))?;
+    let entries = get_u16(&bytes, end + 10)? as usize;
+    let mut at = get_u32(&bytes, end + 16)? as usize;
+
+    let mut archive = NpzArchive::default();
+    for _ in 0..entries {
+        if get_u32(&bytes, at)? != CENTRAL_HEADER {
+            return Err(format_error("bad central directory entry"));
+        }
+        let method = get_u16(&bytes, at + 10)?;
+        let crc = get_u32(&bytes, at + 16)?;
+        let size = get_u32(&bytes, at + 20)? as usize;
+        let name_len = get_u16(&bytes, at + 28)? as usize;
+        let extra_len = get_u16(&bytes, at + 30)? as usize;
+        let comment_len = get_u16(&bytes, at + 32)? as usize;
+        let local = get_u32(&bytes, at + 42)? as usize;
+        let name = bytes
+            .get(at + CENTRAL_HEADER_LEN..at + CENTRAL_HEADER_LEN + name_len)
+            .ok_or_else(|| format_error("truncated archive"))?;
+        let name = String::from_utf8_lossy(name).into_owned();
+        at += CENTRAL_HEADER_LEN + name_len + extra_len + comment_len;
+
+        if method != 0 {
+            return Err(TensorError::InvalidOperation(format!(
+                "npz entry '{}' is compressed; only numpy.savez archives are supported",
+                name
+            )));
+        }
+        if get_u32(&bytes, local)? != LOCAL_HEADER {
+            return Err(format_error("bad local file header"));
+        }
+        let start = local
+            + LOCAL_HEADER_LEN
+            + get_u16(&bytes, local + 26)? as usize
+            + get_u16(&bytes, local + 28)? as usize;
+        let data = bytes
+            .get(start..start + size)
+            .ok_or_else(|| format_error("truncated archive"))?;
+        if crc32(data) != crc {
+            return Err(format_error(format!("checksum mismatch in '{}'", name)));
+        }
+        let key = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+        archive.entries.insert(key, data.to_vec());
+    }
+    Ok(archive)
+}
+
+/// Saves `arrays` to the `.npz` file at `path`.
+pub fn save_npz(
+    path: impl AsRef<Path>,
+    arrays: &[(&str, &dyn NpyArray)],
+) -> Result<(), TensorError> {
+    let mut bytes = Vec::new();
+    write_npz(&mut bytes, arrays)?;
+    std::fs::write(path, bytes).map_err(|e| io_error("npz", e))
+}
+
+/// Loads every array of the `.npz` file at `path`.
+pub fn load_npz(path: impl AsRef<Path>) -> Result<NpzArchive, TensorError> {
+    let file = std::fs::File::open(path).map_err(|e| io_error("npz", e))?;
+    read_npz(&mut std::io::BufReader::new(file))
+}
@@ -0,0 +1,271 @@
+//! Memory order of a [`Matrix`].
+//!
+//! The third type parameter of [`Matrix`] selects how the elements are
+//! stored: [`RowMajor`], the default that the rest of the crate works with,
+//! or [`ColMajor`], where every column is one contiguous slice as BLAS and
+//! LAPACK expect. `m[(i, j)]`, [`Matrix::get_at`] and
+//! [`Matrix::indexed_iter`] give the same answers in either order, and
+//! [`Matrix::to_layout`] converts between them in one tiled pass.
+//!
+//! [`Layout`] names the order of an external flat buffer for
+//! [`Matrix::to_buffer`] and [`Matrix::from_buffer`]. A column-major matrix
+//! is built with [`Matrix::from_buffer`] or converted from a row-major one
+//! with [`Matrix::into_layout`].
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Mul};
+
+/// Order of the elements of a matrix in a flat buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Rows one after another; element `(i, j)` is at `i * cols + j`.
+    #[default]
+    RowMajor,
+    /// Columns one after another; element `(i, j)` is at `j * rows + i`.
+    ColMajor,
+}
+
+impl Layout {
+    /// Position of element `(i, j)` of a `rows x cols` matrix.
+    pub fn offset(self, i: usize, j: usize, rows: usize, cols: usize) -> usize {
+        match self {
+            Layout::RowMajor => i * cols + j,
+            Layout::ColMajor => j * rows + i,
+        }
+    }
+
+    /// The `(i, j)` index of the element at position `k`.
+    pub fn index_of(self, k: usize, rows: usize, cols: usize) -> (usize, usize) {
+        match self {
+            Layout::RowMajor => (k / cols, k % cols),
+            Layout::ColMajor => (k % rows, k / rows),
+        }
+    }
+}
+
+/// A memory order usable as the layout parameter of [`Matrix`].
+pub trait MemoryLayout: Copy + Default + Debug + Eq + Hash + 'static {
+    const LAYOUT: Layout;
+}
+
+/// Row-major storage, the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RowMajor;
+
+/// Column-major storage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ColMajor;
+
+impl MemoryLayout for RowMajor {
+    const LAYOUT: Layout = Layout::RowMajor;
+}
+
+impl MemoryLayout for ColMajor {
+    const LAYOUT: Layout = Layout::ColMajor;
+}
+
+// Side of the square tiles copied by `relayout`
+const TILE: usize = 32;
+
+// Copies a `rows x cols` matrix from one order to another, tile by tile so
+// that both the reads and the writes stay within a few cache lines
+fn relayout<T: Copy>(src: &[T], rows: usize, cols: usize, from: Layout, to: Layout) -> Vec<T> {
+    if from == to {
+        return src.to_vec();
+    }
+    // Every element is overwritten below
+    let mut dst = src.to_vec();
+    for i0 in (0..rows).step_by(TILE) {
+        for j0 in (0..cols).step_by(TILE) {
+            for i in i0..(i0 + TILE).min(rows) {
+                for j in j0..(j0 + TILE).min(cols) {
+                    dst[to.offset(i, j, rows, cols)] = src[from.offset(i, j, rows, cols)];
+                }
+            }
+        }
+    }
+    dst
+}
+
+impl<T: AllowedNumericTypes, const N: usize, L: MemoryLayout> Matrix<T, N, L> {
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, N)
+    }
+
+    fn check_index(&self, row: usize, col: usize) -> Result<(), TensorError> {
+        if row >= self.rows || col >= N {
+            return Err(TensorError::OutOfBounds {
+                index: format!("({}, {})", row, col),
+                size: format!("{}x{}", self.rows, N),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn get_at(&self, row: usize, col: usize) -> Result<&T, TensorError> {
+        self.check_index(row, col)?;
+        Ok(&self.data[L::LAYOUT.offset(row, col, self.rows, N)])
+    }
+
+    pub fn get_at_mut(&mut self, row: usize, col: usize) -> Result<&mut T, TensorError> {
+        self.check_index(row, col)?;
+        Ok(&mut self.data[L::LAYOUT.offset(row, col, self.rows, N)])
+    }
+
+    /// Every element with its `(row, col)` index, in storage order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(k, x)| (L::LAYOUT.index_of(k, self.rows, N), x))
+    }
+
+    /// Copies the matrix into the memory order `L2`.
+    pub fn to_layout<L2: MemoryLayout>(&self) -> Matrix<T, N, L2> {
+        Matrix {
+            data: relayout(&self.data, self.rows, N, L::LAYOUT, L2::LAYOUT),
+            rows: self.rows,
+            layout: PhantomData,
+        }
+    }
+
+    /// Like [`Matrix::to_layout`], reusing the buffer if the order is
+    /// unchanged.
+    pub fn into_layout<L2: MemoryLayout>(self) -> Matrix<T, N, L2> {
+        if L::LAYOUT == L2::LAYOUT {
+            return Matrix {
+                data: self.data,
+                rows: self.rows,
+                layout: PhantomData,
+            };
+        }
+        self.to_layout()
+    }
+
+    /// Copies the elements into a flat buffer in the given order.
+    pub fn to_buffer(&self, layout: Layout) -> Vec<T> {
+        relayout(&self.data, self.rows, N, L::LAYOUT, layout)
+    }
+
+    /// Builds a `rows x N` matrix from a flat buffer in the given order;
+    /// fails unless `data` holds exactly `rows * N` elements.
+    pub fn from_buffer(rows: usize, data: &[T], layout: Layout) -> Result<Self, TensorError> {
+        if data.len() != rows * N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements", rows * N),
+                found: format!("{} elements", data.len()),
+                operation: "Matrix from_buffer".to_string(),
+            });
+        }
+        Ok(Matrix {
+            data: relayout(data, rows, N, layout, L::LAYOUT),
+            rows,
+            layout: PhantomData,
+        })
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, L: MemoryLayout> Index<(usize, usize)>
+    for Matrix<T, N, L>
+{
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < N, "index out of bounds");
+        &self.data[L::LAYOUT.offset(row, col, self.rows, N)]
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize, L: MemoryLayout> IndexMut<(usize, usize)>
+    for Matrix<T, N, L>
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < N, "index out of bounds");
+        &mut self.data[L::LAYOUT.offset(row, col, self.rows, N)]
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N, ColMajor> {
+    /// All elements as one column-major slice, e.g. for BLAS.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    fn check_col(col: usize) -> Result<(), TensorError> {
+        if col >= N {
+            return Err(TensorError::OutOfBounds {
+                index: col.to_string(),
+                size: N.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Column `col` as a contiguous slice.
+    pub fn col(&self, col: usize) -> Result<&[T], TensorError> {
+        Self::check_col(col)?;
+        Ok(&self.data[col * self.rows..(col + 1) * self.rows])
+    }
+
+    pub fn col_mut(&mut self, col: usize) -> Result<&mut [T], TensorError> {
+        Self::check_col(col)?;
+        let rows = self.rows;
+        Ok(&mut self.data[col * rows..(col + 1) * rows])
+    }
+
+    /// Iterates over the columns as contiguous slices.
+    pub fn iter_cols(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..N).map(move |j| &self.data[j * self.rows..(j + 1) * self.rows])
+    }
+}
+
+// Column-major product: column `j` of the result accumulates the columns of
+// `lhs` scaled by column `j` of `rhs` with Kahan summation, so every update
+// runs over contiguous memory
+fn col_major_mat_mul<T: AllowedNumericTypes, const N: usize, const M: usize>(
+    lhs: &Matrix<T, N, ColMajor>,
+    rhs: &Matrix<T, M, ColMajor>,
+) -> Result<Matrix<T, M, ColMajor>, TensorError> {
+    if rhs.rows != N {
+        return Err(TensorError::DimensionMismatch {
+            expected: format!("{}x{}", N, M),
+            found: format!("{}x{}", rhs.rows, M),
+            operation: "Matrix multiplication".to_string(),
+        });
+    }
+    #[cfg(feature = "profiling")]
+    crate::profile::record("matmul", 2 * lhs.rows * N * M, 1);
+    let rows = lhs.rows;
+    let mut result = Matrix {
+        data: vec![T::zero(); rows * M],
+        rows,
+        layout: PhantomData,
+    };
+    let mut comp = vec![T::zero(); rows];
+    for j in 0..M {
+        let out = &mut result.data[j * rows..(j + 1) * rows];
+        comp.fill(T::zero());
+        for (k, &scale) in rhs.data[j * N..(j + 1) * N].iter().enumerate() {
+            T::kahan_axpy_slices(out, &mut comp, scale, &lhs.data[k * rows..(k + 1) * rows]);
+        }
+    }
+    Ok(result)
+}
+
+impl<'b, T: AllowedNumericTypes, const N: usize, const M: usize> Mul<&'b Matrix<T, M, ColMajor>>
+    for &Matrix<T, N, ColMajor>
+{
+    type Output = Result<Matrix<T, M, ColMajor>, TensorError>;
+
+    fn mul(self, rhs: &'b Matrix<T, M, ColMajor>) -> Self::Output {
+        col_major_mat_mul(self, rhs)
+    }
+}
@@ -1,6 +1,65 @@
+pub mod activations;
+#[cfg(feature = "allocator")]
+pub mod allocator;
 pub mod arithmetic;
+#[cfg(feature = "autodiff")]
+pub mod autodiff;
+#[cfg(feature = "complex")]
+pub mod complex;
+pub mod conv;
+#[cfg(feature = "decimal")]
+mod decimal;
+pub mod display;
+pub mod distributions;
+pub mod dual;
+pub mod dynamic;
 pub mod error;
+pub mod expr;
+#[cfg(all(feature = "fast-math", not(feature = "simd")))]
+mod fast_math;
+#[cfg(feature = "fixed")]
+mod fixed_point;
+#[cfg(feature = "half")]
+mod half_float;
+pub mod image;
+pub mod integrate;
+#[cfg(any(feature = "glam", feature = "cgmath"))]
+mod interop;
+pub mod io;
+pub mod layout;
+pub mod linalg;
+pub mod mask;
+pub mod modular;
+pub mod ndtensor;
+pub mod overflow;
+pub mod pad;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "profiling")]
+pub mod profile;
+pub mod quasirandom;
+pub mod ragged;
+#[cfg(feature = "rational")]
+pub mod rational;
+pub mod reduce;
+pub mod rolling;
+pub mod scan;
+pub mod segment;
+pub mod select;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod shared;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod stack_matrix;
+pub mod stencil;
+pub mod stream;
 pub mod tensor;
 mod tensor_impl; // impls for tensor types
+pub mod view;
+pub mod workspace;
 
 pub mod macros;
+
+#[cfg(feature = "num-traits")]
+pub use num_traits;
@@ -0,0 +1,800 @@
+//! Linear algebra routines built on Householder reflections.
+//!
+//! Factorizations work on a dense row-major copy of the matrix and keep their
+//! reflectors, so a factorization can be computed once and applied to any
+//! number of right-hand sides.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes, Matrix, Vector};
+use crate::workspace::Workspace;
+
+/// Vector norms accepted by normalization routines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Norm {
+    /// Sum of absolute values.
+    L1,
+    /// Euclidean length.
+    #[default]
+    L2,
+    /// Largest absolute value.
+    Max,
+}
+
+/// Distance metrics between rows used by [`Matrix::pairwise_distances`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// L2 distance.
+    Euclidean,
+    /// Squared L2 distance, avoiding the square root.
+    SquaredEuclidean,
+    /// L1 distance.
+    Manhattan,
+    /// L-infinity distance.
+    Chebyshev,
+    /// One minus the cosine similarity.
+    Cosine,
+}
+
+fn norm_of<T: AllowedFloatTypes>(values: impl Iterator<Item = T>, norm: Norm) -> T {
+    match norm {
+        Norm::L1 => values.fold(T::zero(), |acc, x| acc + x.abs()),
+        Norm::L2 => values.fold(T::zero(), |acc, x| acc + x * x).sqrt(),
+        Norm::Max => values.fold(T::zero(), |acc, x| {
+            let x = x.abs();
+            if x > acc {
+                x
+            } else {
+                acc
+            }
+        }),
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Vector<T, N> {
+    /// Computes the requested norm of the vector.
+    pub fn norm(&self, norm: Norm) -> T {
+        norm_of(self.iter().copied(), norm)
+    }
+}
+
+/// Householder QR of a dense `rows x cols` row-major buffer.
+///
+/// Reflector `k` is stored LAPACK-style: an implicit unit entry at row `k`
+/// followed by the entries below the diagonal of column `k`. The upper
+/// triangle holds `R`. `perm[k]` is the original column placed at `k`.
+struct HouseholderQr<T> {
+    a: Vec<T>,
+    rows: usize,
+    cols: usize,
+    tau: Vec<T>,
+    perm: Vec<usize>,
+}
+
+impl<T: AllowedFloatTypes> HouseholderQr<T> {
+    fn new(mut a: Vec<T>, rows: usize, cols: usize, pivot: bool) -> Self {
+        let mut tau = vec![T::zero(); rows.min(cols)];
+        let mut perm: Vec<usize> = (0..cols).collect();
+        factor_qr(&mut a, &mut tau, pivot.then_some(&mut perm[..]), rows, cols);
+        HouseholderQr {
+            a,
+            rows,
+            cols,
+            tau,
+            perm,
+        }
+    }
+
+    fn reflectors(&self) -> Reflectors<'_, T> {
+        Reflectors {
+            a: &self.a,
+            tau: &self.tau,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+// Factors the `rows x cols` buffer `a` in place into the reflectors and `R`
+// of `HouseholderQr`, writing one `tau` per step. Pivots on the column with
+// the largest trailing norm if `perm` is given.
+fn factor_qr<T: AllowedFloatTypes>(
+    a: &mut [T],
+    tau: &mut [T],
+    mut perm: Option<&mut [usize]>,
+    rows: usize,
+    cols: usize,
+) {
+    for k in 0..rows.min(cols) {
+        if let Some(perm) = perm.as_deref_mut() {
+            // Bring the column with the largest trailing norm to position k
+            let mut best = k;
+            let mut best_norm = T::zero();
+            for j in k..cols {
+                let mut s = T::zero();
+                for i in k..rows {
+                    s = s + a[i * cols + j] * a[i * cols + j];
+                }
+                if s > best_norm {
+                    best_norm = s;
+                    best = j;
+                }
+            }
+            if best != k {
+                for i in 0..rows {
+                    a.swap(i * cols + k, i * cols + best);
+                }
+                perm.swap(k, best);
+            }
+        }
+
+        let mut norm_sq = T::zero();
+        for i in k..rows {
+            norm_sq = norm_sq + a[i * cols + k] * a[i * cols + k];
+        }
+        let norm = norm_sq.sqrt();
+        if norm.is_zero() {
+            tau[k] = T::zero();
+            continue;
+        }
+
+        let alpha = a[k * cols + k];
+        let beta = if alpha > T::zero() {
+            T::zero() - norm
+        } else {
+            norm
+        };
+        let scale = alpha - beta;
+        for i in k + 1..rows {
+            a[i * cols + k] = a[i * cols + k] / scale;
+        }
+        let t = (beta - alpha) / beta;
+        a[k * cols + k] = beta;
+
+        for j in k + 1..cols {
+            let mut w = a[k * cols + j];
+            for i in k + 1..rows {
+                w = w + a[i * cols + k] * a[i * cols + j];
+            }
+            w = w * t;
+            a[k * cols + j] = a[k * cols + j] - w;
+            for i in k + 1..rows {
+                a[i * cols + j] = a[i * cols + j] - w * a[i * cols + k];
+            }
+        }
+        tau[k] = t;
+    }
+}
+
+// The factored buffer and `tau` of a Householder QR, owned by a
+// `HouseholderQr` or lent by a workspace
+#[derive(Clone, Copy)]
+struct Reflectors<'a, T> {
+    a: &'a [T],
+    tau: &'a [T],
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: AllowedFloatTypes> Reflectors<'_, T> {
+    fn r(&self, i: usize, j: usize) -> T {
+        self.a[i * self.cols + j]
+    }
+
+    fn reflect(&self, k: usize, x: &mut [T]) {
+        let t = self.tau[k];
+        if t.is_zero() {
+            return;
+        }
+        let v = |i: usize| self.a[i * self.cols + k];
+        let mut w = x[k];
+        for (i, xi) in x.iter().enumerate().take(self.rows).skip(k + 1) {
+            w = w + v(i) * *xi;
+        }
+        w = w * t;
+        x[k] = x[k] - w;
+        for (i, xi) in x.iter_mut().enumerate().take(self.rows).skip(k + 1) {
+            *xi = *xi - w * v(i);
+        }
+    }
+
+    // x <- Q^T x
+    fn apply_qt(&self, x: &mut [T]) {
+        for k in 0..self.tau.len() {
+            self.reflect(k, x);
+        }
+    }
+
+    // x <- Q x
+    fn apply_q(&self, x: &mut [T]) {
+        for k in (0..self.tau.len()).rev() {
+            self.reflect(k, x);
+        }
+    }
+}
+
+// Numerical rank of a column-pivoted QR of a `rows x cols` matrix: the
+// diagonal entries of R are non-increasing in magnitude after pivoting
+fn qr_rank<T: AllowedFloatTypes>(qr: Reflectors<'_, T>) -> usize {
+    let steps = qr.rows.min(qr.cols);
+    let mut rank = 0;
+    if steps > 0 {
+        let largest = qr.r(0, 0).abs();
+        let tol = T::epsilon() * T::from_f64(qr.rows.max(qr.cols) as f64) * largest;
+        while rank < steps && qr.r(rank, rank).abs() > tol {
+            rank += 1;
+        }
+    }
+    rank
+}
+
+// Writes the transpose of the leading `rank` rows of R into the
+// `cols x rank` buffer `rt`, zero below the diagonal of R
+fn leading_rows_transposed<T: AllowedFloatTypes>(qr: Reflectors<'_, T>, rank: usize, rt: &mut [T]) {
+    rt.fill(T::zero());
+    for i in 0..qr.cols {
+        for j in 0..rank.min(i + 1) {
+            rt[i * rank + j] = qr.r(j, i);
+        }
+    }
+}
+
+// `pinv(A) b` from the two QRs of a complete orthogonal decomposition,
+// using `c` (one entry per row of A) and `y` (N entries) as scratch
+fn cod_pinv_apply<T: AllowedFloatTypes, const N: usize>(
+    qr: Reflectors<'_, T>,
+    rz: Reflectors<'_, T>,
+    perm: &[usize],
+    rank: usize,
+    b: &[T],
+    c: &mut [T],
+    y: &mut [T],
+) -> Result<Vector<T, N>, TensorError> {
+    if b.len() != qr.rows {
+        return Err(TensorError::DimensionMismatch {
+            expected: qr.rows.to_string(),
+            found: b.len().to_string(),
+            operation: "pinv_apply".to_string(),
+        });
+    }
+
+    c.copy_from_slice(b);
+    qr.apply_qt(c);
+
+    // Forward substitution with the lower-triangular L = R2^T
+    y.fill(T::zero());
+    for i in 0..rank {
+        let mut s = c[i];
+        for (j, yj) in y.iter().enumerate().take(i) {
+            s = s - rz.r(j, i) * *yj;
+        }
+        y[i] = s / rz.r(i, i);
+    }
+    rz.apply_q(y);
+
+    let mut x = Vector::new();
+    for (i, &col) in perm.iter().enumerate() {
+        x[col] = y[i];
+    }
+    Ok(x)
+}
+
+/// Rank-revealing complete orthogonal decomposition `A P = Q [L 0; 0 0] Z^T`.
+///
+/// Stores the reflectors of a column-pivoted QR of `A` and of a second QR
+/// that compresses the leading `rank` rows of `R`. Applying the
+/// pseudo-inverse then costs two sweeps of reflectors and one triangular
+/// solve, without ever forming `pinv(A)` as a dense matrix.
+pub struct CompleteOrthogonalDecomposition<T: AllowedFloatTypes, const N: usize> {
+    qr: HouseholderQr<T>,
+    rz: HouseholderQr<T>,
+    rank: usize,
+}
+
+impl<T: AllowedFloatTypes, const N: usize> CompleteOrthogonalDecomposition<T, N> {
+    /// Numerical rank detected during factorization.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Shape `(rows, N)` of the factorized matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.qr.rows, N)
+    }
+
+    /// Computes the minimum-norm least-squares solution `x = pinv(A) b`.
+    ///
+    /// `b` must have one entry per row of `A`.
+    pub fn pinv_apply(&self, b: &[T]) -> Result<Vector<T, N>, TensorError> {
+        self.pinv_apply_in(b, &mut Workspace::new())
+    }
+
+    /// [`CompleteOrthogonalDecomposition::pinv_apply`] using `workspace` for
+    /// its temporaries, so applying the decomposition does not allocate.
+    pub fn pinv_apply_in(
+        &self,
+        b: &[T],
+        workspace: &mut Workspace<T>,
+    ) -> Result<Vector<T, N>, TensorError> {
+        let (c, y) = workspace
+            .scratch(self.qr.rows + N)
+            .split_at_mut(self.qr.rows);
+        cod_pinv_apply(
+            self.qr.reflectors(),
+            self.rz.reflectors(),
+            &self.qr.perm,
+            self.rank,
+            b,
+            c,
+            y,
+        )
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Computes a complete orthogonal decomposition suitable for applying the
+    /// pseudo-inverse to many right-hand sides.
+    pub fn cod(&self) -> CompleteOrthogonalDecomposition<T, N> {
+        let rows = self.rows;
+        // Two Householder QRs of at most `rows x N`
+        #[cfg(feature = "profiling")]
+        crate::profile::record("cod", 4 * rows * N * N, 2);
+        let qr = HouseholderQr::new(self.as_slice().to_vec(), rows, N, true);
+        let rank = qr_rank(qr.reflectors());
+
+        // Second QR on the transpose of the leading `rank` rows of R
+        let mut rt = vec![T::zero(); N * rank];
+        leading_rows_transposed(qr.reflectors(), rank, &mut rt);
+        let rz = HouseholderQr::new(rt, N, rank, false);
+
+        CompleteOrthogonalDecomposition { qr, rz, rank }
+    }
+
+    /// Computes `pinv(self) * b`, the minimum-norm least-squares solution of
+    /// `self * x = b`, without materializing the pseudo-inverse.
+    ///
+    /// For repeated right-hand sides, factor once with [`Matrix::cod`] and
+    /// call [`CompleteOrthogonalDecomposition::pinv_apply`].
+    pub fn pinv_apply(&self, b: &[T]) -> Result<Vector<T, N>, TensorError> {
+        self.cod().pinv_apply(b)
+    }
+
+    /// [`Matrix::pinv_apply`] factoring in `workspace`, so solving many
+    /// systems of the same shape does not allocate.
+    pub fn pinv_apply_in(
+        &self,
+        b: &[T],
+        workspace: &mut Workspace<T>,
+    ) -> Result<Vector<T, N>, TensorError> {
+        let rows = self.rows;
+        let steps = rows.min(N);
+        // The factored matrix and its taus, then those of the second QR, then
+        // the right-hand side and solution
+        let len = rows * N + steps + N * steps + steps + rows + N;
+        #[cfg(feature = "profiling")]
+        crate::profile::record(
+            "cod",
+            4 * rows * N * N,
+            usize::from(workspace.capacity() < len),
+        );
+        let scratch = workspace.scratch(len);
+        let (a, rest) = scratch.split_at_mut(rows * N);
+        let (tau, rest) = rest.split_at_mut(steps);
+        a.copy_from_slice(self.as_slice());
+        let mut perm: [usize; N] = std::array::from_fn(|j| j);
+        factor_qr(a, tau, Some(&mut perm[..]), rows, N);
+        let qr = Reflectors {
+            a,
+            tau,
+            rows,
+            cols: N,
+        };
+        let rank = qr_rank(qr);
+
+        let (rt, rest) = rest.split_at_mut(N * rank);
+        let (rz_tau, rest) = rest.split_at_mut(rank);
+        leading_rows_transposed(qr, rank, rt);
+        factor_qr(rt, rz_tau, None, N, rank);
+        let rz = Reflectors {
+            a: rt,
+            tau: rz_tau,
+            rows: N,
+            cols: rank,
+        };
+
+        let (c, rest) = rest.split_at_mut(rows);
+        cod_pinv_apply(qr, rz, &perm, rank, b, c, &mut rest[..N])
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Scales every row to unit L2 norm.
+    ///
+    /// Returns `DivisionByZero` if any row is all zeros.
+    pub fn normalize_rows(&self) -> Result<Self, TensorError> {
+        self.normalize_rows_by(Norm::L2)
+    }
+
+    /// Scales every row to unit norm under `norm`.
+    ///
+    /// With `Norm::L1` a non-negative matrix becomes row-stochastic.
+    pub fn normalize_rows_by(&self, norm: Norm) -> Result<Self, TensorError> {
+        let mut result_data = Vec::with_capacity(self.rows);
+        for i in 0..self.rows {
+            result_data.push(self[i].scalar_div(self[i].norm(norm))?);
+        }
+        Ok(Matrix::from_vectors(result_data))
+    }
+
+    /// Scales every column to unit L2 norm.
+    ///
+    /// Returns `DivisionByZero` if any column is all zeros.
+    pub fn normalize_cols(&self) -> Result<Self, TensorError> {
+        self.normalize_cols_by(Norm::L2)
+    }
+
+    /// Scales every column to unit norm under `norm`.
+    pub fn normalize_cols_by(&self, norm: Norm) -> Result<Self, TensorError> {
+        let mut norms = [T::zero(); N];
+        for (j, n) in norms.iter_mut().enumerate() {
+            *n = norm_of((0..self.rows).map(|i| self[i][j]), norm);
+        }
+        let norms = Vector::from(norms);
+
+        let mut result_data = Vec::with_capacity(self.rows);
+        for i in 0..self.rows {
+            result_data.push((self[i] / norms)?);
+        }
+        Ok(Matrix::from_vectors(result_data))
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Computes the `rows x rows` matrix of distances between every pair of
+    /// rows under `metric`.
+    ///
+    /// `M` must equal the number of rows. `Metric::Cosine` returns
+    /// `DivisionByZero` if any row is all zeros.
+    pub fn pairwise_distances<const M: usize>(
+        &self,
+        metric: Metric,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        if M != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, self.rows),
+                found: format!("{}x{}", self.rows, M),
+                operation: "pairwise_distances".to_string(),
+            });
+        }
+
+        let mut norms = Vec::new();
+        if metric == Metric::Cosine {
+            for i in 0..self.rows {
+                let n = self[i].norm(Norm::L2);
+                if n.is_zero() {
+                    return Err(TensorError::DivisionByZero);
+                }
+                norms.push(n);
+            }
+        }
+
+        let mut result: Matrix<T, M> = Matrix::new(self.rows);
+        for i in 0..self.rows {
+            for j in i + 1..self.rows {
+                let diff = (0..N).map(|k| self[i][k] - self[j][k]);
+                let d = match metric {
+                    Metric::Euclidean => norm_of(diff, Norm::L2),
+                    Metric::SquaredEuclidean => diff.fold(T::zero(), |acc, x| acc + x * x),
+                    Metric::Manhattan => norm_of(diff, Norm::L1),
+                    Metric::Chebyshev => norm_of(diff, Norm::Max),
+                    Metric::Cosine => T::one() - self[i].dot(&self[j]) / (norms[i] * norms[j]),
+                };
+                result[i][j] = d;
+                result[j][i] = d;
+            }
+        }
+        Ok(result)
+    }
+}
+
+// Largest size handled by the closed-form cofactor routines below; beyond
+// it elimination is cheaper than cofactor expansion
+const SMALL_SIZE: usize = 4;
+
+// Determinant of the submatrix of `a` at `rows` x `cols` by cofactor
+// expansion along its first row; only used for sizes up to `SMALL_SIZE`
+fn cofactor_det<T: AllowedNumericTypes>(
+    a: &impl Fn(usize, usize) -> T,
+    rows: &[usize],
+    cols: &[usize],
+) -> T {
+    match cols.len() {
+        0 => T::one(),
+        1 => a(rows[0], cols[0]),
+        2 => {
+            let (r0, r1, c0, c1) = (rows[0], rows[1], cols[0], cols[1]);
+            a(r0, c0) * a(r1, c1) - a(r0, c1) * a(r1, c0)
+        }
+        n => {
+            let mut det = T::zero();
+            for (j, &col) in cols.iter().enumerate() {
+                let rest = indices_without(cols, col);
+                let term = a(rows[0], col) * cofactor_det(a, &rows[1..], &rest[..n - 1]);
+                det = if j % 2 == 0 { det + term } else { det - term };
+            }
+            det
+        }
+    }
+}
+
+// `indices` with `skip` removed, padded to `SMALL_SIZE`
+fn indices_without(indices: &[usize], skip: usize) -> [usize; SMALL_SIZE] {
+    let mut rest = [0; SMALL_SIZE];
+    for (slot, &i) in rest.iter_mut().zip(indices.iter().filter(|&&i| i != skip)) {
+        *slot = i;
+    }
+    rest
+}
+
+const INDICES: [usize; SMALL_SIZE] = [0, 1, 2, 3];
+
+// Closed-form determinant of the `n x n` matrix with elements `a(i, j)`
+// for `n <= 4`, or `None` for larger sizes
+pub(crate) fn small_determinant<T: AllowedNumericTypes>(
+    n: usize,
+    a: impl Fn(usize, usize) -> T,
+) -> Option<T> {
+    (n <= SMALL_SIZE).then(|| cofactor_det(&a, &INDICES[..n], &INDICES[..n]))
+}
+
+// Closed-form inverse of a square `N x N` array for `N <= 4` as the
+// adjugate over the determinant. `None` for larger sizes; `Some(None)` if
+// the determinant is exactly zero.
+pub(crate) fn small_inverse<T: AllowedNumericTypes, const N: usize>(
+    a: &[[T; N]; N],
+) -> Option<Option<[[T; N]; N]>> {
+    if N > SMALL_SIZE {
+        return None;
+    }
+    if N == 0 {
+        return Some(Some(*a));
+    }
+    let at = |i: usize, j: usize| a[i][j];
+    // cofactors[i][j] is the signed minor without row i and column j
+    let cofactors: [[T; N]; N] = std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let rows = indices_without(&INDICES[..N], i);
+            let cols = indices_without(&INDICES[..N], j);
+            let minor = cofactor_det(&at, &rows[..N - 1], &cols[..N - 1]);
+            if (i + j) % 2 == 0 {
+                minor
+            } else {
+                T::zero() - minor
+            }
+        })
+    });
+    let det = (0..N).fold(T::zero(), |acc, j| acc + a[0][j] * cofactors[0][j]);
+    if det.is_zero() {
+        return Some(None);
+    }
+    Some(Some(std::array::from_fn(|i| {
+        std::array::from_fn(|j| cofactors[j][i] / det)
+    })))
+}
+
+// Multiply-adds of Gaussian elimination on an `n x n` matrix
+#[cfg(feature = "profiling")]
+fn elimination_flops(n: usize) -> usize {
+    2 * n * n * n / 3
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    // Reduces a copy of the matrix, written to the row-major `a`, to upper
+    // triangular form with partial pivoting, applying the same row
+    // operations to `rhs`. Returns the determinant, or `None` if a pivot's
+    // magnitude is at most `tol`.
+    fn eliminate(&self, a: &mut [T], rhs: &mut [T], tol: T) -> Option<T> {
+        a.copy_from_slice(self.as_slice());
+        let mut det = T::one();
+        for col in 0..N {
+            let pivot = (col..N)
+                .reduce(|best, r| {
+                    if a[r * N + col].abs() > a[best * N + col].abs() {
+                        r
+                    } else {
+                        best
+                    }
+                })
+                .unwrap_or(col);
+            if a[pivot * N + col].is_zero_with_tol(tol) {
+                return None;
+            }
+            if pivot != col {
+                for j in 0..N {
+                    a.swap(pivot * N + j, col * N + j);
+                }
+                rhs.swap(pivot, col);
+                det = T::zero() - det;
+            }
+            let (head, tail) = a.split_at_mut((col + 1) * N);
+            let pivot_row = &head[col * N..];
+            let pivot_rhs = rhs[col];
+            det = det * pivot_row[col];
+            for (row, b) in tail.chunks_mut(N).zip(rhs.iter_mut().skip(col + 1)) {
+                let factor = row[col] / pivot_row[col];
+                for (x, &y) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    *x = *x - factor * y;
+                }
+                *b = *b - factor * pivot_rhs;
+            }
+        }
+        Some(det)
+    }
+
+    /// Determinant by Gaussian elimination with partial pivoting, or in
+    /// closed form for sizes up to 4x4. Only an exactly zero pivot counts as
+    /// singular; see [`Matrix::determinant_with_tol`].
+    pub fn determinant(&self) -> Result<T, TensorError> {
+        self.check_square("determinant")?;
+        match small_determinant(N, |i, j| self.data[i * N + j]) {
+            Some(det) => {
+                #[cfg(feature = "profiling")]
+                crate::profile::record("determinant", elimination_flops(N), 0);
+                Ok(det)
+            }
+            None => self.determinant_with_tol(T::zero()),
+        }
+    }
+
+    /// Determinant that is exactly zero when a pivot's magnitude is at most
+    /// `tol`, so nearly singular matrices are reported as singular.
+    pub fn determinant_with_tol(&self, tol: T) -> Result<T, TensorError> {
+        self.determinant_in(tol, &mut Workspace::new())
+    }
+
+    /// [`Matrix::determinant_with_tol`] using `workspace` for the working
+    /// copy instead of allocating one.
+    pub fn determinant_in(&self, tol: T, workspace: &mut Workspace<T>) -> Result<T, TensorError> {
+        self.check_square("determinant")?;
+        #[cfg(feature = "profiling")]
+        crate::profile::record(
+            "determinant",
+            elimination_flops(N),
+            usize::from(workspace.capacity() < N * N),
+        );
+        let a = workspace.scratch(N * N);
+        Ok(self
+            .eliminate(a, &mut [T::zero(); N], tol)
+            .unwrap_or(T::zero()))
+    }
+
+    /// Solves `A x = b` for square `A` by Gaussian elimination with partial
+    /// pivoting. Fails only on an exactly zero pivot; see
+    /// [`Matrix::solve_with_tol`].
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, TensorError> {
+        self.solve_with_tol(b, T::zero())
+    }
+
+    /// Like [`Matrix::solve`], but fails when a pivot's magnitude is at most
+    /// `tol` instead of returning a meaningless, huge solution.
+    pub fn solve_with_tol(&self, b: &Vector<T, N>, tol: T) -> Result<Vector<T, N>, TensorError> {
+        self.solve_in(b, tol, &mut Workspace::new())
+    }
+
+    /// [`Matrix::solve_with_tol`] using `workspace` for the working copy, so
+    /// repeated solves do not allocate.
+    pub fn solve_in(
+        &self,
+        b: &Vector<T, N>,
+        tol: T,
+        workspace: &mut Workspace<T>,
+    ) -> Result<Vector<T, N>, TensorError> {
+        self.check_square("solve")?;
+        #[cfg(feature = "profiling")]
+        crate::profile::record(
+            "solve",
+            elimination_flops(N) + 2 * N * N,
+            usize::from(workspace.capacity() < N * N),
+        );
+        let a = workspace.scratch(N * N);
+        let mut rhs = b.data;
+        self.eliminate(a, &mut rhs, tol).ok_or_else(|| {
+            TensorError::InvalidOperation("matrix is singular to within tolerance".to_string())
+        })?;
+        let mut x = [T::zero(); N];
+        for i in (0..N).rev() {
+            let tail = (i + 1..N).fold(T::zero(), |acc, j| acc + a[i * N + j] * x[j]);
+            x[i] = (rhs[i] - tail) / a[i * N + i];
+        }
+        Ok(Vector::from(x))
+    }
+}
+
+// Gauss-Jordan elimination over the first `cols` columns, taking the first
+// nonzero entry as pivot (exact field arithmetic needs no partial
+// pivoting). Returns the number of pivots found.
+pub(crate) fn gauss_jordan<T: AllowedNumericTypes>(rows: &mut [Vec<T>], cols: usize) -> usize {
+    let mut rank = 0;
+    for col in 0..cols {
+        let Some(pivot) = (rank..rows.len()).find(|&r| !rows[r][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let p = rows[rank][col];
+        for x in rows[rank].iter_mut() {
+            *x = *x / p;
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if r == rank || factor.is_zero() {
+                continue;
+            }
+            for (x, &y) in row.iter_mut().zip(pivot_row.iter()) {
+                *x = *x - factor * y;
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    pub(crate) fn check_square(&self, operation: &str) -> Result<(), TensorError> {
+        if self.rows != N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", N, N),
+                found: format!("{}x{}", self.rows, N),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Determinant by fraction-free Bareiss elimination.
+    ///
+    /// Every division is exact, so integer matrices get an exact result as
+    /// long as the intermediate minors fit the element type; use `i128` for
+    /// large entries. Unsigned types only work when no intermediate value is
+    /// negative; a negative determinant is reported as an error.
+    pub fn determinant_bareiss(&self) -> Result<T, TensorError> {
+        self.check_square("determinant_bareiss")?;
+        if N == 0 {
+            return Ok(T::one());
+        }
+
+        let mut a: Vec<[T; N]> = self.as_rows().iter().map(|row| row.data).collect();
+        let mut negate = false;
+        let mut prev = T::one();
+        for k in 0..N - 1 {
+            if a[k][k].is_zero() {
+                match (k + 1..N).find(|&i| !a[i][k].is_zero()) {
+                    Some(i) => {
+                        a.swap(i, k);
+                        negate = !negate;
+                    }
+                    None => return Ok(T::zero()),
+                }
+            }
+            let pivot_row = a[k];
+            for row in a.iter_mut().skip(k + 1) {
+                for j in k + 1..N {
+                    row[j] = (row[j] * pivot_row[k] - row[k] * pivot_row[j]) / prev;
+                }
+            }
+            prev = pivot_row[k];
+        }
+
+        let det = a[N - 1][N - 1];
+        if !negate {
+            return Ok(det);
+        }
+        // Unsigned types saturate -1 to zero and cannot hold the negation
+        if !det.is_zero() && T::from_f64(-1.0).is_zero() {
+            return Err(TensorError::InvalidOperation(
+                "determinant_bareiss: the determinant is negative and the element type is unsigned"
+                    .to_string(),
+            ));
+        }
+        Ok(T::zero() - det)
+    }
+}
@@ -20,3 +20,287 @@ macro_rules! matrix {
         $crate::tensor::Matrix::from_vectors(rows_vec)
     }};
 }
+
+#[macro_export]
+macro_rules! tensor {
+    // tensor![ [[a, b]; [c, d]]; [[e, f]; [g, h]] ] -> Tensor<T, N> with one
+    // bracketed matrix per depth
+    ( $( [ $( [ $($x:expr),* $(,)? ] );+ $(;)? ] );+ $(;)? ) => {{
+        let matrices = vec![ $( $crate::matrix![ $( [ $( $x ),* ] );+ ] ),+ ];
+        $crate::tensor::Tensor::from_matrices(matrices)
+            .expect("tensor! depths must have the same number of rows")
+    }};
+}
+
+/// Registers a single-field tuple newtype over an element type as an element
+/// type itself, e.g. `struct Meters(f64)`.
+///
+/// Generates `Add`, `Sub`, `Mul` and `Div` for the newtype plus
+/// [`AllowedNumericTypes`] (and [`AllowedFloatTypes`] when called with
+/// `float`), all forwarding to the inner value. The newtype must derive
+/// `Clone, Copy, Debug, Default, PartialEq` (and `PartialOrd` for `float`).
+///
+/// ```
+/// use tensor_algebra_in_rust::impl_allowed_numeric;
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+/// struct Meters(f64);
+///
+/// impl_allowed_numeric!(Meters(f64), float);
+/// ```
+///
+/// [`AllowedNumericTypes`]: crate::tensor::AllowedNumericTypes
+/// [`AllowedFloatTypes`]: crate::tensor::AllowedFloatTypes
+#[macro_export]
+macro_rules! impl_allowed_numeric {
+    (@op $name:ident, $trait:ident, $method:ident) => {
+        impl ::std::ops::$trait for $name {
+            type Output = $name;
+
+            fn $method(self, rhs: $name) -> $name {
+                $name(::std::ops::$trait::$method(self.0, rhs.0))
+            }
+        }
+    };
+    ($name:ident($inner:ty)) => {
+        $crate::impl_allowed_numeric!(@op $name, Add, add);
+        $crate::impl_allowed_numeric!(@op $name, Sub, sub);
+        $crate::impl_allowed_numeric!(@op $name, Mul, mul);
+        $crate::impl_allowed_numeric!(@op $name, Div, div);
+
+        impl $crate::tensor::AllowedNumericTypes for $name {
+            fn zero() -> Self {
+                $name(<$inner as $crate::tensor::AllowedNumericTypes>::zero())
+            }
+            fn one() -> Self {
+                $name(<$inner as $crate::tensor::AllowedNumericTypes>::one())
+            }
+            fn is_zero(&self) -> bool {
+                <$inner as $crate::tensor::AllowedNumericTypes>::is_zero(&self.0)
+            }
+            fn from_f64(value: f64) -> Self {
+                $name(<$inner as $crate::tensor::AllowedNumericTypes>::from_f64(value))
+            }
+            fn to_f64(self) -> f64 {
+                <$inner as $crate::tensor::AllowedNumericTypes>::to_f64(self.0)
+            }
+        }
+    };
+    ($name:ident($inner:ty), float) => {
+        $crate::impl_allowed_numeric!($name($inner));
+
+        impl $crate::tensor::AllowedFloatTypes for $name {
+            fn epsilon() -> Self {
+                $name(<$inner as $crate::tensor::AllowedFloatTypes>::epsilon())
+            }
+            fn abs(self) -> Self {
+                $name(<$inner as $crate::tensor::AllowedFloatTypes>::abs(self.0))
+            }
+            fn sqrt(self) -> Self {
+                $name(<$inner as $crate::tensor::AllowedFloatTypes>::sqrt(self.0))
+            }
+            fn exp(self) -> Self {
+                $name(<$inner as $crate::tensor::AllowedFloatTypes>::exp(self.0))
+            }
+            fn tanh(self) -> Self {
+                $name(<$inner as $crate::tensor::AllowedFloatTypes>::tanh(self.0))
+            }
+        }
+    };
+}
+
+/// Implements [`AllowedNumericTypes`] for a type from its `num-traits`
+/// impls, and [`AllowedFloatTypes`] too when called with `float`.
+///
+/// A blanket impl over `num_traits::Num` would overlap the impls for the
+/// primitives, so third-party types opt in with one line instead. The type
+/// needs `Num + NumCast + Copy + Default + Debug` (plus `Float` for `float`).
+/// `from_f64` returns zero for values the type cannot represent, and
+/// `to_f64` returns NaN.
+///
+/// ```ignore
+/// impl_num_traits_element!(MyInt);
+/// impl_num_traits_element!(MyFloat, float);
+/// ```
+///
+/// [`AllowedNumericTypes`]: crate::tensor::AllowedNumericTypes
+/// [`AllowedFloatTypes`]: crate::tensor::AllowedFloatTypes
+#[cfg(feature = "num-traits")]
+#[macro_export]
+macro_rules! impl_num_traits_element {
+    ($t:ty) => {
+        impl $crate::tensor::AllowedNumericTypes for $t {
+            fn zero() -> Self {
+                <$t as $crate::num_traits::Zero>::zero()
+            }
+            fn one() -> Self {
+                <$t as $crate::num_traits::One>::one()
+            }
+            fn is_zero(&self) -> bool {
+                <$t as $crate::num_traits::Zero>::is_zero(self)
+            }
+            fn from_f64(value: f64) -> Self {
+                <$t as $crate::num_traits::NumCast>::from(value)
+                    .unwrap_or_else(<$t as $crate::num_traits::Zero>::zero)
+            }
+            fn to_f64(self) -> f64 {
+                $crate::num_traits::ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
+            }
+        }
+    };
+    ($t:ty, float) => {
+        $crate::impl_num_traits_element!($t);
+
+        impl $crate::tensor::AllowedFloatTypes for $t {
+            fn epsilon() -> Self {
+                <$t as $crate::num_traits::Float>::epsilon()
+            }
+            fn abs(self) -> Self {
+                <$t as $crate::num_traits::Float>::abs(self)
+            }
+            fn sqrt(self) -> Self {
+                <$t as $crate::num_traits::Float>::sqrt(self)
+            }
+            fn exp(self) -> Self {
+                <$t as $crate::num_traits::Float>::exp(self)
+            }
+            fn tanh(self) -> Self {
+                <$t as $crate::num_traits::Float>::tanh(self)
+            }
+        }
+    };
+}
+
+/// Generates a test module checking the algebraic laws the crate relies on
+/// for an element type: associativity and commutativity of addition,
+/// `zero`/`one` identities, distributivity, matrix multiplication laws, and
+/// that shape and division errors are reported.
+///
+/// Results are compared after `to_f64` with a relative tolerance (default
+/// `1e-9`), so float and approximate types can pass. Sample values are small
+/// non-negative integers to suit unsigned and narrow types.
+///
+/// ```ignore
+/// impl_algebra_tests!(my_fixed_laws, MyFixed);
+/// impl_algebra_tests!(my_half_laws, MyHalf, tolerance = 1e-2);
+/// ```
+#[macro_export]
+macro_rules! impl_algebra_tests {
+    ($name:ident, $t:ty) => {
+        $crate::impl_algebra_tests!($name, $t, tolerance = 1e-9);
+    };
+    ($name:ident, $t:ty, tolerance = $tol:expr) => {
+        #[cfg(test)]
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::error::TensorError;
+            use $crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+            type Elem = $t;
+
+            fn val(x: f64) -> Elem {
+                <Elem as AllowedNumericTypes>::from_f64(x)
+            }
+
+            fn vector(values: [f64; 3]) -> Vector<Elem, 3> {
+                Vector::from(values.map(val))
+            }
+
+            fn matrix(rows: [[f64; 2]; 2]) -> Matrix<Elem, 2> {
+                Matrix::from_vectors(rows.iter().map(|r| Vector::from(r.map(val))).collect())
+            }
+
+            fn close(a: Elem, b: Elem) -> bool {
+                let (a, b) = (a.to_f64(), b.to_f64());
+                (a - b).abs() <= $tol * (1.0 + a.abs().max(b.abs()))
+            }
+
+            fn vectors_close<const N: usize>(a: &Vector<Elem, N>, b: &Vector<Elem, N>) -> bool {
+                a.iter().zip(b.iter()).all(|(&x, &y)| close(x, y))
+            }
+
+            fn matrices_close<const N: usize>(a: &Matrix<Elem, N>, b: &Matrix<Elem, N>) -> bool {
+                a.shape() == b.shape() && (0..a.shape().0).all(|r| vectors_close(&a[r], &b[r]))
+            }
+
+            fn samples() -> [Vector<Elem, 3>; 3] {
+                [
+                    vector([1.0, 2.0, 3.0]),
+                    vector([4.0, 0.0, 2.0]),
+                    vector([3.0, 1.0, 5.0]),
+                ]
+            }
+
+            #[test]
+            fn addition_is_associative_and_commutative() {
+                let [a, b, c] = samples();
+                let left = (a.clone() + b.clone()) + c.clone();
+                let right = a.clone() + (b.clone() + c);
+                assert!(vectors_close(&left, &right));
+                assert!(vectors_close(&(a.clone() + b.clone()), &(b + a)));
+            }
+
+            #[test]
+            fn zero_and_one_are_identities() {
+                let zero = <Elem as AllowedNumericTypes>::zero();
+                let one = <Elem as AllowedNumericTypes>::one();
+                assert!(zero.is_zero());
+                assert!(!one.is_zero());
+                assert!(close(val(0.0), zero));
+                assert!(close(val(1.0), one));
+
+                let [a, _, _] = samples();
+                assert!(vectors_close(&(a.clone() + Vector::new()), &a));
+                assert!(vectors_close(&a.scalar_add(zero), &a));
+                assert!(vectors_close(&a.scalar_mul(one), &a));
+                assert!(vectors_close(&(a.clone() * Vector::from([one; 3])), &a));
+            }
+
+            #[test]
+            fn multiplication_distributes_over_addition() {
+                let [a, b, c] = samples();
+                let s = val(2.0);
+                let left = (a.clone() + b.clone()).scalar_mul(s);
+                let right = a.scalar_mul(s) + b.scalar_mul(s);
+                assert!(vectors_close(&left, &right));
+
+                let left = a.clone() * (b.clone() + c.clone());
+                let right = a.clone() * b + a * c;
+                assert!(vectors_close(&left, &right));
+            }
+
+            #[test]
+            fn matrix_multiplication_laws() {
+                let a = matrix([[1.0, 2.0], [3.0, 1.0]]);
+                let b = matrix([[2.0, 0.0], [1.0, 3.0]]);
+                let c = matrix([[1.0, 1.0], [0.0, 2.0]]);
+                let identity = matrix([[1.0, 0.0], [0.0, 1.0]]);
+
+                assert!(matrices_close(&(&a * &identity).unwrap(), &a));
+                assert!(matrices_close(&(&identity * &a).unwrap(), &a));
+
+                let left = (&(&a * &b).unwrap() * &c).unwrap();
+                let right = (&a * &(&b * &c).unwrap()).unwrap();
+                assert!(matrices_close(&left, &right));
+            }
+
+            #[test]
+            fn shape_mismatches_and_zero_division_are_errors() {
+                let two: Matrix<Elem, 2> = Matrix::new(2);
+                let three: Matrix<Elem, 2> = Matrix::new(3);
+                assert!(matches!(
+                    two.clone() + three.clone(),
+                    Err(TensorError::DimensionMismatch { .. })
+                ));
+                assert!(matches!(
+                    &two * &three,
+                    Err(TensorError::DimensionMismatch { .. })
+                ));
+
+                let [a, _, _] = samples();
+                assert_eq!(a / Vector::new(), Err(TensorError::DivisionByZero));
+            }
+        }
+    };
+}
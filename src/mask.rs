@@ -0,0 +1,428 @@
+//! Boolean masks.
+//!
+//! [`Mask`] is a dense `rows x N` grid of booleans produced by element-wise
+//! comparisons and consumed by `select` and `fill_where` on [`Matrix`] and
+//! [`Vector`] (a vector's mask has one row). [`RleMask`] stores only the runs
+//! of set elements in row-major order, so large, mostly-empty segmentation
+//! masks stay small and logical operations run in time proportional to the
+//! number of runs.
+//!
+//! Both are exchanged with ordinary matrices as 0/1 values: any nonzero
+//! element counts as set.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+use std::ops::Not;
+
+fn check_rows<const N: usize>(
+    expected: usize,
+    found: usize,
+    operation: &str,
+) -> Result<(), TensorError> {
+    if expected != found {
+        return Err(TensorError::DimensionMismatch {
+            expected: format!("{}x{}", expected, N),
+            found: format!("{}x{}", found, N),
+            operation: operation.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Dense `rows x N` boolean mask.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mask<const N: usize> {
+    rows: usize,
+    data: Vec<[bool; N]>,
+}
+
+impl<const N: usize> Mask<N> {
+    /// A mask with `rows` rows, every element set to `value`.
+    pub fn full(rows: usize, value: bool) -> Self {
+        Mask {
+            rows,
+            data: vec![[value; N]; rows],
+        }
+    }
+
+    /// Sets the nonzero elements of `m`.
+    pub fn from_matrix<T: AllowedNumericTypes>(m: &Matrix<T, N>) -> Self {
+        Mask {
+            rows: m.rows,
+            data: m
+                .as_rows()
+                .iter()
+                .map(|row| row.data.map(|x| !x.is_zero()))
+                .collect(),
+        }
+    }
+
+    /// `T::one()` for set elements and `T::zero()` elsewhere.
+    pub fn to_matrix<T: AllowedNumericTypes>(&self) -> Matrix<T, N> {
+        Matrix::from_vectors(
+            self.data
+                .iter()
+                .map(|row| Vector::from(row.map(|b| if b { T::one() } else { T::zero() })))
+                .collect(),
+        )
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, N)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<bool, TensorError> {
+        if row >= self.rows || col >= N {
+            return Err(TensorError::OutOfBounds {
+                index: format!("({}, {})", row, col),
+                size: format!("{}x{}", self.rows, N),
+            });
+        }
+        Ok(self.data[row][col])
+    }
+
+    /// Number of set elements.
+    pub fn count(&self) -> usize {
+        self.data.iter().flatten().filter(|&&b| b).count()
+    }
+
+    pub fn any(&self) -> bool {
+        self.data.iter().flatten().any(|&b| b)
+    }
+
+    pub fn all(&self) -> bool {
+        self.data.iter().flatten().all(|&b| b)
+    }
+
+    fn combine(
+        &self,
+        other: &Self,
+        operation: &str,
+        op: impl Fn(bool, bool) -> bool,
+    ) -> Result<Self, TensorError> {
+        check_rows::<N>(self.rows, other.rows, operation)?;
+        Ok(Mask {
+            rows: self.rows,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, b)| std::array::from_fn(|j| op(a[j], b[j])))
+                .collect(),
+        })
+    }
+
+    /// Element-wise logical AND.
+    pub fn and(&self, other: &Self) -> Result<Self, TensorError> {
+        self.combine(other, "Mask and", |a, b| a && b)
+    }
+
+    /// Element-wise logical OR.
+    pub fn or(&self, other: &Self) -> Result<Self, TensorError> {
+        self.combine(other, "Mask or", |a, b| a || b)
+    }
+
+    /// Element-wise logical XOR.
+    pub fn xor(&self, other: &Self) -> Result<Self, TensorError> {
+        self.combine(other, "Mask xor", |a, b| a != b)
+    }
+}
+
+impl<const N: usize> Not for &Mask<N> {
+    type Output = Mask<N>;
+
+    fn not(self) -> Self::Output {
+        Mask {
+            rows: self.rows,
+            data: self.data.iter().map(|row| row.map(|b| !b)).collect(),
+        }
+    }
+}
+
+impl<const N: usize> Not for Mask<N> {
+    type Output = Mask<N>;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Mask of the elements satisfying `pred`.
+    pub fn mask_where(&self, pred: impl Fn(T) -> bool) -> Mask<N> {
+        Mask {
+            rows: self.rows,
+            data: self
+                .as_rows()
+                .iter()
+                .map(|row| row.data.map(&pred))
+                .collect(),
+        }
+    }
+
+    /// Mask of the positions where `pred(self, other)` holds.
+    pub fn mask_zip(
+        &self,
+        other: &Self,
+        pred: impl Fn(T, T) -> bool,
+    ) -> Result<Mask<N>, TensorError> {
+        check_rows::<N>(self.rows, other.rows, "Matrix mask_zip")?;
+        Ok(Mask {
+            rows: self.rows,
+            data: self
+                .as_rows()
+                .iter()
+                .zip(other.as_rows())
+                .map(|(a, b)| std::array::from_fn(|j| pred(a[j], b[j])))
+                .collect(),
+        })
+    }
+
+    /// Mask of the elements equal to the corresponding element of `other`.
+    pub fn equal(&self, other: &Self) -> Result<Mask<N>, TensorError> {
+        self.mask_zip(other, |a, b| a == b)
+    }
+
+    /// Takes elements from `self` where `mask` is set and from `other`
+    /// elsewhere.
+    pub fn select(&self, mask: &Mask<N>, other: &Self) -> Result<Self, TensorError> {
+        check_rows::<N>(self.rows, mask.rows, "Matrix select")?;
+        check_rows::<N>(self.rows, other.rows, "Matrix select")?;
+        Ok(Matrix::from_vectors(
+            self.as_rows()
+                .iter()
+                .zip(other.as_rows())
+                .zip(mask.data.iter())
+                .map(|((a, b), m)| {
+                    Vector::from(std::array::from_fn(|j| if m[j] { a[j] } else { b[j] }))
+                })
+                .collect(),
+        ))
+    }
+
+    /// Replaces the elements where `mask` is set with `value`.
+    pub fn fill_where(&self, mask: &Mask<N>, value: T) -> Result<Self, TensorError> {
+        check_rows::<N>(self.rows, mask.rows, "Matrix fill_where")?;
+        Ok(Matrix::from_vectors(
+            self.as_rows()
+                .iter()
+                .zip(mask.data.iter())
+                .map(|(a, m)| {
+                    Vector::from(std::array::from_fn(|j| if m[j] { value } else { a[j] }))
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Matrix<T, N> {
+    /// Mask of the elements greater than the corresponding element of
+    /// `other`.
+    pub fn greater(&self, other: &Self) -> Result<Mask<N>, TensorError> {
+        self.mask_zip(other, |a, b| a > b)
+    }
+
+    /// Mask of the elements less than the corresponding element of `other`.
+    pub fn less(&self, other: &Self) -> Result<Mask<N>, TensorError> {
+        self.mask_zip(other, |a, b| a < b)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
+    /// One-row mask of the elements satisfying `pred`.
+    pub fn mask_where(&self, pred: impl Fn(T) -> bool) -> Mask<N> {
+        Mask {
+            rows: 1,
+            data: vec![self.data.map(pred)],
+        }
+    }
+
+    /// Takes elements from `self` where the one-row `mask` is set and from
+    /// `other` elsewhere.
+    pub fn select(&self, mask: &Mask<N>, other: &Self) -> Result<Self, TensorError> {
+        check_rows::<N>(1, mask.rows, "Vector select")?;
+        let m = mask.data[0];
+        Ok(Vector::from(std::array::from_fn(|j| {
+            if m[j] {
+                self[j]
+            } else {
+                other[j]
+            }
+        })))
+    }
+
+    /// Replaces the elements where the one-row `mask` is set with `value`.
+    pub fn fill_where(&self, mask: &Mask<N>, value: T) -> Result<Self, TensorError> {
+        check_rows::<N>(1, mask.rows, "Vector fill_where")?;
+        let m = mask.data[0];
+        Ok(Vector::from(std::array::from_fn(|j| {
+            if m[j] {
+                value
+            } else {
+                self[j]
+            }
+        })))
+    }
+}
+
+/// Run-length encoded `rows x N` boolean mask.
+///
+/// Runs are `(start, len)` pairs over the row-major flattened index. They are
+/// sorted, non-empty, and never overlap or touch, so every mask has exactly
+/// one encoding and `==` compares masks by content.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RleMask<const N: usize> {
+    rows: usize,
+    runs: Vec<(usize, usize)>,
+}
+
+impl<const N: usize> RleMask<N> {
+    /// An all-false mask with `rows` rows.
+    pub fn new(rows: usize) -> Self {
+        RleMask {
+            rows,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Encodes the nonzero elements of `m`.
+    pub fn from_matrix<T: AllowedNumericTypes>(m: &Matrix<T, N>) -> Self {
+        let mut mask = RleMask::new(m.rows);
+        for r in 0..m.rows {
+            for c in 0..N {
+                if !m[r][c].is_zero() {
+                    mask.push_run(r * N + c, 1);
+                }
+            }
+        }
+        mask
+    }
+
+    /// Decodes into a matrix holding `T::one()` for set elements and
+    /// `T::zero()` elsewhere.
+    pub fn to_matrix<T: AllowedNumericTypes>(&self) -> Matrix<T, N> {
+        let mut m = Matrix::new(self.rows);
+        for &(start, len) in self.runs.iter() {
+            for i in start..start + len {
+                m[i / N][i % N] = T::one();
+            }
+        }
+        m
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, N)
+    }
+
+    /// The `(start, len)` runs of set elements in row-major order.
+    pub fn runs(&self) -> &[(usize, usize)] {
+        &self.runs
+    }
+
+    /// Number of set elements.
+    pub fn count(&self) -> usize {
+        self.runs.iter().map(|&(_, len)| len).sum()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<bool, TensorError> {
+        if row >= self.rows || col >= N {
+            return Err(TensorError::OutOfBounds {
+                index: format!("({}, {})", row, col),
+                size: format!("{}x{}", self.rows, N),
+            });
+        }
+        let i = row * N + col;
+        // Index of the first run starting after `i`; the run before it may contain `i`
+        let next = self.runs.partition_point(|&(start, _)| start <= i);
+        Ok(next > 0 && {
+            let (start, len) = self.runs[next - 1];
+            i < start + len
+        })
+    }
+
+    // Appends a run at or after the end of the last one, merging if they touch
+    fn push_run(&mut self, start: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        if let Some(last) = self.runs.last_mut() {
+            if last.0 + last.1 == start {
+                last.1 += len;
+                return;
+            }
+        }
+        self.runs.push((start, len));
+    }
+
+    fn combine(
+        &self,
+        other: &Self,
+        operation: &str,
+        op: impl Fn(bool, bool) -> bool,
+    ) -> Result<Self, TensorError> {
+        check_rows::<N>(self.rows, other.rows, operation)?;
+        Ok(self.sweep(&other.runs, op))
+    }
+
+    // Evaluates `op` on every segment between consecutive run boundaries
+    fn sweep(&self, other: &[(usize, usize)], op: impl Fn(bool, bool) -> bool) -> Self {
+        let total = self.rows * N;
+        let mut points = vec![0, total];
+        for &(start, len) in self.runs.iter().chain(other.iter()) {
+            points.push(start);
+            points.push(start + len);
+        }
+        points.sort_unstable();
+        points.dedup();
+
+        let contains = |runs: &[(usize, usize)], idx: &mut usize, at: usize| {
+            while *idx < runs.len() && runs[*idx].0 + runs[*idx].1 <= at {
+                *idx += 1;
+            }
+            *idx < runs.len() && runs[*idx].0 <= at
+        };
+
+        let mut result = RleMask::new(self.rows);
+        let (mut i, mut j) = (0, 0);
+        for w in points.windows(2) {
+            let (start, end) = (w[0], w[1]);
+            let in_self = contains(&self.runs, &mut i, start);
+            let in_other = contains(other, &mut j, start);
+            if op(in_self, in_other) {
+                result.push_run(start, end - start);
+            }
+        }
+        result
+    }
+
+    /// Element-wise logical AND.
+    pub fn and(&self, other: &Self) -> Result<Self, TensorError> {
+        self.combine(other, "RleMask and", |a, b| a && b)
+    }
+
+    /// Element-wise logical OR.
+    pub fn or(&self, other: &Self) -> Result<Self, TensorError> {
+        self.combine(other, "RleMask or", |a, b| a || b)
+    }
+
+    /// Element-wise logical XOR.
+    pub fn xor(&self, other: &Self) -> Result<Self, TensorError> {
+        self.combine(other, "RleMask xor", |a, b| a != b)
+    }
+}
+
+impl<const N: usize> Not for &RleMask<N> {
+    type Output = RleMask<N>;
+
+    fn not(self) -> Self::Output {
+        self.sweep(&[], |a, _| !a)
+    }
+}
+
+impl<const N: usize> Not for RleMask<N> {
+    type Output = RleMask<N>;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
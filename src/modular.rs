@@ -0,0 +1,183 @@
+//! Integers modulo a prime, for linear algebra over GF(p).
+//!
+//! [`Mod<P>`] stores a residue in `0..P`; a modulus below 2 fails to
+//! compile. Addition, subtraction and multiplication wrap modulo `P`;
+//! division multiplies by the modular inverse and panics on residues that
+//! have none, which for prime `P` is only zero. Matrices over GF(p) get
+//! exact [`Matrix::rank`], [`Matrix::solve`] and [`Matrix::inverse`];
+//! [`Matrix::determinant_bareiss`] is exact as well. These eliminate by
+//! dividing through pivots, so they need a prime `P`.
+
+use crate::error::TensorError;
+use crate::linalg::gauss_jordan;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A residue modulo `P`, always kept in `0..P`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Mod<const P: u64>(u64);
+
+impl<const P: u64> Mod<P> {
+    // `P`, rejected at compile time if no residue class could hold one
+    const fn modulus() -> u64 {
+        const { assert!(P >= 2, "the modulus P must be at least 2") }
+        P
+    }
+
+    fn reduce(value: u128) -> Self {
+        Mod((value % Self::modulus() as u128) as u64)
+    }
+
+    pub fn new(value: u64) -> Self {
+        Self::reduce(value as u128)
+    }
+
+    /// The residue in `0..P`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// `self^exp` by repeated squaring.
+    pub fn pow(self, mut exp: u64) -> Self {
+        let (mut base, mut result) = (self, Mod::new(1));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse by the extended Euclidean algorithm, or
+    /// `None` unless the residue is coprime to `P` (for prime `P`, unless it
+    /// is zero).
+    pub fn inverse(self) -> Option<Self> {
+        let p = Self::modulus() as i128;
+        // Invariant: old_s * self == old_r and s * self == r modulo P
+        let (mut old_r, mut r) = (self.0 as i128, p);
+        let (mut old_s, mut s) = (1i128, 0i128);
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_s, s) = (s, old_s - q * s);
+        }
+        (old_r == 1).then(|| Mod(old_s.rem_euclid(p) as u64))
+    }
+}
+
+impl<const P: u64> Add for Mod<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::reduce(self.0 as u128 + rhs.0 as u128)
+    }
+}
+
+impl<const P: u64> Sub for Mod<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::reduce(self.0 as u128 + Self::modulus() as u128 - rhs.0 as u128)
+    }
+}
+
+impl<const P: u64> Mul for Mod<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::reduce(self.0 as u128 * rhs.0 as u128)
+    }
+}
+
+impl<const P: u64> Div for Mod<P> {
+    type Output = Self;
+
+    /// Panics when `rhs` has no inverse, like dividing the primitive
+    /// integers by zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs
+            .inverse()
+            .expect("attempt to divide by a residue with no inverse modulo P")
+    }
+}
+
+impl<const P: u64> fmt::Display for Mod<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// `from_f64` truncates toward zero and then reduces, so negative values
+/// map to their residues (`-1` becomes `P - 1`).
+impl<const P: u64> AllowedNumericTypes for Mod<P> {
+    fn zero() -> Self {
+        Mod::new(0)
+    }
+    fn one() -> Self {
+        Mod::new(1)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        Mod((value as i128).rem_euclid(Self::modulus() as i128) as u64)
+    }
+    fn to_f64(self) -> f64 {
+        self.0 as f64
+    }
+}
+
+impl<const P: u64, const N: usize> Matrix<Mod<P>, N> {
+    fn reduced_rows(&self, extra: impl Fn(usize) -> Vec<Mod<P>>) -> (Vec<Vec<Mod<P>>>, usize) {
+        let mut rows: Vec<Vec<Mod<P>>> = self
+            .as_rows()
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let mut row = v.data.to_vec();
+                row.extend(extra(i));
+                row
+            })
+            .collect();
+        let rank = gauss_jordan(&mut rows, N);
+        (rows, rank)
+    }
+
+    fn singular() -> TensorError {
+        TensorError::InvalidOperation("matrix is singular modulo P".to_string())
+    }
+
+    /// Rank over GF(p).
+    pub fn rank(&self) -> usize {
+        self.reduced_rows(|_| Vec::new()).1
+    }
+
+    /// Solves `A x = b` for square, invertible `A`.
+    pub fn solve(&self, b: &Vector<Mod<P>, N>) -> Result<Vector<Mod<P>, N>, TensorError> {
+        self.check_square("solve")?;
+        let (rows, rank) = self.reduced_rows(|i| vec![b[i]]);
+        if rank < N {
+            return Err(Self::singular());
+        }
+        Ok(Vector::from(std::array::from_fn(|i| rows[i][N])))
+    }
+
+    /// Inverse by Gauss-Jordan elimination on `[A | I]`.
+    pub fn inverse(&self) -> Result<Self, TensorError> {
+        self.check_square("inverse")?;
+        let (rows, rank) =
+            self.reduced_rows(|i| (0..N).map(|j| Mod::new((i == j) as u64)).collect());
+        if rank < N {
+            return Err(Self::singular());
+        }
+        Ok(Matrix::from_vectors(
+            rows.iter()
+                .map(|row| Vector::from(std::array::from_fn(|j| row[N + j])))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,240 @@
+//! Tensors with any number of axes.
+//!
+//! [`NdTensor`] keeps its shape at runtime and stores elements contiguously
+//! in row-major order, so it can hold data the fixed-rank [`Vector`],
+//! [`Matrix`] and [`Tensor`] cannot, such as batches of multi-channel images.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+use std::ops::{Index, IndexMut};
+
+/// Row-major tensor with a runtime shape.
+///
+/// `strides[k]` is the distance in elements between neighbours along axis
+/// `k`; the last axis always has stride one. A zero-axis tensor is a scalar
+/// holding one element.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NdTensor<T: AllowedNumericTypes> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+fn row_major_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for k in (0..shape.len().saturating_sub(1)).rev() {
+        strides[k] = strides[k + 1] * shape[k + 1];
+    }
+    strides
+}
+
+fn shape_string(shape: &[usize]) -> String {
+    shape
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join("x")
+}
+
+impl<T: AllowedNumericTypes> NdTensor<T> {
+    pub fn zeros(shape: &[usize]) -> Self {
+        NdTensor {
+            data: vec![T::zero(); shape.iter().product()],
+            shape: shape.to_vec(),
+            strides: row_major_strides(shape),
+        }
+    }
+
+    /// Wraps row-major `data`; its length must equal the product of `shape`.
+    pub fn from_vec(data: Vec<T>, shape: &[usize]) -> Result<Self, TensorError> {
+        let len: usize = shape.iter().product();
+        if data.len() != len {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements for shape {}", len, shape_string(shape)),
+                found: format!("{} elements", data.len()),
+                operation: "NdTensor from_vec".to_string(),
+            });
+        }
+        Ok(NdTensor {
+            data,
+            shape: shape.to_vec(),
+            strides: row_major_strides(shape),
+        })
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Number of axes.
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    fn offset(&self, index: &[usize]) -> Result<usize, TensorError> {
+        let in_bounds = index.len() == self.shape.len()
+            && index.iter().zip(self.shape.iter()).all(|(&i, &d)| i < d);
+        if !in_bounds {
+            return Err(TensorError::OutOfBounds {
+                index: format!("{:?}", index),
+                size: shape_string(&self.shape),
+            });
+        }
+        Ok(index
+            .iter()
+            .zip(self.strides.iter())
+            .map(|(&i, &s)| i * s)
+            .sum())
+    }
+
+    pub fn get(&self, index: &[usize]) -> Result<&T, TensorError> {
+        let offset = self.offset(index)?;
+        Ok(&self.data[offset])
+    }
+
+    pub fn get_mut(&mut self, index: &[usize]) -> Result<&mut T, TensorError> {
+        let offset = self.offset(index)?;
+        Ok(&mut self.data[offset])
+    }
+
+    /// Reinterprets the elements with a new shape of the same total size.
+    pub fn reshape(&self, shape: &[usize]) -> Result<Self, TensorError> {
+        if shape.iter().product::<usize>() != self.data.len() {
+            return Err(TensorError::DimensionMismatch {
+                expected: shape_string(&self.shape),
+                found: shape_string(shape),
+                operation: "NdTensor reshape".to_string(),
+            });
+        }
+        Ok(NdTensor {
+            data: self.data.clone(),
+            shape: shape.to_vec(),
+            strides: row_major_strides(shape),
+        })
+    }
+
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> NdTensor<U> {
+        NdTensor {
+            data: self.data.iter().copied().map(f).collect(),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+
+    // `None` in `expected` matches any length along that axis
+    fn check_shape(&self, expected: &[Option<usize>], operation: &str) -> Result<(), TensorError> {
+        let matches = self.shape.len() == expected.len()
+            && self
+                .shape
+                .iter()
+                .zip(expected.iter())
+                .all(|(&d, e)| e.is_none_or(|e| e == d));
+        if !matches {
+            let expected: Vec<String> = expected
+                .iter()
+                .map(|e| e.map_or("?".to_string(), |d| d.to_string()))
+                .collect();
+            return Err(TensorError::DimensionMismatch {
+                expected: expected.join("x"),
+                found: shape_string(&self.shape),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Converts a one-axis tensor of length `N`.
+    pub fn to_vector<const N: usize>(&self) -> Result<Vector<T, N>, TensorError> {
+        self.check_shape(&[Some(N)], "NdTensor to_vector")?;
+        Vector::from_slice(&self.data)
+    }
+
+    /// Converts a two-axis tensor with `N` columns.
+    pub fn to_matrix<const N: usize>(&self) -> Result<Matrix<T, N>, TensorError> {
+        self.check_shape(&[None, Some(N)], "NdTensor to_matrix")?;
+        Ok(Matrix::from_vectors(
+            (0..self.shape[0])
+                .map(|r| Vector::from_slice(&self.data[r * N..(r + 1) * N]))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    /// Converts a three-axis `depths x rows x N` tensor.
+    pub fn to_tensor<const N: usize>(&self) -> Result<Tensor<T, N>, TensorError> {
+        self.check_shape(&[None, None, Some(N)], "NdTensor to_tensor")?;
+        let depth_len = self.shape[1] * N;
+        let matrices = (0..self.shape[0])
+            .map(|d| {
+                NdTensor::from_vec(
+                    self.data[d * depth_len..(d + 1) * depth_len].to_vec(),
+                    &self.shape[1..],
+                )
+                .and_then(|m| m.to_matrix())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Tensor::from_matrices(matrices)
+    }
+}
+
+impl<T: AllowedNumericTypes> Index<&[usize]> for NdTensor<T> {
+    type Output = T;
+
+    fn index(&self, index: &[usize]) -> &Self::Output {
+        self.get(index).expect("NdTensor index out of bounds")
+    }
+}
+
+impl<T: AllowedNumericTypes> IndexMut<&[usize]> for NdTensor<T> {
+    fn index_mut(&mut self, index: &[usize]) -> &mut Self::Output {
+        self.get_mut(index).expect("NdTensor index out of bounds")
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> From<&Vector<T, N>> for NdTensor<T> {
+    fn from(v: &Vector<T, N>) -> Self {
+        NdTensor {
+            data: v.iter().copied().collect(),
+            shape: vec![N],
+            strides: vec![1],
+        }
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> From<&Matrix<T, N>> for NdTensor<T> {
+    fn from(m: &Matrix<T, N>) -> Self {
+        let (rows, _) = m.shape();
+        let data = (0..rows).flat_map(|r| m[r].iter().copied()).collect();
+        NdTensor::from_vec(data, &[rows, N]).expect("matrix element count")
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> From<&Tensor<T, N>> for NdTensor<T> {
+    fn from(t: &Tensor<T, N>) -> Self {
+        let (depths, rows, _) = t.shape();
+        let data = (0..depths)
+            .flat_map(|d| (0..rows).flat_map(move |r| t[d][r].iter().copied()))
+            .collect();
+        NdTensor::from_vec(data, &[depths, rows, N]).expect("tensor element count")
+    }
+}
@@ -0,0 +1,207 @@
+//! Element arithmetic with an explicit overflow policy.
+//!
+//! The plain operators inherit Rust's integer behavior: a panic in debug
+//! builds and silent wrapping in release builds. The `*_policy` methods
+//! choose per call: [`Policy::Checked`] reports overflow as an error,
+//! [`Policy::Saturating`] clamps to the type's bounds (the usual choice for
+//! `u8` image math), and [`Policy::Wrapping`] wraps modulo `2^bits`.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+
+/// How integer overflow is handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+    /// Fail with [`TensorError::InvalidOperation`].
+    Checked,
+    /// Clamp to the minimum or maximum value.
+    Saturating,
+    /// Wrap around modulo `2^bits`.
+    Wrapping,
+}
+
+/// Integer element types with checked, saturating and wrapping operations.
+pub trait OverflowArithmetic: AllowedNumericTypes {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! overflow_impls {
+    ($($ty:ty),+) => {
+        $(
+            impl OverflowArithmetic for $ty {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_sub(self, rhs)
+                }
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, rhs)
+                }
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$ty>::saturating_add(self, rhs)
+                }
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$ty>::saturating_sub(self, rhs)
+                }
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$ty>::saturating_mul(self, rhs)
+                }
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_add(self, rhs)
+                }
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_sub(self, rhs)
+                }
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_mul(self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+overflow_impls!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+fn apply<T: OverflowArithmetic>(a: T, b: T, op: Op, policy: Policy) -> Result<T, TensorError> {
+    match policy {
+        Policy::Checked => {
+            let result = match op {
+                Op::Add => a.checked_add(b),
+                Op::Sub => a.checked_sub(b),
+                Op::Mul => a.checked_mul(b),
+            };
+            result.ok_or_else(|| {
+                TensorError::InvalidOperation(format!("overflow computing {:?} and {:?}", a, b))
+            })
+        }
+        Policy::Saturating => Ok(match op {
+            Op::Add => a.saturating_add(b),
+            Op::Sub => a.saturating_sub(b),
+            Op::Mul => a.saturating_mul(b),
+        }),
+        Policy::Wrapping => Ok(match op {
+            Op::Add => a.wrapping_add(b),
+            Op::Sub => a.wrapping_sub(b),
+            Op::Mul => a.wrapping_mul(b),
+        }),
+    }
+}
+
+impl<T: OverflowArithmetic, const N: usize> Vector<T, N> {
+    fn zip_policy(&self, other: &Self, op: Op, policy: Policy) -> Result<Self, TensorError> {
+        let mut data = self.data;
+        for (x, &y) in data.iter_mut().zip(other.data.iter()) {
+            *x = apply(*x, y, op, policy)?;
+        }
+        Ok(Vector { data })
+    }
+
+    /// Element-wise addition under `policy`.
+    pub fn add_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Add, policy)
+    }
+
+    /// Element-wise subtraction under `policy`.
+    pub fn sub_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Sub, policy)
+    }
+
+    /// Element-wise multiplication under `policy`.
+    pub fn mul_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Mul, policy)
+    }
+
+    /// Multiplies every element by `scalar` under `policy`.
+    pub fn scalar_mul_policy(&self, scalar: T, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(&Vector { data: [scalar; N] }, Op::Mul, policy)
+    }
+}
+
+impl<T: OverflowArithmetic, const N: usize> Matrix<T, N> {
+    fn zip_policy(&self, other: &Self, op: Op, policy: Policy) -> Result<Self, TensorError> {
+        if self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", other.rows, N),
+                operation: "Matrix arithmetic with overflow policy".to_string(),
+            });
+        }
+        let mut data = Vec::with_capacity(self.rows);
+        for (a, b) in self.as_rows().iter().zip(other.as_rows()) {
+            data.push(a.zip_policy(b, op, policy)?);
+        }
+        Ok(Matrix::from_vectors(data))
+    }
+
+    /// Element-wise addition under `policy`.
+    pub fn add_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Add, policy)
+    }
+
+    /// Element-wise subtraction under `policy`.
+    pub fn sub_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Sub, policy)
+    }
+
+    /// Element-wise (Hadamard) multiplication under `policy`.
+    pub fn mul_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Mul, policy)
+    }
+
+    /// Multiplies every element by `scalar` under `policy`.
+    pub fn scalar_mul_policy(&self, scalar: T, policy: Policy) -> Result<Self, TensorError> {
+        let mut data = Vec::with_capacity(self.rows);
+        for row in self.as_rows() {
+            data.push(row.scalar_mul_policy(scalar, policy)?);
+        }
+        Ok(Matrix::from_vectors(data))
+    }
+}
+
+impl<T: OverflowArithmetic, const N: usize> Tensor<T, N> {
+    fn zip_policy(&self, other: &Self, op: Op, policy: Policy) -> Result<Self, TensorError> {
+        self.check_same_shape(other, "Tensor arithmetic with overflow policy")?;
+        let data = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| apply(a, b, op, policy))
+            .collect::<Result<_, _>>()?;
+        Ok(Tensor {
+            data,
+            depths: self.depths,
+            rows: self.rows,
+        })
+    }
+
+    /// Element-wise addition under `policy`.
+    pub fn add_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Add, policy)
+    }
+
+    /// Element-wise subtraction under `policy`.
+    pub fn sub_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Sub, policy)
+    }
+
+    /// Element-wise multiplication under `policy`.
+    pub fn mul_policy(&self, other: &Self, policy: Policy) -> Result<Self, TensorError> {
+        self.zip_policy(other, Op::Mul, policy)
+    }
+}
@@ -0,0 +1,116 @@
+//! Padding vectors and matrices along their edges.
+//!
+//! The padded size is a const generic checked at runtime, like
+//! [`Matrix::transpose`]: padding a `rows x N` matrix by `left` and `right`
+//! columns needs `M == left + N + right`.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+/// How padded elements are filled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PadMode<T> {
+    /// Fill with a constant value.
+    Constant(T),
+    /// Repeat the edge element.
+    Edge,
+    /// Mirror around the edge element without repeating it, so
+    /// `[1, 2, 3]` padded by two on the left gives `[3, 2, 1, 2, 3]`.
+    Reflect,
+}
+
+// Maps a position in padded coordinates (relative to the original start) to
+// the source index, or `None` for a constant fill
+fn source_index<T>(i: isize, len: usize, mode: &PadMode<T>) -> Option<usize> {
+    let n = len as isize;
+    if (0..n).contains(&i) {
+        return Some(i as usize);
+    }
+    match mode {
+        PadMode::Constant(_) => None,
+        PadMode::Edge => Some(i.clamp(0, n - 1) as usize),
+        PadMode::Reflect if n == 1 => Some(0),
+        PadMode::Reflect => {
+            let period = 2 * (n - 1);
+            let k = i.rem_euclid(period);
+            Some(if k < n { k } else { period - k } as usize)
+        }
+    }
+}
+
+fn check_source<T>(len: usize, padding: usize, mode: &PadMode<T>) -> Result<(), TensorError> {
+    if len == 0 && padding > 0 && !matches!(mode, PadMode::Constant(_)) {
+        return Err(TensorError::InvalidOperation(
+            "pad: edge and reflect padding need a non-empty axis".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
+    /// Pads with `before` elements at the start and `after` at the end;
+    /// `M` must equal `before + N + after`.
+    pub fn pad<const M: usize>(
+        &self,
+        before: usize,
+        after: usize,
+        mode: PadMode<T>,
+    ) -> Result<Vector<T, M>, TensorError> {
+        if before + N + after != M {
+            return Err(TensorError::DimensionMismatch {
+                expected: M.to_string(),
+                found: (before + N + after).to_string(),
+                operation: "Vector pad".to_string(),
+            });
+        }
+        check_source(N, before + after, &mode)?;
+
+        let mut result = Vector::<T, M>::new();
+        for (i, x) in result.iter_mut().enumerate() {
+            *x = match (source_index(i as isize - before as isize, N, &mode), mode) {
+                (Some(j), _) => self[j],
+                (None, PadMode::Constant(v)) => v,
+                (None, _) => unreachable!("only constant padding has no source"),
+            };
+        }
+        Ok(result)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Pads with `top`/`bottom` rows and `left`/`right` columns; `M` must
+    /// equal `left + N + right`.
+    pub fn pad<const M: usize>(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        mode: PadMode<T>,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        if left + N + right != M {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows + top + bottom, M),
+                found: format!("{}x{}", self.rows + top + bottom, left + N + right),
+                operation: "Matrix pad".to_string(),
+            });
+        }
+        check_source(self.rows, top + bottom, &mode)?;
+        check_source(N, left + right, &mode)?;
+
+        let rows = self.rows + top + bottom;
+        let mut result = Matrix::<T, M>::new(rows);
+        for r in 0..rows {
+            let src_row = source_index(r as isize - top as isize, self.rows, &mode);
+            for c in 0..M {
+                let src_col = source_index(c as isize - left as isize, N, &mode);
+                result[r][c] = match (src_row, src_col, mode) {
+                    (Some(i), Some(j), _) => self[i][j],
+                    (_, _, PadMode::Constant(v)) => v,
+                    _ => unreachable!("only constant padding has no source"),
+                };
+            }
+        }
+        Ok(result)
+    }
+}
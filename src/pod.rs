@@ -0,0 +1,54 @@
+//! Zero-copy byte views through `bytemuck`.
+//!
+//! Enabled by the `bytemuck` feature. [`Vector`] is `Pod` and `Zeroable`
+//! whenever its element type is, so slices of vectors cast with
+//! `bytemuck::cast_slice`. A [`Matrix`] owns a contiguous row-major buffer
+//! and lends it as bytes for GPU uploads or file I/O.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+use bytemuck::{Pod, Zeroable};
+use std::marker::PhantomData;
+
+// SAFETY: `Vector<T, N>` is `repr(transparent)` over `[T; N]`, which is
+// all-zeros valid when `T` is
+unsafe impl<T: AllowedNumericTypes + Zeroable, const N: usize> Zeroable for Vector<T, N> {}
+
+// SAFETY: as above; `[T; N]` has no padding and accepts any bit pattern when
+// `T` is `Pod`, and `Vector` is `Copy` and `'static` whenever `T` is
+unsafe impl<T: AllowedNumericTypes + Pod, const N: usize> Pod for Vector<T, N> {}
+
+impl<T: AllowedNumericTypes + Pod, const N: usize> Vector<T, N> {
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
+
+impl<T: AllowedNumericTypes + Pod, const N: usize> Matrix<T, N> {
+    /// The row-major elements as native-endian bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Copies native-endian row-major elements out of `bytes`, which may
+    /// have any alignment but must hold a whole number of rows.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TensorError> {
+        let row_bytes = size_of::<Vector<T, N>>();
+        if row_bytes == 0 || !bytes.len().is_multiple_of(row_bytes) {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("a multiple of {} bytes", row_bytes),
+                found: format!("{} bytes", bytes.len()),
+                operation: "Matrix from_bytes".to_string(),
+            });
+        }
+        Ok(Matrix {
+            data: bytemuck::pod_collect_to_vec(bytes),
+            rows: bytes.len() / row_bytes,
+            layout: PhantomData,
+        })
+    }
+}
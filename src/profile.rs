@@ -0,0 +1,95 @@
+//! Operation counters for finding which operations dominate a pipeline.
+//!
+//! Enabled by the `profiling` feature. Matrix products, determinants,
+//! solves and decompositions record how often they ran, an estimate of the
+//! floating-point operations they performed and the buffers they
+//! allocated. Counters are global across threads; read them with
+//! [`profile`], clear them with [`reset_profile`], or observe every call as
+//! it happens with [`set_profile_callback`].
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, RwLock};
+
+/// Counters for one operation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpStats {
+    pub calls: u64,
+    /// Estimated multiply and add operations.
+    pub flops: u64,
+    /// Result and scratch buffers allocated.
+    pub allocations: u64,
+}
+
+impl OpStats {
+    fn merge(&mut self, other: OpStats) {
+        self.calls += other.calls;
+        self.flops += other.flops;
+        self.allocations += other.allocations;
+    }
+}
+
+/// A snapshot of the counters of every operation that has run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    ops: BTreeMap<&'static str, OpStats>,
+}
+
+impl Profile {
+    /// Counters for `operation`, all zero if it has not run.
+    pub fn get(&self, operation: &str) -> OpStats {
+        self.ops.get(operation).copied().unwrap_or_default()
+    }
+
+    /// Operations in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, OpStats)> + '_ {
+        self.ops.iter().map(|(&name, &stats)| (name, stats))
+    }
+
+    /// Counters summed over all operations.
+    pub fn total(&self) -> OpStats {
+        let mut total = OpStats::default();
+        for &stats in self.ops.values() {
+            total.merge(stats);
+        }
+        total
+    }
+}
+
+/// Called with the counters of each individual call.
+pub type ProfileCallback = fn(&'static str, OpStats);
+
+static PROFILE: Mutex<BTreeMap<&'static str, OpStats>> = Mutex::new(BTreeMap::new());
+static CALLBACK: RwLock<Option<ProfileCallback>> = RwLock::new(None);
+
+/// The counters accumulated since the last [`reset_profile`].
+pub fn profile() -> Profile {
+    let ops = PROFILE.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    Profile { ops }
+}
+
+pub fn reset_profile() {
+    PROFILE.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Installs `callback` to be run after every recorded call, or removes it
+/// with `None`.
+pub fn set_profile_callback(callback: Option<ProfileCallback>) {
+    *CALLBACK.write().unwrap_or_else(|e| e.into_inner()) = callback;
+}
+
+pub(crate) fn record(operation: &'static str, flops: usize, allocations: usize) {
+    let stats = OpStats {
+        calls: 1,
+        flops: flops as u64,
+        allocations: allocations as u64,
+    };
+    PROFILE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(operation)
+        .or_default()
+        .merge(stats);
+    if let Some(callback) = *CALLBACK.read().unwrap_or_else(|e| e.into_inner()) {
+        callback(operation, stats);
+    }
+}
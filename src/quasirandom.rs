@@ -0,0 +1,172 @@
+//! Low-discrepancy (quasi-random) point sequences on the unit hypercube.
+//!
+//! Both generators are iterators over `Vector<f64, N>` points in `[0, 1)^N`
+//! and start at the origin. Use [`Iterator::nth`] to drop leading points and
+//! `points(n)` to collect a batch into a `Matrix`.
+
+use crate::error::TensorError;
+use crate::tensor::{Matrix, Vector};
+
+const BITS: usize = 32;
+
+// Primitive polynomial degree `s`, coefficients `a`, and initial direction
+// numbers `m` for dimensions 2..=16 (Joe & Kuo, new-joe-kuo-6.21201).
+const SOBOL_PARAMS: [(u32, u32, &[u32]); 15] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+];
+
+/// Sobol sequence generator in up to 16 dimensions.
+///
+/// Points are generated in Gray-code order with 32 bits of resolution, so at
+/// most `2^32` points are available.
+#[derive(Clone, Debug)]
+pub struct Sobol<const N: usize> {
+    directions: Vec<[u32; BITS]>,
+    state: [u32; N],
+    index: u64,
+}
+
+impl<const N: usize> Sobol<N> {
+    /// Largest supported dimension.
+    pub const MAX_DIMENSIONS: usize = SOBOL_PARAMS.len() + 1;
+
+    pub fn new() -> Result<Self, TensorError> {
+        if N > Self::MAX_DIMENSIONS {
+            return Err(TensorError::InvalidOperation(format!(
+                "Sobol sequences support at most {} dimensions, got {}",
+                Self::MAX_DIMENSIONS,
+                N
+            )));
+        }
+
+        let mut directions = Vec::with_capacity(N);
+        for d in 0..N {
+            let mut v = [0u32; BITS];
+            if d == 0 {
+                for (k, vk) in v.iter_mut().enumerate() {
+                    *vk = 1 << (BITS - 1 - k);
+                }
+            } else {
+                let (s, a, m) = SOBOL_PARAMS[d - 1];
+                let s = s as usize;
+                for k in 0..BITS {
+                    if k < s {
+                        v[k] = m[k] << (BITS - 1 - k);
+                    } else {
+                        v[k] = v[k - s] ^ (v[k - s] >> s);
+                        for i in 1..s {
+                            if (a >> (s - 1 - i)) & 1 == 1 {
+                                v[k] ^= v[k - i];
+                            }
+                        }
+                    }
+                }
+            }
+            directions.push(v);
+        }
+
+        Ok(Sobol {
+            directions,
+            state: [0; N],
+            index: 0,
+        })
+    }
+
+    /// Collects the next `n` points as the rows of a matrix.
+    pub fn points(&mut self, n: usize) -> Matrix<f64, N> {
+        Matrix::from_vectors(self.take(n).collect())
+    }
+}
+
+impl<const N: usize> Iterator for Sobol<N> {
+    type Item = Vector<f64, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= 1 << BITS {
+            return None;
+        }
+        let point = Vector::from(self.state.map(|x| x as f64 / (1u64 << BITS) as f64));
+
+        // Flip the direction number of the lowest zero bit of the index
+        let c = self.index.trailing_ones() as usize;
+        if c < BITS {
+            for (x, v) in self.state.iter_mut().zip(self.directions.iter()) {
+                *x ^= v[c];
+            }
+        }
+        self.index += 1;
+        Some(point)
+    }
+}
+
+/// Halton sequence generator using the first `N` primes as bases.
+#[derive(Clone, Debug)]
+pub struct Halton<const N: usize> {
+    bases: [u64; N],
+    index: u64,
+}
+
+impl<const N: usize> Default for Halton<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Halton<N> {
+    pub fn new() -> Self {
+        let mut bases = [0u64; N];
+        let mut candidate = 2u64;
+        for base in bases.iter_mut() {
+            while (2..candidate)
+                .take_while(|p| p * p <= candidate)
+                .any(|p| candidate.is_multiple_of(p))
+            {
+                candidate += 1;
+            }
+            *base = candidate;
+            candidate += 1;
+        }
+        Halton { bases, index: 0 }
+    }
+
+    /// Collects the next `n` points as the rows of a matrix.
+    pub fn points(&mut self, n: usize) -> Matrix<f64, N> {
+        Matrix::from_vectors(self.take(n).collect())
+    }
+}
+
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let inv = 1.0 / base as f64;
+    let mut scale = inv;
+    let mut result = 0.0;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inv;
+    }
+    result
+}
+
+impl<const N: usize> Iterator for Halton<N> {
+    type Item = Vector<f64, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.index = self.index.checked_add(1)?;
+        Some(Vector::from(self.bases.map(|b| radical_inverse(index, b))))
+    }
+}
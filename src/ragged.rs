@@ -0,0 +1,131 @@
+//! Matrices whose rows have different lengths.
+//!
+//! [`RaggedMatrix`] stores all elements in one `Vec` plus CSR-style row
+//! offsets (see [`crate::segment`]), so variable-length data such as token
+//! sequences takes no more memory than its elements.
+
+use crate::error::TensorError;
+use crate::segment::check_offsets;
+use crate::tensor::{AllowedNumericTypes, Matrix};
+
+/// Row-major ragged matrix: row `r` is `values[offsets[r]..offsets[r + 1]]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaggedMatrix<T> {
+    values: Vec<T>,
+    offsets: Vec<usize>,
+}
+
+impl<T: AllowedNumericTypes> RaggedMatrix<T> {
+    /// Builds from packed `values` and `rows + 1` offsets starting at zero
+    /// and ending at `values.len()`.
+    pub fn new(values: Vec<T>, offsets: Vec<usize>) -> Result<Self, TensorError> {
+        check_offsets(&offsets, values.len(), "RaggedMatrix::new")?;
+        Ok(RaggedMatrix { values, offsets })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let mut offsets = Vec::with_capacity(rows.len() + 1);
+        offsets.push(0);
+        let mut values = Vec::new();
+        for row in rows {
+            values.extend(row);
+            offsets.push(values.len());
+        }
+        RaggedMatrix { values, offsets }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Total number of stored elements.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    pub fn row_len(&self, row: usize) -> Result<usize, TensorError> {
+        self.row(row).map(|r| r.len())
+    }
+
+    /// Length of the longest row, or zero without rows.
+    pub fn max_row_len(&self) -> usize {
+        self.offsets
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn row(&self, row: usize) -> Result<&[T], TensorError> {
+        if row >= self.rows() {
+            return Err(TensorError::OutOfBounds {
+                index: row.to_string(),
+                size: self.rows().to_string(),
+            });
+        }
+        Ok(&self.values[self.offsets[row]..self.offsets[row + 1]])
+    }
+
+    /// Iterates over rows as slices.
+    pub fn iter(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.offsets.windows(2).map(|w| &self.values[w[0]..w[1]])
+    }
+
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> RaggedMatrix<U> {
+        RaggedMatrix {
+            values: self.values.iter().copied().map(f).collect(),
+            offsets: self.offsets.clone(),
+        }
+    }
+
+    /// Folds every row with `f` starting from `init`; empty rows give `init`.
+    pub fn reduce_rows<F: FnMut(T, T) -> T>(&self, init: T, mut f: F) -> Vec<T> {
+        self.iter()
+            .map(|row| row.iter().fold(init, |acc, &x| f(acc, x)))
+            .collect()
+    }
+
+    pub fn row_sums(&self) -> Vec<T> {
+        self.reduce_rows(T::zero(), |a, b| a + b)
+    }
+
+    /// Pads every row to `N` columns with `fill`.
+    ///
+    /// Returns the padded matrix and a 0/1 mask marking the original
+    /// elements. Fails if a row is longer than `N`.
+    pub fn to_padded<const N: usize>(
+        &self,
+        fill: T,
+    ) -> Result<(Matrix<T, N>, Matrix<T, N>), TensorError> {
+        let longest = self.max_row_len();
+        if longest > N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("rows of at most {} elements", N),
+                found: format!("row of {} elements", longest),
+                operation: "RaggedMatrix to_padded".to_string(),
+            });
+        }
+
+        let mut padded = Matrix::new(self.rows()).map(|_: T| fill);
+        let mut mask = Matrix::new(self.rows());
+        for (r, row) in self.iter().enumerate() {
+            for (c, &x) in row.iter().enumerate() {
+                padded[r][c] = x;
+                mask[r][c] = T::one();
+            }
+        }
+        Ok((padded, mask))
+    }
+}
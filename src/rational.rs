@@ -0,0 +1,84 @@
+//! Exact rational element types.
+//!
+//! Enabled by the `rational` feature. [`Ratio`] over `i32`, `i64` and `i128`
+//! implements [`AllowedNumericTypes`], and rational matrices gain exact
+//! [`Matrix::rref`] and [`Matrix::inverse`]. [`Matrix::determinant_bareiss`]
+//! is already exact for them.
+//!
+//! Arithmetic panics on overflow of the underlying integers, like the
+//! primitive integer types do in debug builds.
+
+use crate::error::TensorError;
+use crate::linalg::gauss_jordan;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+pub use num_rational::Ratio;
+
+macro_rules! ratio_impls {
+    ($($int:ty),+) => {
+        $(
+            /// `from_f64` finds the closest ratio that fits the integer type,
+            /// or zero if there is none (NaN, infinities, huge values).
+            impl AllowedNumericTypes for Ratio<$int> {
+                fn zero() -> Self {
+                    Ratio::from_integer(0)
+                }
+                fn one() -> Self {
+                    Ratio::from_integer(1)
+                }
+                fn is_zero(&self) -> bool {
+                    *self.numer() == 0
+                }
+                fn from_f64(value: f64) -> Self {
+                    Ratio::approximate_float(value).unwrap_or_else(Self::zero)
+                }
+                fn to_f64(self) -> f64 {
+                    *self.numer() as f64 / *self.denom() as f64
+                }
+            }
+        )+
+    };
+}
+
+ratio_impls!(i32, i64, i128);
+
+impl<I, const N: usize> Matrix<Ratio<I>, N>
+where
+    Ratio<I>: AllowedNumericTypes,
+{
+    /// Exact reduced row echelon form.
+    pub fn rref(&self) -> Self {
+        let mut rows: Vec<Vec<Ratio<I>>> = self.as_rows().iter().map(|v| v.data.to_vec()).collect();
+        gauss_jordan(&mut rows, N);
+        Matrix::from_vectors(
+            rows.iter()
+                .map(|row| Vector::from(std::array::from_fn(|j| row[j])))
+                .collect(),
+        )
+    }
+
+    /// Exact inverse by Gauss-Jordan elimination on `[A | I]`; fails if the
+    /// matrix is not square or is singular.
+    pub fn inverse(&self) -> Result<Self, TensorError> {
+        self.check_square("inverse")?;
+        let mut rows: Vec<Vec<Ratio<I>>> = self
+            .as_rows()
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let mut row = v.data.to_vec();
+                row.extend((0..N).map(|j| if i == j { Ratio::one() } else { Ratio::zero() }));
+                row
+            })
+            .collect();
+        if gauss_jordan(&mut rows, N) < N {
+            return Err(TensorError::InvalidOperation(
+                "matrix is singular".to_string(),
+            ));
+        }
+        Ok(Matrix::from_vectors(
+            rows.iter()
+                .map(|row| Vector::from(std::array::from_fn(|j| row[N + j])))
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,166 @@
+//! Extrema of vectors and matrices that never panic on NaN.
+//!
+//! `max`, `min`, `argmax` and `argmin` (`max_element` and `min_element` on
+//! vectors, which also have `Ord::max`) fail with
+//! [`TensorError::InvalidOperation`] when the input is empty or contains an
+//! unordered value (a float NaN), instead of silently returning a value that
+//! depends on where the NaN sits. The `nan*` variants skip unordered values,
+//! like NumPy's `nanmax`, and fail only when nothing ordered remains. Ties
+//! resolve to the first occurrence in row-major order.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+#[cfg(feature = "parallel")]
+use crate::tensor_impl::PARALLEL_MIN_LEN;
+use std::cmp::Ordering;
+
+fn is_unordered<T: PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+fn nan_error(operation: &str, index: usize) -> TensorError {
+    TensorError::InvalidOperation(format!(
+        "{}: encountered NaN at flat index {}",
+        operation, index
+    ))
+}
+
+fn empty_error(operation: &str) -> TensorError {
+    TensorError::InvalidOperation(format!("{}: no ordered elements", operation))
+}
+
+// `extreme` for the maximum, on rayon; a NaN is reported at the same index
+// and ties keep the first occurrence
+#[cfg(feature = "parallel")]
+fn par_max<T: PartialOrd + Copy + Send + Sync>(values: &[T]) -> Result<T, TensorError> {
+    use rayon::prelude::*;
+
+    if let Some(i) = values.par_iter().position_first(is_unordered) {
+        return Err(nan_error("max", i));
+    }
+    values
+        .par_iter()
+        .copied()
+        .reduce_with(|a, b| if b > a { b } else { a })
+        .ok_or_else(|| empty_error("max"))
+}
+
+// First index holding the extreme value, where `better` is the ordering a
+// candidate must have against the current best to replace it
+fn extreme<T: PartialOrd + Copy>(
+    values: impl Iterator<Item = T>,
+    better: Ordering,
+    skip_nan: bool,
+    operation: &str,
+) -> Result<(usize, T), TensorError> {
+    let mut best: Option<(usize, T)> = None;
+    for (i, x) in values.enumerate() {
+        if is_unordered(&x) {
+            if skip_nan {
+                continue;
+            }
+            return Err(nan_error(operation, i));
+        }
+        match best {
+            Some((_, b)) if x.partial_cmp(&b) != Some(better) => {}
+            _ => best = Some((i, x)),
+        }
+    }
+    best.ok_or_else(|| empty_error(operation))
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Vector<T, N> {
+    /// The largest element; named apart from `Ord::max`, which vectors of
+    /// ordered elements also have.
+    pub fn max_element(&self) -> Result<T, TensorError> {
+        extreme(self.iter().copied(), Ordering::Greater, false, "max").map(|(_, x)| x)
+    }
+
+    /// The smallest element; see [`Vector::max_element`].
+    pub fn min_element(&self) -> Result<T, TensorError> {
+        extreme(self.iter().copied(), Ordering::Less, false, "min").map(|(_, x)| x)
+    }
+
+    pub fn argmax(&self) -> Result<usize, TensorError> {
+        extreme(self.iter().copied(), Ordering::Greater, false, "argmax").map(|(i, _)| i)
+    }
+
+    pub fn argmin(&self) -> Result<usize, TensorError> {
+        extreme(self.iter().copied(), Ordering::Less, false, "argmin").map(|(i, _)| i)
+    }
+
+    /// Largest element, ignoring NaNs.
+    pub fn nanmax(&self) -> Result<T, TensorError> {
+        extreme(self.iter().copied(), Ordering::Greater, true, "nanmax").map(|(_, x)| x)
+    }
+
+    /// Smallest element, ignoring NaNs.
+    pub fn nanmin(&self) -> Result<T, TensorError> {
+        extreme(self.iter().copied(), Ordering::Less, true, "nanmin").map(|(_, x)| x)
+    }
+
+    /// Index of the largest element, ignoring NaNs.
+    pub fn nanargmax(&self) -> Result<usize, TensorError> {
+        extreme(self.iter().copied(), Ordering::Greater, true, "nanargmax").map(|(i, _)| i)
+    }
+
+    /// Index of the smallest element, ignoring NaNs.
+    pub fn nanargmin(&self) -> Result<usize, TensorError> {
+        extreme(self.iter().copied(), Ordering::Less, true, "nanargmin").map(|(i, _)| i)
+    }
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Matrix<T, N> {
+    fn flat(&self) -> impl Iterator<Item = T> + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    // Flat row-major index to `(row, col)`
+    fn unflatten(i: usize) -> (usize, usize) {
+        (i / N, i % N)
+    }
+
+    /// With the `parallel` feature, matrices of at least 32768 elements are
+    /// scanned on several threads, with the same result and error.
+    pub fn max(&self) -> Result<T, TensorError> {
+        #[cfg(feature = "parallel")]
+        if self.as_slice().len() >= PARALLEL_MIN_LEN {
+            return par_max(self.as_slice());
+        }
+        extreme(self.flat(), Ordering::Greater, false, "max").map(|(_, x)| x)
+    }
+
+    pub fn min(&self) -> Result<T, TensorError> {
+        extreme(self.flat(), Ordering::Less, false, "min").map(|(_, x)| x)
+    }
+
+    /// `(row, col)` of the largest element.
+    pub fn argmax(&self) -> Result<(usize, usize), TensorError> {
+        extreme(self.flat(), Ordering::Greater, false, "argmax").map(|(i, _)| Self::unflatten(i))
+    }
+
+    /// `(row, col)` of the smallest element.
+    pub fn argmin(&self) -> Result<(usize, usize), TensorError> {
+        extreme(self.flat(), Ordering::Less, false, "argmin").map(|(i, _)| Self::unflatten(i))
+    }
+
+    /// Largest element, ignoring NaNs.
+    pub fn nanmax(&self) -> Result<T, TensorError> {
+        extreme(self.flat(), Ordering::Greater, true, "nanmax").map(|(_, x)| x)
+    }
+
+    /// Smallest element, ignoring NaNs.
+    pub fn nanmin(&self) -> Result<T, TensorError> {
+        extreme(self.flat(), Ordering::Less, true, "nanmin").map(|(_, x)| x)
+    }
+
+    /// `(row, col)` of the largest element, ignoring NaNs.
+    pub fn nanargmax(&self) -> Result<(usize, usize), TensorError> {
+        extreme(self.flat(), Ordering::Greater, true, "nanargmax").map(|(i, _)| Self::unflatten(i))
+    }
+
+    /// `(row, col)` of the smallest element, ignoring NaNs.
+    pub fn nanargmin(&self) -> Result<(usize, usize), TensorError> {
+        extreme(self.flat(), Ordering::Less, true, "nanargmin").map(|(i, _)| Self::unflatten(i))
+    }
+}
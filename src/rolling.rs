@@ -0,0 +1,90 @@
+//! Fixed-capacity sliding window of row vectors.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+/// Ring buffer keeping the last `capacity` rows pushed into it.
+///
+/// Pushing is O(1): once full, the oldest row is overwritten in place. Rows
+/// are always reported oldest first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingMatrix<T: AllowedNumericTypes, const N: usize> {
+    buffer: Vec<Vector<T, N>>,
+    capacity: usize,
+    // Index of the oldest row once the buffer is full
+    head: usize,
+}
+
+impl<T: AllowedNumericTypes, const N: usize> RollingMatrix<T, N> {
+    /// An empty window holding at most `capacity` rows. With zero capacity
+    /// every push is discarded.
+    pub fn new(capacity: usize) -> Self {
+        RollingMatrix {
+            buffer: Vec::with_capacity(capacity),
+            capacity,
+            head: 0,
+        }
+    }
+
+    /// Appends `row`, returning the evicted oldest row once full.
+    pub fn push(&mut self, row: Vector<T, N>) -> Option<Vector<T, N>> {
+        if self.capacity == 0 {
+            return Some(row);
+        }
+        if self.buffer.len() < self.capacity {
+            self.buffer.push(row);
+            return None;
+        }
+        let evicted = std::mem::replace(&mut self.buffer[self.head], row);
+        self.head = (self.head + 1) % self.capacity;
+        Some(evicted)
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.buffer.len() == self.capacity
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.head = 0;
+    }
+
+    /// The `index`-th row, counting from the oldest.
+    pub fn get(&self, index: usize) -> Result<&Vector<T, N>, TensorError> {
+        if index >= self.buffer.len() {
+            return Err(TensorError::OutOfBounds {
+                index: index.to_string(),
+                size: self.buffer.len().to_string(),
+            });
+        }
+        Ok(&self.buffer[(self.head + index) % self.buffer.len()])
+    }
+
+    /// The most recently pushed row.
+    pub fn latest(&self) -> Option<&Vector<T, N>> {
+        self.len().checked_sub(1).and_then(|i| self.get(i).ok())
+    }
+
+    /// Iterates over rows from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &Vector<T, N>> + '_ {
+        let (newer, older) = self.buffer.split_at(self.head);
+        older.iter().chain(newer.iter())
+    }
+
+    /// Copies the window into a matrix, oldest row first.
+    pub fn as_matrix(&self) -> Matrix<T, N> {
+        Matrix::from_vectors(self.iter().cloned().collect())
+    }
+}
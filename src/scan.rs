@@ -0,0 +1,148 @@
+//! Inclusive prefix scans (cumulative sums and products).
+//!
+//! With the `parallel` feature, the `par_*` variants use a work-efficient
+//! two-pass blocked scan: every block is scanned independently, the block
+//! totals are combined serially, and the resulting offsets are applied to
+//! every block in parallel. Float results may differ from the serial scan in
+//! the last bits because additions are grouped differently.
+
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+fn scan_in_place<T: AllowedNumericTypes>(values: &mut [T], op: &impl Fn(T, T) -> T) {
+    let mut acc: Option<T> = None;
+    for x in values.iter_mut() {
+        if let Some(a) = acc {
+            *x = op(a, *x);
+        }
+        acc = Some(*x);
+    }
+}
+
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_BLOCK: usize = 4096;
+
+#[cfg(feature = "parallel")]
+fn par_scan_in_place<T, F>(values: &mut [T], op: &F)
+where
+    T: AllowedNumericTypes + Send + Sync,
+    F: Fn(T, T) -> T + Sync,
+{
+    use rayon::prelude::*;
+
+    let block = values
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(MIN_PARALLEL_BLOCK);
+    if values.len() <= block {
+        scan_in_place(values, op);
+        return;
+    }
+
+    values
+        .par_chunks_mut(block)
+        .for_each(|chunk| scan_in_place(chunk, op));
+
+    // Exclusive scan of block totals gives the offset for each block
+    let mut offsets = Vec::with_capacity(values.len().div_ceil(block));
+    let mut acc: Option<T> = None;
+    for chunk in values.chunks(block) {
+        offsets.push(acc);
+        let total = chunk[chunk.len() - 1];
+        acc = Some(match acc {
+            Some(a) => op(a, total),
+            None => total,
+        });
+    }
+
+    values
+        .par_chunks_mut(block)
+        .zip(offsets.par_iter())
+        .for_each(|(chunk, offset)| {
+            if let Some(o) = *offset {
+                for x in chunk.iter_mut() {
+                    *x = op(o, *x);
+                }
+            }
+        });
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
+    /// Cumulative sum: element `i` is the sum of elements `0..=i`.
+    pub fn cumsum(&self) -> Self {
+        let mut result = *self;
+        scan_in_place(&mut result.data, &|a, b| a + b);
+        result
+    }
+
+    /// Cumulative product: element `i` is the product of elements `0..=i`.
+    pub fn cumprod(&self) -> Self {
+        let mut result = *self;
+        scan_in_place(&mut result.data, &|a, b| a * b);
+        result
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Cumulative sum along each row.
+    pub fn cumsum_rows(&self) -> Self {
+        let mut result = self.clone();
+        for row in result.as_rows_mut() {
+            scan_in_place(&mut row.data, &|a, b| a + b);
+        }
+        result
+    }
+
+    /// Cumulative product along each row.
+    pub fn cumprod_rows(&self) -> Self {
+        let mut result = self.clone();
+        for row in result.as_rows_mut() {
+            scan_in_place(&mut row.data, &|a, b| a * b);
+        }
+        result
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: AllowedNumericTypes + Send + Sync, const N: usize> Vector<T, N> {
+    /// Parallel [`Vector::cumsum`].
+    pub fn par_cumsum(&self) -> Self {
+        let mut result = *self;
+        par_scan_in_place(&mut result.data, &|a, b| a + b);
+        result
+    }
+
+    /// Parallel [`Vector::cumprod`].
+    pub fn par_cumprod(&self) -> Self {
+        let mut result = *self;
+        par_scan_in_place(&mut result.data, &|a, b| a * b);
+        result
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: AllowedNumericTypes + Send + Sync, const N: usize> Matrix<T, N> {
+    /// Parallel [`Matrix::cumsum_rows`]; rows are scanned concurrently and
+    /// long rows are themselves split into blocks.
+    pub fn par_cumsum_rows(&self) -> Self {
+        use rayon::prelude::*;
+
+        let mut result = self.clone();
+        result
+            .as_rows_mut()
+            .par_iter_mut()
+            .for_each(|row| par_scan_in_place(&mut row.data, &|a, b| a + b));
+        result
+    }
+
+    /// Parallel [`Matrix::cumprod_rows`].
+    pub fn par_cumprod_rows(&self) -> Self {
+        use rayon::prelude::*;
+
+        let mut result = self.clone();
+        result
+            .as_rows_mut()
+            .par_iter_mut()
+            .for_each(|row| par_scan_in_place(&mut row.data, &|a, b| a * b));
+        result
+    }
+}
@@ -0,0 +1,75 @@
+//! Reductions over contiguous segments.
+//!
+//! Segments are described CSR-style by `offsets`: segment `s` covers
+//! `offsets[s]..offsets[s + 1]`, so `S` segments need `S + 1` offsets
+//! starting at zero, never decreasing, and ending at the reduced length.
+//! Empty segments are allowed and sum to zero. This packs variable-length
+//! sequences into one vector or matrix without padding.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+pub(crate) fn check_offsets(
+    offsets: &[usize],
+    len: usize,
+    operation: &str,
+) -> Result<(), TensorError> {
+    let valid = offsets.first() == Some(&0)
+        && offsets.last() == Some(&len)
+        && offsets.windows(2).all(|w| w[0] <= w[1]);
+    if !valid {
+        return Err(TensorError::InvalidOperation(format!(
+            "{}: offsets must start at 0, be non-decreasing and end at {}, got {:?}",
+            operation, len, offsets
+        )));
+    }
+    Ok(())
+}
+
+/// Sums `values` over each segment, returning one total per segment.
+pub fn segmented_sum<T: AllowedNumericTypes, const N: usize>(
+    values: &Vector<T, N>,
+    offsets: &[usize],
+) -> Result<Vec<T>, TensorError> {
+    check_offsets(offsets, N, "segmented_sum")?;
+    Ok(offsets
+        .windows(2)
+        .map(|w| {
+            values
+                .iter()
+                .skip(w[0])
+                .take(w[1] - w[0])
+                .fold(T::zero(), |acc, &x| acc + x)
+        })
+        .collect())
+}
+
+/// Sums groups of consecutive rows: row `s` of the result is the sum of rows
+/// `offsets[s]..offsets[s + 1]` of `values`.
+pub fn segmented_sum_rows<T: AllowedNumericTypes, const N: usize>(
+    values: &Matrix<T, N>,
+    offsets: &[usize],
+) -> Result<Matrix<T, N>, TensorError> {
+    check_offsets(offsets, values.rows, "segmented_sum_rows")?;
+    let mut result = Matrix::new(offsets.len() - 1);
+    for (s, w) in offsets.windows(2).enumerate() {
+        for r in w[0]..w[1] {
+            for (acc, &x) in result[s].iter_mut().zip(values[r].iter()) {
+                *acc = *acc + x;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Sums each row of `values` over the same column segments, giving a
+/// `rows x S` result as one `Vec` per row.
+pub fn segmented_sum_cols<T: AllowedNumericTypes, const N: usize>(
+    values: &Matrix<T, N>,
+    offsets: &[usize],
+) -> Result<Vec<Vec<T>>, TensorError> {
+    check_offsets(offsets, N, "segmented_sum_cols")?;
+    (0..values.rows)
+        .map(|r| segmented_sum(&values[r], offsets))
+        .collect()
+}
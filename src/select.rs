@@ -0,0 +1,69 @@
+//! Partial selection of the largest elements per row.
+//!
+//! [`Matrix::top_k_per_row`] partitions each row with quickselect and sorts
+//! only the selected `k` elements, so a row costs `O(N + k log k)` instead of
+//! the `O(N log N)` of a full sort.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix};
+use std::cmp::Ordering;
+
+/// The `k` largest elements of every row, in descending order.
+///
+/// `values[r][i]` is the `i`-th largest element of row `r` and
+/// `indices[r][i]` its column. Equal values are ordered by column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TopK<T> {
+    pub values: Vec<Vec<T>>,
+    pub indices: Vec<Vec<usize>>,
+}
+
+// Values not even equal to themselves, such as float NaNs
+fn is_nan<T: PartialOrd>(x: T) -> bool {
+    x.partial_cmp(&x).is_none()
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Matrix<T, N> {
+    /// Selects the `k` largest elements of every row without sorting the
+    /// whole row.
+    ///
+    /// Fails if `k > N`. NaNs rank below every other value, so they are
+    /// selected only once a row has fewer than `k` other elements.
+    pub fn top_k_per_row(&self, k: usize) -> Result<TopK<T>, TensorError> {
+        if k > N {
+            return Err(TensorError::InvalidOperation(format!(
+                "top_k_per_row: k = {} exceeds row length {}",
+                k, N
+            )));
+        }
+
+        let mut values = Vec::with_capacity(self.rows);
+        let mut indices = Vec::with_capacity(self.rows);
+        let mut order: Vec<usize> = Vec::with_capacity(N);
+        for r in 0..self.rows {
+            let row = &self[r];
+            // Descending by value with NaNs last, then ascending by column: a
+            // total order, as the sorts require
+            let cmp = |&a: &usize, &b: &usize| {
+                let (x, y) = (row[a], row[b]);
+                let by_value = match (is_nan(x), is_nan(y)) {
+                    (false, false) => y.partial_cmp(&x).unwrap_or(Ordering::Equal),
+                    (nan_x, nan_y) => nan_x.cmp(&nan_y),
+                };
+                by_value.then(a.cmp(&b))
+            };
+
+            order.clear();
+            order.extend(0..N);
+            if k > 0 && k < N {
+                order.select_nth_unstable_by(k - 1, cmp);
+            }
+            order.truncate(k);
+            order.sort_unstable_by(cmp);
+
+            values.push(order.iter().map(|&c| row[c]).collect());
+            indices.push(order.clone());
+        }
+        Ok(TopK { values, indices })
+    }
+}
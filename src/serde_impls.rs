@@ -0,0 +1,60 @@
+//! `Serialize` and `Deserialize` for vectors, matrices and tensors.
+//!
+//! Enabled by the `serde` feature. A [`Vector`] is a sequence of `N`
+//! elements, a [`Matrix`] a sequence of rows and a [`Tensor`] a sequence of
+//! depth matrices. Deserializing checks that every row has exactly `N`
+//! elements and every depth the same number of rows.
+//!
+//! The nested arrays carry no separate shape, so a tensor with no depths
+//! serializes as `[]` whatever its row count and reads back as `0x0`. The
+//! binary, `.npy` and `.npz` formats store the shape and keep it.
+
+use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+impl<T: AllowedNumericTypes + Serialize, const N: usize> Serialize for Vector<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<T: AllowedNumericTypes + Serialize, const N: usize> Serialize for Matrix<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_rows())
+    }
+}
+
+impl<T: AllowedNumericTypes + Serialize, const N: usize> Serialize for Tensor<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq((0..self.depths).map(|d| &self[d]))
+    }
+}
+
+impl<'de, T: AllowedNumericTypes + Deserialize<'de>, const N: usize> Deserialize<'de>
+    for Vector<T, N>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<T>::deserialize(deserializer)?;
+        Vector::from_slice(&elements).map_err(|_| {
+            D::Error::invalid_length(elements.len(), &format!("{} elements", N).as_str())
+        })
+    }
+}
+
+impl<'de, T: AllowedNumericTypes + Deserialize<'de>, const N: usize> Deserialize<'de>
+    for Matrix<T, N>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Vector<T, N>>::deserialize(deserializer).map(Matrix::from_vectors)
+    }
+}
+
+impl<'de, T: AllowedNumericTypes + Deserialize<'de>, const N: usize> Deserialize<'de>
+    for Tensor<T, N>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let matrices = Vec::<Matrix<T, N>>::deserialize(deserializer)?;
+        Tensor::from_matrices(matrices).map_err(D::Error::custom)
+    }
+}
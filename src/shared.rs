@@ -0,0 +1,65 @@
+//! Copy-on-write matrices with O(1) clones.
+//!
+//! [`SharedMatrix`] keeps its [`Matrix`] behind an `Arc`, so cloning only
+//! bumps a reference count and read-only stages can pass the same buffer
+//! along. It dereferences to `&Matrix` for every read-only operation; the
+//! first mutation through [`SharedMatrix::make_mut`] copies the data if any
+//! other clone still refers to it.
+
+use crate::tensor::{AllowedNumericTypes, Matrix};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A reference-counted, copy-on-write [`Matrix`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SharedMatrix<T: AllowedNumericTypes, const N: usize> {
+    inner: Arc<Matrix<T, N>>,
+}
+
+impl<T: AllowedNumericTypes, const N: usize> SharedMatrix<T, N> {
+    pub fn new(matrix: Matrix<T, N>) -> Self {
+        SharedMatrix {
+            inner: Arc::new(matrix),
+        }
+    }
+
+    /// Mutable access, copying the matrix first if it is shared.
+    pub fn make_mut(&mut self) -> &mut Matrix<T, N> {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// The owned matrix; copies only if other clones are still alive.
+    pub fn into_matrix(self) -> Matrix<T, N> {
+        Arc::unwrap_or_clone(self.inner)
+    }
+
+    /// True if both refer to the same buffer.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Number of clones sharing this buffer.
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Deref for SharedMatrix<T, N> {
+    type Target = Matrix<T, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> AsRef<Matrix<T, N>> for SharedMatrix<T, N> {
+    fn as_ref(&self) -> &Matrix<T, N> {
+        &self.inner
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> From<Matrix<T, N>> for SharedMatrix<T, N> {
+    fn from(matrix: Matrix<T, N>) -> Self {
+        Self::new(matrix)
+    }
+}
@@ -0,0 +1,93 @@
+//! Vectorized `f32`/`f64` kernels behind the `simd` feature.
+//!
+//! These back the [`AllowedNumericTypes::dot_slices`] and
+//! [`AllowedNumericTypes::axpy_slices`] overrides for the primitive floats,
+//! and [`AllowedNumericTypes::kahan_axpy_slices`] for matrix products.
+//! Slices are processed in full SIMD lanes with a scalar loop for the tail.
+//! Lane-wise accumulation reorders the additions, so results can differ from
+//! the scalar kernels in the last bits. The compensated axpy runs the same
+//! operations per element as the scalar one, so matrix products are
+//! unchanged; with `fast-math` products use the plain axpy instead.
+//! Element-wise operations on the
+//! fixed-size arrays already compile to vector instructions and need no
+//! explicit kernels.
+//!
+//! [`AllowedNumericTypes::dot_slices`]: crate::tensor::AllowedNumericTypes::dot_slices
+//! [`AllowedNumericTypes::axpy_slices`]: crate::tensor::AllowedNumericTypes::axpy_slices
+//! [`AllowedNumericTypes::kahan_axpy_slices`]: crate::tensor::AllowedNumericTypes::kahan_axpy_slices
+
+use wide::{f32x8, f64x4};
+
+macro_rules! float_kernels {
+    ($float:ty, $simd:ty, $lanes:literal, $dot:ident, $axpy:ident, $kahan_axpy:ident) => {
+        pub(crate) fn $dot(a: &[$float], b: &[$float]) -> $float {
+            let (a_chunks, b_chunks) = (a.chunks_exact($lanes), b.chunks_exact($lanes));
+            let tail = a_chunks
+                .remainder()
+                .iter()
+                .zip(b_chunks.remainder())
+                .fold(0.0, |acc, (&x, &y)| acc + x * y);
+            let mut sum = <$simd>::ZERO;
+            for (x, y) in a_chunks.zip(b_chunks) {
+                let x = <$simd>::from(<[$float; $lanes]>::try_from(x).unwrap());
+                let y = <$simd>::from(<[$float; $lanes]>::try_from(y).unwrap());
+                sum = x.mul_add(y, sum);
+            }
+            sum.reduce_add() + tail
+        }
+
+        pub(crate) fn $axpy(acc: &mut [$float], alpha: $float, x: &[$float]) {
+            let alpha_lanes = <$simd>::splat(alpha);
+            let mut acc_chunks = acc.chunks_exact_mut($lanes);
+            let mut x_chunks = x.chunks_exact($lanes);
+            for (a, x) in (&mut acc_chunks).zip(&mut x_chunks) {
+                let lanes = <$simd>::from(<[$float; $lanes]>::try_from(&*a).unwrap());
+                let x = <$simd>::from(<[$float; $lanes]>::try_from(x).unwrap());
+                a.copy_from_slice(&x.mul_add(alpha_lanes, lanes).to_array());
+            }
+            for (a, &x) in acc_chunks
+                .into_remainder()
+                .iter_mut()
+                .zip(x_chunks.remainder())
+            {
+                *a += alpha * x;
+            }
+        }
+
+        #[cfg(not(feature = "fast-math"))]
+        pub(crate) fn $kahan_axpy(
+            acc: &mut [$float],
+            comp: &mut [$float],
+            alpha: $float,
+            x: &[$float],
+        ) {
+            let alpha_lanes = <$simd>::splat(alpha);
+            let mut acc_chunks = acc.chunks_exact_mut($lanes);
+            let mut comp_chunks = comp.chunks_exact_mut($lanes);
+            let mut x_chunks = x.chunks_exact($lanes);
+            for ((a, c), x) in (&mut acc_chunks).zip(&mut comp_chunks).zip(&mut x_chunks) {
+                let sum = <$simd>::from(<[$float; $lanes]>::try_from(&*a).unwrap());
+                let comp_lanes = <$simd>::from(<[$float; $lanes]>::try_from(&*c).unwrap());
+                let x = <$simd>::from(<[$float; $lanes]>::try_from(x).unwrap());
+                let y = alpha_lanes * x - comp_lanes;
+                let t = sum + y;
+                c.copy_from_slice(&((t - sum) - y).to_array());
+                a.copy_from_slice(&t.to_array());
+            }
+            let tail = acc_chunks
+                .into_remainder()
+                .iter_mut()
+                .zip(comp_chunks.into_remainder())
+                .zip(x_chunks.remainder());
+            for ((sum, c), &x) in tail {
+                let y = alpha * x - *c;
+                let t = *sum + y;
+                *c = (t - *sum) - y;
+                *sum = t;
+            }
+        }
+    };
+}
+
+float_kernels!(f32, f32x8, 8, dot_f32, axpy_f32, kahan_axpy_f32);
+float_kernels!(f64, f64x4, 4, dot_f64, axpy_f64, kahan_axpy_f64);
@@ -0,0 +1,254 @@
+//! Fixed-shape matrices stored inline, for code that must not allocate.
+//!
+//! [`StackMatrix`] keeps its elements in a `[[T; C]; R]` array, so it lives
+//! entirely on the stack (or inside whatever contains it) and every shape
+//! check happens at compile time. Only the core operations are provided;
+//! convert to [`Matrix`] for everything else, with `From`/`TryFrom` or
+//! [`StackMatrix::to_matrix`]/[`StackMatrix::from_matrix`]. [`SMatrix`] is a
+//! shorter name for the same type.
+
+use crate::error::TensorError;
+use crate::linalg::{small_determinant, small_inverse};
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes, Matrix, Vector};
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+/// An `R x C` matrix stored as `[[T; C]; R]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StackMatrix<T: AllowedNumericTypes, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+/// A fully static `R x C` matrix; an alias of [`StackMatrix`].
+pub type SMatrix<T, const R: usize, const C: usize> = StackMatrix<T, R, C>;
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> Default for StackMatrix<T, R, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> StackMatrix<T, R, C> {
+    /// A zero matrix.
+    pub fn new() -> Self {
+        StackMatrix {
+            data: [[T::zero(); C]; R],
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Self {
+        StackMatrix {
+            data: self.data.map(|row| row.map(|x| x * scalar)),
+        }
+    }
+
+    pub fn transpose(&self) -> StackMatrix<T, C, R> {
+        StackMatrix {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| self.data[i][j])),
+        }
+    }
+
+    /// Copies into a heap-backed [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<T, C> {
+        Matrix::from_vectors(self.data.iter().map(|&row| Vector::from(row)).collect())
+    }
+
+    /// Copies from a [`Matrix`], which must have exactly `R` rows.
+    pub fn from_matrix(m: &Matrix<T, C>) -> Result<Self, TensorError> {
+        if m.rows != R {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", R, C),
+                found: format!("{}x{}", m.rows, C),
+                operation: "StackMatrix from_matrix".to_string(),
+            });
+        }
+        Ok(StackMatrix {
+            data: std::array::from_fn(|i| m[i].data),
+        })
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> StackMatrix<T, N, N> {
+    pub fn identity() -> Self {
+        let mut result = Self::new();
+        for i in 0..N {
+            result.data[i][i] = T::one();
+        }
+        result
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> StackMatrix<T, N, N> {
+    // Gauss-Jordan elimination with partial pivoting applied to `self` and
+    // `rhs` together; returns the determinant, which is zero if a pivot is
+    // within `tol` of zero
+    fn eliminate(&self, rhs: &mut Self, tol: T) -> T {
+        let mut a = self.data;
+        let mut det = T::one();
+        for col in 0..N {
+            let mut pivot = col;
+            for row in col + 1..N {
+                if a[row][col].abs() > a[pivot][col].abs() {
+                    pivot = row;
+                }
+            }
+            if a[pivot][col].is_zero_with_tol(tol) {
+                return T::zero();
+            }
+            if pivot != col {
+                a.swap(pivot, col);
+                rhs.data.swap(pivot, col);
+                det = T::zero() - det;
+            }
+
+            let p = a[col][col];
+            det = det * p;
+            let pivot_row = a[col].map(|x| x / p);
+            let pivot_rhs = rhs.data[col].map(|x| x / p);
+            for (row, (a_row, rhs_row)) in a.iter_mut().zip(rhs.data.iter_mut()).enumerate() {
+                if row == col {
+                    *a_row = pivot_row;
+                    *rhs_row = pivot_rhs;
+                    continue;
+                }
+                let factor = a_row[col];
+                for (x, &y) in a_row.iter_mut().zip(pivot_row.iter()) {
+                    *x = *x - factor * y;
+                }
+                for (x, &y) in rhs_row.iter_mut().zip(pivot_rhs.iter()) {
+                    *x = *x - factor * y;
+                }
+            }
+        }
+        det
+    }
+
+    /// Determinant; closed-form for sizes up to 4x4, by elimination beyond.
+    pub fn determinant(&self) -> T {
+        small_determinant(N, |i, j| self.data[i][j])
+            .unwrap_or_else(|| self.determinant_with_tol(T::zero()))
+    }
+
+    /// Determinant that is exactly zero when a pivot's magnitude is at most
+    /// `tol`, so nearly singular matrices are reported as singular.
+    pub fn determinant_with_tol(&self, tol: T) -> T {
+        self.eliminate(&mut Self::new(), tol)
+    }
+
+    /// Inverse by Gauss-Jordan elimination, or from the adjugate for sizes
+    /// up to 4x4; fails if the matrix is singular.
+    pub fn inverse(&self) -> Result<Self, TensorError> {
+        match small_inverse(&self.data) {
+            Some(Some(data)) => Ok(StackMatrix { data }),
+            Some(None) => Err(TensorError::InvalidOperation(
+                "StackMatrix is singular".to_string(),
+            )),
+            None => self.inverse_with_tol(T::zero()),
+        }
+    }
+
+    /// Like [`StackMatrix::inverse`], but also fails when a pivot's
+    /// magnitude is at most `tol`.
+    pub fn inverse_with_tol(&self, tol: T) -> Result<Self, TensorError> {
+        let mut inv = Self::identity();
+        if self.eliminate(&mut inv, tol).is_zero() {
+            return Err(TensorError::InvalidOperation(
+                "StackMatrix is singular".to_string(),
+            ));
+        }
+        Ok(inv)
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> From<[[T; C]; R]>
+    for StackMatrix<T, R, C>
+{
+    fn from(data: [[T; C]; R]) -> Self {
+        StackMatrix { data }
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> From<StackMatrix<T, R, C>>
+    for [[T; C]; R]
+{
+    fn from(m: StackMatrix<T, R, C>) -> Self {
+        m.data
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> From<StackMatrix<T, R, C>>
+    for Matrix<T, C>
+{
+    fn from(m: StackMatrix<T, R, C>) -> Self {
+        m.to_matrix()
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> TryFrom<&Matrix<T, C>>
+    for StackMatrix<T, R, C>
+{
+    type Error = TensorError;
+
+    fn try_from(m: &Matrix<T, C>) -> Result<Self, Self::Error> {
+        Self::from_matrix(m)
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> Index<usize> for StackMatrix<T, R, C> {
+    type Output = [T; C];
+
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.data[row]
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> IndexMut<usize>
+    for StackMatrix<T, R, C>
+{
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        &mut self.data[row]
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> Add for StackMatrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        StackMatrix {
+            data: std::array::from_fn(|i| {
+                std::array::from_fn(|j| self.data[i][j] + rhs.data[i][j])
+            }),
+        }
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const C: usize> Sub for StackMatrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        StackMatrix {
+            data: std::array::from_fn(|i| {
+                std::array::from_fn(|j| self.data[i][j] - rhs.data[i][j])
+            }),
+        }
+    }
+}
+
+impl<T: AllowedNumericTypes, const R: usize, const K: usize, const C: usize>
+    Mul<StackMatrix<T, K, C>> for StackMatrix<T, R, K>
+{
+    type Output = StackMatrix<T, R, C>;
+
+    fn mul(self, rhs: StackMatrix<T, K, C>) -> Self::Output {
+        StackMatrix {
+            data: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    (0..K).fold(T::zero(), |acc, k| acc + self.data[i][k] * rhs.data[k][j])
+                })
+            }),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! 3x3 stencil application with boundary policies.
+//!
+//! A stencil is applied as a cross-correlation: the output at `(r, c)` is
+//! `sum k[i][j] * x[r + i - 1][c + j - 1]`, with out-of-range neighbours
+//! resolved by a [`Boundary`] policy. This is the update step of explicit
+//! finite-difference PDE solvers.
+//!
+//! Dense finite-difference operator matrices and forward-difference
+//! gradients are provided alongside for building those solvers.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedFloatTypes, AllowedNumericTypes, Matrix};
+
+/// How neighbours outside the matrix are resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// Wrap around to the opposite edge.
+    Periodic,
+    /// Repeat the nearest edge element.
+    Clamp,
+    /// Treat outside elements as zero.
+    Zero,
+}
+
+/// Reads `m[row][col]`, resolving out-of-range indices with `boundary`.
+pub(crate) fn boundary_value<T: AllowedNumericTypes, const N: usize>(
+    m: &Matrix<T, N>,
+    row: isize,
+    col: isize,
+    boundary: Boundary,
+) -> T {
+    let rows = m.rows as isize;
+    let cols = N as isize;
+    let inside = (0..rows).contains(&row) && (0..cols).contains(&col);
+    if inside {
+        return m[row as usize][col as usize];
+    }
+    match boundary {
+        Boundary::Periodic => *m
+            .get_wrapped(row, col)
+            .expect("boundary lookups only happen on non-empty matrices"),
+        Boundary::Clamp => m[row.clamp(0, rows - 1) as usize][col.clamp(0, cols - 1) as usize],
+        Boundary::Zero => T::zero(),
+    }
+}
+
+fn check_kernel<T: AllowedNumericTypes>(kernel: &Matrix<T, 3>) -> Result<(), TensorError> {
+    if kernel.rows != 3 {
+        return Err(TensorError::DimensionMismatch {
+            expected: "3x3".to_string(),
+            found: format!("{}x3", kernel.rows),
+            operation: "apply_stencil".to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn stencil_kernel<T: AllowedNumericTypes, const N: usize>(
+    src: &Matrix<T, N>,
+    kernel: &Matrix<T, 3>,
+    boundary: Boundary,
+    out: &mut Matrix<T, N>,
+) {
+    let rows = src.rows;
+    for r in 0..rows {
+        for c in 0..N {
+            let interior = r >= 1 && r + 1 < rows && c >= 1 && c + 1 < N;
+            let mut sum = T::zero();
+            for i in 0..3 {
+                for j in 0..3 {
+                    let x = if interior {
+                        src[r + i - 1][c + j - 1]
+                    } else {
+                        boundary_value(
+                            src,
+                            r as isize + i as isize - 1,
+                            c as isize + j as isize - 1,
+                            boundary,
+                        )
+                    };
+                    sum = sum + kernel[i][j] * x;
+                }
+            }
+            out[r][c] = sum;
+        }
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Applies a 3x3 stencil to every element and returns the result.
+    ///
+    /// `kernel` must have exactly three rows.
+    pub fn apply_stencil(
+        &self,
+        kernel: &Matrix<T, 3>,
+        boundary: Boundary,
+    ) -> Result<Self, TensorError> {
+        let mut out = Matrix::new(self.rows);
+        self.apply_stencil_into(kernel, boundary, &mut out)?;
+        Ok(out)
+    }
+
+    /// Applies a 3x3 stencil, writing into a preallocated `out` of the same
+    /// shape.
+    pub fn apply_stencil_into(
+        &self,
+        kernel: &Matrix<T, 3>,
+        boundary: Boundary,
+        out: &mut Self,
+    ) -> Result<(), TensorError> {
+        check_kernel(kernel)?;
+        if out.rows != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", out.rows, N),
+                operation: "apply_stencil_into".to_string(),
+            });
+        }
+        stencil_kernel(self, kernel, boundary, out);
+        Ok(())
+    }
+
+    /// Applies the stencil `steps` times in place.
+    ///
+    /// Uses a single scratch buffer that is swapped with `self` after every
+    /// step, so repeated application allocates only once.
+    pub fn apply_stencil_steps(
+        &mut self,
+        kernel: &Matrix<T, 3>,
+        boundary: Boundary,
+        steps: usize,
+    ) -> Result<(), TensorError> {
+        check_kernel(kernel)?;
+        if steps == 0 {
+            return Ok(());
+        }
+        let mut scratch = Matrix::new(self.rows);
+        for _ in 0..steps {
+            stencil_kernel(self, kernel, boundary, &mut scratch);
+            std::mem::swap(self, &mut scratch);
+        }
+        Ok(())
+    }
+}
+
+/// Dense 1-D discrete Laplacian of size `N x N`.
+///
+/// Uses the `[1, -2, 1]` stencil with zero (Dirichlet) boundaries and unit
+/// grid spacing; divide by `h^2` for spacing `h`.
+pub fn discrete_laplacian_1d<T: AllowedFloatTypes, const N: usize>() -> Matrix<T, N> {
+    let two = T::one() + T::one();
+    let mut result = Matrix::new(N);
+    for i in 0..N {
+        result[i][i] = T::zero() - two;
+        if i > 0 {
+            result[i][i - 1] = T::one();
+        }
+        if i + 1 < N {
+            result[i][i + 1] = T::one();
+        }
+    }
+    result
+}
+
+/// Dense 2-D five-point discrete Laplacian on an `nx x ny` grid.
+///
+/// Grid point `(x, y)` maps to index `y * nx + x`, matching a row-major field
+/// with `ny` rows and `nx` columns. `N` must equal `nx * ny`. Boundaries are
+/// zero (Dirichlet) and the grid spacing is one.
+pub fn discrete_laplacian_2d<T: AllowedFloatTypes, const N: usize>(
+    nx: usize,
+    ny: usize,
+) -> Result<Matrix<T, N>, TensorError> {
+    if nx * ny != N {
+        return Err(TensorError::DimensionMismatch {
+            expected: N.to_string(),
+            found: format!("{}x{}", nx, ny),
+            operation: "discrete_laplacian_2d".to_string(),
+        });
+    }
+
+    let four = T::from_f64(4.0);
+    let mut result = Matrix::new(N);
+    for y in 0..ny {
+        for x in 0..nx {
+            let k = y * nx + x;
+            result[k][k] = T::zero() - four;
+            if x > 0 {
+                result[k][k - 1] = T::one();
+            }
+            if x + 1 < nx {
+                result[k][k + 1] = T::one();
+            }
+            if y > 0 {
+                result[k][k - nx] = T::one();
+            }
+            if y + 1 < ny {
+                result[k][k + nx] = T::one();
+            }
+        }
+    }
+    Ok(result)
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Forward-difference gradient of a scalar field, returned as `(dx, dy)`.
+    ///
+    /// `dx` differences along columns and `dy` along rows. The last column of
+    /// `dx` and the last row of `dy` are zero.
+    pub fn gradient(&self) -> (Self, Self) {
+        let mut dx = Matrix::new(self.rows);
+        let mut dy = Matrix::new(self.rows);
+        for r in 0..self.rows {
+            for c in 0..N {
+                if c + 1 < N {
+                    dx[r][c] = self[r][c + 1] - self[r][c];
+                }
+                if r + 1 < self.rows {
+                    dy[r][c] = self[r + 1][c] - self[r][c];
+                }
+            }
+        }
+        (dx, dy)
+    }
+}
@@ -0,0 +1,123 @@
+//! Out-of-core matrix multiplication over byte streams.
+//!
+//! Operands and result are `f64` matrices in the format of
+//! [`io::binary`](crate::io::binary): a file written by
+//! [`write_binary`](crate::io::binary::write_binary), or a memory map of one
+//! wrapped in a [`Cursor`](std::io::Cursor), can be streamed directly. Only
+//! one panel of `block_rows` rows of each operand and of the result is held
+//! in memory at a time.
+
+use crate::error::TensorError;
+use crate::io::binary::{binary_header, read_binary_header};
+use crate::io::npy::{shape_string, NpyElement};
+use crate::io::read_exactly;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+fn io_error(e: std::io::Error) -> TensorError {
+    TensorError::Other(format!("matmul_streamed I/O error: {}", e))
+}
+
+// Reads the header of a streamed operand, which must be a matrix with
+// `cols` columns, and returns its row count and byte order
+fn read_operand<R: Read>(reader: &mut R, cols: usize) -> Result<(usize, bool), TensorError> {
+    let (shape, little) = read_binary_header::<f64, _>(reader)?;
+    match shape[..] {
+        [rows, c] if c == cols => Ok((rows, little)),
+        _ => Err(TensorError::DimensionMismatch {
+            expected: format!("?x{}", cols),
+            found: shape_string(&shape),
+            operation: "matmul_streamed".to_string(),
+        }),
+    }
+}
+
+// Reads exactly `rows` rows of `cols` values into `buf`
+fn read_rows<R: Read>(
+    reader: &mut R,
+    rows: usize,
+    cols: usize,
+    little: bool,
+    buf: &mut Vec<f64>,
+) -> Result<(), TensorError> {
+    let bytes = read_exactly(reader, rows * cols * f64::SIZE, "matmul_streamed")?;
+    buf.clear();
+    buf.extend(bytes.chunks_exact(f64::SIZE).map(|b| f64::read(b, little)));
+    Ok(())
+}
+
+/// Computes `C = A * B` for a streamed `rows x K` matrix `A` and `K x M`
+/// matrix `B`, writing `C` (`rows x M`, little-endian) to `writer_c` one
+/// panel at a time.
+///
+/// The headers are checked before any data is read: `A` must have `K`
+/// columns and `B` must be exactly `K x M`, otherwise the result is a
+/// `DimensionMismatch`. `A` is read once; `B` is re-read from the end of its
+/// header for every panel of `block_rows` rows of `A`, so it must be
+/// seekable. Peak memory is about `block_rows * (K + 2 * M)` values. Returns
+/// the number of result rows written.
+pub fn matmul_streamed<A, B, C, const K: usize, const M: usize>(
+    mut reader_a: A,
+    mut reader_b: B,
+    mut writer_c: C,
+    block_rows: usize,
+) -> Result<usize, TensorError>
+where
+    A: Read,
+    B: Read + Seek,
+    C: Write,
+{
+    if block_rows == 0 || K == 0 || M == 0 {
+        return Err(TensorError::InvalidOperation(
+            "matmul_streamed: block_rows, K and M must be nonzero".to_string(),
+        ));
+    }
+
+    let (rows, a_little) = read_operand(&mut reader_a, K)?;
+    let (b_rows, b_little) = read_operand(&mut reader_b, M)?;
+    if b_rows != K {
+        return Err(TensorError::DimensionMismatch {
+            expected: format!("{}x{}", K, M),
+            found: format!("{}x{}", b_rows, M),
+            operation: "matmul_streamed".to_string(),
+        });
+    }
+    let b_start = reader_b.stream_position().map_err(io_error)?;
+    writer_c
+        .write_all(&binary_header::<f64>(&[rows, M], true))
+        .map_err(io_error)?;
+
+    let mut a_panel = Vec::with_capacity(block_rows * K);
+    let mut b_panel = Vec::with_capacity(block_rows * M);
+    let mut c_panel = vec![0.0; block_rows * M];
+    let mut out = Vec::with_capacity(block_rows * M * f64::SIZE);
+
+    for r0 in (0..rows).step_by(block_rows) {
+        let a_rows = block_rows.min(rows - r0);
+        read_rows(&mut reader_a, a_rows, K, a_little, &mut a_panel)?;
+        c_panel[..a_rows * M].fill(0.0);
+
+        reader_b.seek(SeekFrom::Start(b_start)).map_err(io_error)?;
+        for k0 in (0..K).step_by(block_rows) {
+            let k_rows = block_rows.min(K - k0);
+            read_rows(&mut reader_b, k_rows, M, b_little, &mut b_panel)?;
+            for r in 0..a_rows {
+                let c_row = &mut c_panel[r * M..(r + 1) * M];
+                for (k, b_row) in b_panel.chunks_exact(M).enumerate() {
+                    let a = a_panel[r * K + k0 + k];
+                    for (c, &b) in c_row.iter_mut().zip(b_row.iter()) {
+                        *c += a * b;
+                    }
+                }
+            }
+        }
+
+        out.clear();
+        for &c in &c_panel[..a_rows * M] {
+            c.write(&mut out, true);
+        }
+        writer_c.write_all(&out).map_err(io_error)?;
+    }
+
+    writer_c.flush().map_err(io_error)?;
+    Ok(rows)
+}
@@ -7,15 +7,21 @@
 //!
 //! Indexing with `[]` can panic if out of bounds. Prefer `get`/`get_mut` for checked access.
 
+use crate::layout::{MemoryLayout, RowMajor};
+use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Sub};
 
 /// Numeric bounds required by this crate.
 ///
 /// Implemented for common integer and float types. Provides simple
-/// constructors and predicates used to keep generic code concise.
+/// constructors and predicates used to keep generic code concise. Elements
+/// are `Send + Sync` so that large products and reductions can run on
+/// several threads with the `parallel` feature.
 pub trait AllowedNumericTypes:
     Sized
     + Copy
+    + Send
+    + Sync
     + Default
     + Add<Output = Self>
     + Sub<Output = Self>
@@ -33,30 +39,122 @@ pub trait AllowedNumericTypes:
     /// Note: for floating-point types this uses exact comparison.
     /// If you need epsilon-based comparisons, add that at call sites.
     fn is_zero(&self) -> bool;
+    /// Converts an `f64` into this type.
+    ///
+    /// Floats round to the nearest representable value; integers truncate
+    /// toward zero and saturate at the type's bounds (NaN becomes zero).
+    fn from_f64(value: f64) -> Self;
+    /// Converts this value into an `f64`, rounding if necessary.
+    fn to_f64(self) -> f64;
+
+    /// Sum of the products of corresponding elements of two slices of equal
+    /// length. The default is a scalar loop; types may override it with a
+    /// vectorized kernel (`f32` and `f64` do with the `simd` feature).
+    fn dot_slices(a: &[Self], b: &[Self]) -> Self {
+        a.iter()
+            .zip(b)
+            .fold(Self::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+    /// `acc[i] = acc[i] + alpha * x[i]` over two slices of equal length,
+    /// with the same override rules as [`AllowedNumericTypes::dot_slices`].
+    fn axpy_slices(acc: &mut [Self], alpha: Self, x: &[Self]) {
+        for (a, &x) in acc.iter_mut().zip(x) {
+            *a = *a + alpha * x;
+        }
+    }
+    /// `acc[i] = acc[i] + alpha * x[i]` with Kahan summation, carrying the
+    /// compensation of each element in `comp[i]`; matrix products accumulate
+    /// through this. `f32` and `f64` override it with the `simd` feature, and
+    /// with `fast-math` replace it by [`AllowedNumericTypes::axpy_slices`].
+    fn kahan_axpy_slices(acc: &mut [Self], comp: &mut [Self], alpha: Self, x: &[Self]) {
+        for ((sum, c), &x) in acc.iter_mut().zip(comp.iter_mut()).zip(x) {
+            let y = alpha * x - *c;
+            let t = *sum + y;
+            *c = (t - *sum) - y;
+            *sum = t;
+        }
+    }
+}
+
+/// Additional bounds for floating-point element types.
+///
+/// Required by routines that need square roots, tolerances, or ordering,
+/// such as the factorizations in `linalg`.
+pub trait AllowedFloatTypes: AllowedNumericTypes + PartialOrd {
+    /// Machine epsilon for the type.
+    fn epsilon() -> Self;
+    /// Absolute value.
+    fn abs(self) -> Self;
+    /// Square root.
+    fn sqrt(self) -> Self;
+    /// Natural exponential `e^self`.
+    fn exp(self) -> Self;
+    /// Hyperbolic tangent.
+    fn tanh(self) -> Self;
+    /// Returns true if `|self| <= tol`. Use this instead of
+    /// [`AllowedNumericTypes::is_zero`] when rounding error is expected.
+    fn is_zero_with_tol(&self, tol: Self) -> bool {
+        self.abs() <= tol
+    }
+}
+
+/// Element conversion used by `cast` on vectors, matrices and tensors.
+///
+/// Implemented between all primitive integer and float types. Integers
+/// convert exactly when the value fits and saturate at the target's bounds
+/// otherwise; conversions to a float round to the nearest representable
+/// value; floats convert to integers as with
+/// [`AllowedNumericTypes::from_f64`]. Unlike a round trip through `f64`, this
+/// keeps large `i64`, `u64`, `i128` and `u128` values intact.
+pub trait CastFrom<T>: Sized {
+    fn cast_from(value: T) -> Self;
 }
 
 // Implementations for primitive numeric types are provided in `tensor_impl.rs`.
 
 /// A fixed-size 1-D vector of length `N` backed by `[T; N]`.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Vectors compare lexicographically when the element type is ordered.
+/// The layout is exactly that of `[T; N]`, and like an array it is `Copy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
 pub struct Vector<T: AllowedNumericTypes, const N: usize> {
     pub(crate) data: [T; N],
 }
 
-/// A 2-D matrix with `rows` rows and `N` columns, stored as row-major
-/// `Vec<Vector<T, N>>`.
-#[derive(Clone, Debug, PartialEq)]
-pub struct Matrix<T: AllowedNumericTypes, const N: usize> {
-    pub(crate) data: Vec<Vector<T, N>>,
+/// A 2-D matrix with `rows` rows and `N` columns, stored as one contiguous
+/// `Vec<T>` of `rows * N` elements in the memory order `L`.
+///
+/// With the default [`RowMajor`] layout element `(i, j)` is at `i * N + j`
+/// and rows are handed out as `&Vector<T, N>` borrowed from the buffer; the
+/// rest of the crate works on row-major matrices. A
+/// [`ColMajor`](crate::layout::ColMajor) matrix
+/// stores element `(i, j)` at `j * rows + i`; see [`crate::layout`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix<T: AllowedNumericTypes, const N: usize, L: MemoryLayout = RowMajor> {
+    pub(crate) data: Vec<T>,
     pub(crate) rows: usize,
+    pub(crate) layout: PhantomData<L>,
 }
 
-/// A simple 3-D tensor with shape `(depths, rows, N)` and row-major
-/// storage via `Vec<Matrix<T, N>>`.
-#[derive(Clone, Debug, PartialEq)]
+/// A simple 3-D tensor with shape `(depths, rows, N)`, stored as one
+/// contiguous `Vec<T>` in depth, row, column order.
+///
+/// Element `(d, r, c)` is at `d * rows * N + r * N + c`; see
+/// [`Tensor::strides`]. Depth slices are handed out as views or row slices
+/// borrowed from the buffer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Tensor<T: AllowedNumericTypes, const N: usize> {
-    pub(crate) data: Vec<Matrix<T, N>>,
+    pub(crate) data: Vec<T>,
     pub(crate) depths: usize,
     pub(crate) rows: usize,
 }
 // Implementations for methods and operator traits are provided in `tensor_impl.rs`.
+
+/// A variable-length axis of a [`Tensor`]. The column axis has the
+/// compile-time length `N` and cannot change size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Depth,
+    Row,
+}
@@ -1,6 +1,10 @@
 use crate::error::TensorError;
-use crate::tensor::{AllowedNumericTypes, Matrix, Tensor, Vector};
-use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use crate::tensor::{
+    AllowedFloatTypes, AllowedNumericTypes, Axis, CastFrom, Matrix, Tensor, Vector,
+};
+use crate::view::MatrixView;
+use std::marker::PhantomData;
+use std::ops::{Add, Bound, Div, Index, IndexMut, Mul, RangeBounds, Sub};
 
 // AllowedNumericTypes implementations for common primitives
 impl AllowedNumericTypes for f32 {
@@ -13,6 +17,37 @@ impl AllowedNumericTypes for f32 {
     fn is_zero(&self) -> bool {
         *self == 0.0
     }
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    #[cfg(feature = "simd")]
+    fn dot_slices(a: &[Self], b: &[Self]) -> Self {
+        crate::simd::dot_f32(a, b)
+    }
+    #[cfg(feature = "simd")]
+    fn axpy_slices(acc: &mut [Self], alpha: Self, x: &[Self]) {
+        crate::simd::axpy_f32(acc, alpha, x)
+    }
+    #[cfg(all(feature = "simd", not(feature = "fast-math")))]
+    fn kahan_axpy_slices(acc: &mut [Self], comp: &mut [Self], alpha: Self, x: &[Self]) {
+        crate::simd::kahan_axpy_f32(acc, comp, alpha, x)
+    }
+    // `fast-math` trades the compensation for the plain fused kernel
+    #[cfg(feature = "fast-math")]
+    fn kahan_axpy_slices(acc: &mut [Self], _comp: &mut [Self], alpha: Self, x: &[Self]) {
+        Self::axpy_slices(acc, alpha, x)
+    }
+    #[cfg(all(feature = "fast-math", not(feature = "simd")))]
+    fn dot_slices(a: &[Self], b: &[Self]) -> Self {
+        crate::fast_math::dot_f32(a, b)
+    }
+    #[cfg(all(feature = "fast-math", not(feature = "simd")))]
+    fn axpy_slices(acc: &mut [Self], alpha: Self, x: &[Self]) {
+        crate::fast_math::axpy_f32(acc, alpha, x)
+    }
 }
 
 impl AllowedNumericTypes for f64 {
@@ -25,6 +60,37 @@ impl AllowedNumericTypes for f64 {
     fn is_zero(&self) -> bool {
         *self == 0.0
     }
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+    #[cfg(feature = "simd")]
+    fn dot_slices(a: &[Self], b: &[Self]) -> Self {
+        crate::simd::dot_f64(a, b)
+    }
+    #[cfg(feature = "simd")]
+    fn axpy_slices(acc: &mut [Self], alpha: Self, x: &[Self]) {
+        crate::simd::axpy_f64(acc, alpha, x)
+    }
+    #[cfg(all(feature = "simd", not(feature = "fast-math")))]
+    fn kahan_axpy_slices(acc: &mut [Self], comp: &mut [Self], alpha: Self, x: &[Self]) {
+        crate::simd::kahan_axpy_f64(acc, comp, alpha, x)
+    }
+    // `fast-math` trades the compensation for the plain fused kernel
+    #[cfg(feature = "fast-math")]
+    fn kahan_axpy_slices(acc: &mut [Self], _comp: &mut [Self], alpha: Self, x: &[Self]) {
+        Self::axpy_slices(acc, alpha, x)
+    }
+    #[cfg(all(feature = "fast-math", not(feature = "simd")))]
+    fn dot_slices(a: &[Self], b: &[Self]) -> Self {
+        crate::fast_math::dot_f64(a, b)
+    }
+    #[cfg(all(feature = "fast-math", not(feature = "simd")))]
+    fn axpy_slices(acc: &mut [Self], alpha: Self, x: &[Self]) {
+        crate::fast_math::axpy_f64(acc, alpha, x)
+    }
 }
 
 impl AllowedNumericTypes for i32 {
@@ -37,6 +103,12 @@ impl AllowedNumericTypes for i32 {
     fn is_zero(&self) -> bool {
         *self == 0
     }
+    fn from_f64(value: f64) -> Self {
+        value as i32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
 }
 
 impl AllowedNumericTypes for i64 {
@@ -49,6 +121,12 @@ impl AllowedNumericTypes for i64 {
     fn is_zero(&self) -> bool {
         *self == 0
     }
+    fn from_f64(value: f64) -> Self {
+        value as i64
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
 }
 
 impl AllowedNumericTypes for u32 {
@@ -61,6 +139,12 @@ impl AllowedNumericTypes for u32 {
     fn is_zero(&self) -> bool {
         *self == 0
     }
+    fn from_f64(value: f64) -> Self {
+        value as u32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
 }
 
 impl AllowedNumericTypes for u64 {
@@ -73,8 +157,228 @@ impl AllowedNumericTypes for u64 {
     fn is_zero(&self) -> bool {
         *self == 0
     }
+    fn from_f64(value: f64) -> Self {
+        value as u64
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for i8 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as i8
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for i16 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as i16
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for isize {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as isize
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for u8 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as u8
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for u16 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as u16
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for usize {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as usize
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for i128 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as i128
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl AllowedNumericTypes for u128 {
+    fn zero() -> Self {
+        0
+    }
+    fn one() -> Self {
+        1
+    }
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+    fn from_f64(value: f64) -> Self {
+        value as u128
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
 }
 
+// AllowedFloatTypes implementations for the primitive floats
+impl AllowedFloatTypes for f32 {
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+    fn tanh(self) -> Self {
+        f32::tanh(self)
+    }
+}
+
+impl AllowedFloatTypes for f64 {
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+    fn tanh(self) -> Self {
+        f64::tanh(self)
+    }
+}
+
+// CastFrom between the primitive types: integers through `TryFrom` with
+// saturation, anything involving a float through `as`
+macro_rules! cast_from_int {
+    ($from:ty => $($to:ty),*) => {$(
+        impl CastFrom<$from> for $to {
+            fn cast_from(value: $from) -> Self {
+                <$to>::try_from(value).unwrap_or(if value > 0 { <$to>::MAX } else { <$to>::MIN })
+            }
+        }
+    )*};
+}
+
+macro_rules! cast_from_as {
+    ($from:ty => $($to:ty),*) => {$(
+        impl CastFrom<$from> for $to {
+            fn cast_from(value: $from) -> Self {
+                value as $to
+            }
+        }
+    )*};
+}
+
+macro_rules! cast_from_primitive {
+    ($($int:ty),*) => {$(
+        cast_from_int!($int => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+        cast_from_as!($int => f32, f64);
+    )*};
+}
+
+cast_from_primitive!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+cast_from_as!(f32 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+cast_from_as!(f64 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
 // Vector inherent impls and trait impls
 impl<T: AllowedNumericTypes, const N: usize> Default for Vector<T, N> {
     fn default() -> Self {
@@ -110,6 +414,14 @@ impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
         N == 0
     }
 
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
     pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.data.iter()
     }
@@ -117,6 +429,41 @@ impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
         self.data.iter_mut()
     }
+
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, mut f: F) -> Vector<U, N> {
+        Vector {
+            data: std::array::from_fn(|i| f(self.data[i])),
+        }
+    }
+
+    pub fn zip_map<U, V, F>(&self, other: &Vector<U, N>, mut f: F) -> Vector<V, N>
+    where
+        U: AllowedNumericTypes,
+        V: AllowedNumericTypes,
+        F: FnMut(T, U) -> V,
+    {
+        Vector {
+            data: std::array::from_fn(|i| f(self.data[i], other.data[i])),
+        }
+    }
+
+    /// Converts every element with [`CastFrom`]; defined between the
+    /// primitive numeric types.
+    pub fn cast<U: AllowedNumericTypes + CastFrom<T>>(&self) -> Vector<U, N> {
+        self.map(U::cast_from)
+    }
+
+    /// Returns the element at `index` taken modulo `N`, so `-1` is the last
+    /// element. Errors only if the vector is empty.
+    pub fn get_wrapped(&self, index: isize) -> Result<&T, TensorError> {
+        if N == 0 {
+            return Err(TensorError::OutOfBounds {
+                index: index.to_string(),
+                size: N.to_string(),
+            });
+        }
+        Ok(&self.data[index.rem_euclid(N as isize) as usize])
+    }
 }
 
 impl<T: AllowedNumericTypes, const N: usize> From<[T; N]> for Vector<T, N> {
@@ -187,6 +534,11 @@ impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
         Vector { data: result }
     }
 
+    pub fn scalar_sub(&self, scalar: T) -> Self {
+        let result = std::array::from_fn(|i| self.data[i] - scalar);
+        Vector { data: result }
+    }
+
     pub fn scalar_mul(&self, scalar: T) -> Self {
         let result = std::array::from_fn(|i| self.data[i] * scalar);
         Vector { data: result }
@@ -202,34 +554,147 @@ impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
     }
 
     pub fn dot(&self, other: &Self) -> T {
-        let mut sum = T::zero();
-        for i in 0..N {
-            sum = sum + (self.data[i] * other.data[i]);
-        }
-        sum
+        T::dot_slices(&self.data, &other.data)
     }
 }
 
+// Row `Vector`s over a row-major buffer of `rows * N` elements
+pub(crate) fn rows_of<T: AllowedNumericTypes, const N: usize>(
+    flat: &[T],
+    rows: usize,
+) -> &[Vector<T, N>] {
+    assert_eq!(flat.len(), rows * N, "buffer does not hold {} rows", rows);
+    // SAFETY: `Vector<T, N>` is `repr(transparent)` over `[T; N]`, so `rows`
+    // consecutive rows are exactly the `rows * N` elements of `flat`. With
+    // `N == 0` the rows are zero-sized and the aligned pointer suffices
+    unsafe { std::slice::from_raw_parts(flat.as_ptr().cast(), rows) }
+}
+
+pub(crate) fn rows_of_mut<T: AllowedNumericTypes, const N: usize>(
+    flat: &mut [T],
+    rows: usize,
+) -> &mut [Vector<T, N>] {
+    assert_eq!(flat.len(), rows * N, "buffer does not hold {} rows", rows);
+    // SAFETY: as in `rows_of`, and the rows borrow `flat` mutably
+    unsafe { std::slice::from_raw_parts_mut(flat.as_mut_ptr().cast(), rows) }
+}
+
 // Matrix impls and trait impls
 impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
     pub fn new(rows: usize) -> Self {
-        let mut data = Vec::with_capacity(rows);
-        for _ in 0..rows {
-            data.push(Vector::new());
+        Matrix {
+            data: vec![T::default(); rows * N],
+            rows,
+            layout: PhantomData,
         }
-        Matrix { data, rows }
     }
 
     pub fn from_vectors(vectors: Vec<Vector<T, N>>) -> Self {
-        let rows = vectors.len();
         Matrix {
-            data: vectors,
+            data: vectors
+                .iter()
+                .flat_map(|v| v.data.iter().copied())
+                .collect(),
+            rows: vectors.len(),
+            layout: PhantomData,
+        }
+    }
+
+    /// Builds a `rows x N` matrix from a row-major buffer, which must hold
+    /// exactly `rows * N` elements. The buffer is used as is, without copying.
+    pub fn from_vec(rows: usize, data: Vec<T>) -> Result<Self, TensorError> {
+        if data.len() != rows * N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements", rows * N),
+                found: format!("{} elements", data.len()),
+                operation: "Matrix from_vec".to_string(),
+            });
+        }
+        Ok(Matrix {
+            data,
+            rows,
+            layout: PhantomData,
+        })
+    }
+
+    /// The row-major element buffer.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Builds a `rows x N` matrix with element `(i, j)` set to `f(i, j)`,
+    /// called in row-major order.
+    ///
+    /// Each element is written once into a buffer of exactly the right
+    /// size, instead of zero-filling the matrix first as [`Matrix::new`]
+    /// does.
+    pub fn from_fn(rows: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Matrix {
+            data: (0..rows * N).map(|k| f(k / N, k % N)).collect(),
             rows,
+            layout: PhantomData,
+        }
+    }
+
+    /// All elements as one row-major slice, e.g. to hand the buffer to FFI
+    /// or serialization code without copying.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// The rows as vectors borrowed from the buffer.
+    pub fn as_rows(&self) -> &[Vector<T, N>] {
+        rows_of(&self.data, self.rows)
+    }
+
+    pub fn as_rows_mut(&mut self) -> &mut [Vector<T, N>] {
+        rows_of_mut(&mut self.data, self.rows)
+    }
+
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> Matrix<U, N> {
+        Matrix {
+            data: self.data.iter().copied().map(f).collect(),
+            rows: self.rows,
+            layout: PhantomData,
+        }
+    }
+
+    pub fn zip_map<U, V, F>(
+        &self,
+        other: &Matrix<U, N>,
+        mut f: F,
+    ) -> Result<Matrix<V, N>, TensorError>
+    where
+        U: AllowedNumericTypes,
+        V: AllowedNumericTypes,
+        F: FnMut(T, U) -> V,
+    {
+        if self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", other.rows, N),
+                operation: "Matrix zip_map".to_string(),
+            });
         }
+
+        Ok(Matrix {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+            rows: self.rows,
+            layout: PhantomData,
+        })
     }
 
-    pub fn shape(&self) -> (usize, usize) {
-        (self.rows, N)
+    pub fn cast<U: AllowedNumericTypes + CastFrom<T>>(&self) -> Matrix<U, N> {
+        self.map(U::cast_from)
     }
 
     pub fn get(&self, row: usize) -> Result<&Vector<T, N>, TensorError> {
@@ -240,7 +705,7 @@ impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
             });
         }
 
-        Ok(&self.data[row])
+        Ok(&self.as_rows()[row])
     }
 
     pub fn get_mut(&mut self, row: usize) -> Result<&mut Vector<T, N>, TensorError> {
@@ -251,7 +716,36 @@ impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
             });
         }
 
-        Ok(&mut self.data[row])
+        Ok(&mut self.as_rows_mut()[row])
+    }
+
+    /// Swaps rows `a` and `b` in place.
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), TensorError> {
+        for row in [a, b] {
+            if row >= self.rows {
+                return Err(TensorError::OutOfBounds {
+                    index: row.to_string(),
+                    size: self.rows.to_string(),
+                });
+            }
+        }
+        self.as_rows_mut().swap(a, b);
+        Ok(())
+    }
+
+    /// Returns the element at `(row, col)` with both indices taken modulo the
+    /// shape, for periodic boundary conditions. Errors only if the matrix is
+    /// empty.
+    pub fn get_wrapped(&self, row: isize, col: isize) -> Result<&T, TensorError> {
+        if self.rows == 0 || N == 0 {
+            return Err(TensorError::OutOfBounds {
+                index: format!("({}, {})", row, col),
+                size: format!("{}x{}", self.rows, N),
+            });
+        }
+        let r = row.rem_euclid(self.rows as isize) as usize;
+        let c = col.rem_euclid(N as isize) as usize;
+        Ok(&self.data[r * N + c])
     }
 }
 
@@ -259,118 +753,1196 @@ impl<T: AllowedNumericTypes, const N: usize> Index<usize> for Matrix<T, N> {
     type Output = Vector<T, N>;
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+        &self.as_rows()[index]
     }
 }
 
 impl<T: AllowedNumericTypes, const N: usize> IndexMut<usize> for Matrix<T, N> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+        &mut self.as_rows_mut()[index]
     }
 }
 
-impl<T: AllowedNumericTypes, const N: usize> Add for Matrix<T, N> {
-    type Output = Result<Self, TensorError>;
+// Applies `f` to corresponding elements of two matrices with the same shape
+fn matrix_zip<T: AllowedNumericTypes, const N: usize>(
+    lhs: &Matrix<T, N>,
+    rhs: &Matrix<T, N>,
+    operation: &str,
+    f: impl Fn(T, T) -> T,
+) -> Result<Matrix<T, N>, TensorError> {
+    lhs.check_same_rows(rhs, operation)?;
+    let data = lhs
+        .data
+        .iter()
+        .zip(rhs.data.iter())
+        .map(|(&a, &b)| f(a, b))
+        .collect();
+    Ok(Matrix {
+        data,
+        rows: lhs.rows,
+        layout: PhantomData,
+    })
+}
 
-    fn add(self, rhs: Self) -> Self::Output {
-        if self.rows != rhs.rows {
-            return Err(TensorError::DimensionMismatch {
-                expected: format!("{}x{}", self.rows, N),
-                found: format!("{}x{}", rhs.rows, N),
-                operation: "Matrix addition".to_string(),
-            });
-        }
+// Element-wise operator for every combination of owned and borrowed operands
+macro_rules! matrix_elementwise_op {
+    ($trait:ident, $method:ident, $op:tt, $name:literal) => {
+        impl<T: AllowedNumericTypes, const N: usize> $trait for Matrix<T, N> {
+            type Output = Result<Self, TensorError>;
 
-        let mut result_data = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            result_data.push(self.data[i].clone() + rhs.data[i].clone());
+            fn $method(self, rhs: Self) -> Self::Output {
+                matrix_zip(&self, &rhs, $name, |a, b| a $op b)
+            }
         }
 
-        Ok(Matrix {
-            data: result_data,
-            rows: self.rows,
-        })
-    }
-}
+        impl<'b, T: AllowedNumericTypes, const N: usize> $trait<&'b Matrix<T, N>> for &Matrix<T, N> {
+            type Output = Result<Matrix<T, N>, TensorError>;
 
-impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
-    pub fn scalar_mul(&self, scalar: T) -> Self {
-        let mut result_data = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            result_data.push(self.data[i].scalar_mul(scalar));
+            fn $method(self, rhs: &'b Matrix<T, N>) -> Self::Output {
+                matrix_zip(self, rhs, $name, |a, b| a $op b)
+            }
         }
-        Matrix {
-            data: result_data,
-            rows: self.rows,
+
+        impl<T: AllowedNumericTypes, const N: usize> $trait<Matrix<T, N>> for &Matrix<T, N> {
+            type Output = Result<Matrix<T, N>, TensorError>;
+
+            fn $method(self, rhs: Matrix<T, N>) -> Self::Output {
+                matrix_zip(self, &rhs, $name, |a, b| a $op b)
+            }
         }
-    }
 
-    pub fn mat_vec_mul(&self, vec: &Vector<T, N>) -> Result<Vec<T>, TensorError> {
-        let mut result = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            result.push(self.data[i].dot(vec));
+        impl<'b, T: AllowedNumericTypes, const N: usize> $trait<&'b Matrix<T, N>> for Matrix<T, N> {
+            type Output = Result<Matrix<T, N>, TensorError>;
+
+            fn $method(self, rhs: &'b Matrix<T, N>) -> Self::Output {
+                matrix_zip(&self, rhs, $name, |a, b| a $op b)
+            }
         }
-        Ok(result)
-    }
+    };
+}
+
+matrix_elementwise_op!(Add, add, +, "Matrix addition");
+matrix_elementwise_op!(Sub, sub, -, "Matrix subtraction");
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    pub fn scalar_add(&self, scalar: T) -> Self {
+        self.map(|x| x + scalar)
+    }
+
+    pub fn scalar_sub(&self, scalar: T) -> Self {
+        self.map(|x| x - scalar)
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Self {
+        self.map(|x| x * scalar)
+    }
+
+    pub fn scalar_div(&self, scalar: T) -> Result<Self, TensorError> {
+        if scalar.is_zero() {
+            return Err(TensorError::DivisionByZero);
+        }
+
+        Ok(self.map(|x| x / scalar))
+    }
+
+    pub fn hadamard_product(&self, other: &Self) -> Result<Self, TensorError> {
+        if self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", other.rows, N),
+                operation: "Matrix hadamard product".to_string(),
+            });
+        }
+
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    pub fn mat_vec_mul(&self, vec: &Vector<T, N>) -> Result<Vec<T>, TensorError> {
+        Ok(self.as_rows().iter().map(|row| row.dot(vec)).collect())
+    }
+
+    /// [`Matrix::mat_vec_mul`] written into `out`, which must hold exactly
+    /// one element per row.
+    pub fn mat_vec_mul_into(&self, vec: &Vector<T, N>, out: &mut [T]) -> Result<(), TensorError> {
+        if out.len() != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: self.rows.to_string(),
+                found: out.len().to_string(),
+                operation: "Matrix mat_vec_mul_into".to_string(),
+            });
+        }
+        for (x, row) in out.iter_mut().zip(self.as_rows()) {
+            *x = row.dot(vec);
+        }
+        Ok(())
+    }
+
+    pub fn transpose<const M: usize>(&self) -> Result<Matrix<T, M>, TensorError> {
+        if M != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", N, self.rows),
+                found: format!("{}x{}", N, M),
+                operation: "Matrix transpose".to_string(),
+            });
+        }
+
+        Ok(Matrix::from_fn(N, |i, j| self.data[j * N + i]))
+    }
+
+    /// Stacks copies of `matrices` along a new depth axis.
+    pub fn stack(matrices: &[Matrix<T, N>]) -> Result<Tensor<T, N>, TensorError> {
+        Tensor::from_matrices(matrices.to_vec())
+    }
+
+    /// Regroups the elements into rows of `M`, keeping row-major element
+    /// order; `rows * N` must be a multiple of `M`.
+    pub fn reshape<const M: usize>(&self) -> Result<Matrix<T, M>, TensorError> {
+        let total = self.rows * N;
+        if M == 0 || !total.is_multiple_of(M) {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{} columns", M),
+                operation: "Matrix reshape".to_string(),
+            });
+        }
+        let flat = self.as_slice();
+        Ok(Matrix::from_fn(total / M, |i, j| flat[i * M + j]))
+    }
+
+    /// All elements in row-major order.
+    pub fn flatten(&self) -> Vec<T> {
+        self.as_slice().to_vec()
+    }
+
+    /// All elements in row-major order as a [`Vector`]; `M` must equal
+    /// `rows * N`.
+    pub fn flatten_to_vector<const M: usize>(&self) -> Result<Vector<T, M>, TensorError> {
+        if self.rows * N != M {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements", self.rows * N),
+                found: format!("{} elements", M),
+                operation: "Matrix flatten_to_vector".to_string(),
+            });
+        }
+        Vector::from_slice(self.as_slice())
+    }
+
+    /// Reshapes into a `depths x rows x M` tensor in row-major element order.
+    pub fn to_tensor<const M: usize>(
+        &self,
+        depths: usize,
+        rows: usize,
+    ) -> Result<Tensor<T, M>, TensorError> {
+        if depths * rows * M != self.rows * N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}x{}", depths, rows, M),
+                operation: "Matrix to_tensor".to_string(),
+            });
+        }
+        Ok(Tensor {
+            data: self.data.clone(),
+            depths,
+            rows,
+        })
+    }
+}
+
+// Resolves `range` against an axis of length `len` into `start..end`
+pub(crate) fn resolve_range<R: RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> Result<(usize, usize), TensorError> {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        return Err(TensorError::OutOfBounds {
+            index: format!("{}..{}", start, end),
+            size: len.to_string(),
+        });
+    }
+    Ok((start, end))
 }
 
 // Tensor impls and trait impls
 impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
     pub fn new(depths: usize, rows: usize) -> Self {
-        let mut data = Vec::with_capacity(depths);
-        for _ in 0..depths {
-            data.push(Matrix::new(rows));
+        Self::full(depths, rows, T::default())
+    }
+
+    pub fn zeros(depths: usize, rows: usize) -> Self {
+        Self::full(depths, rows, T::zero())
+    }
+
+    pub fn ones(depths: usize, rows: usize) -> Self {
+        Self::full(depths, rows, T::one())
+    }
+
+    /// A `depths x rows x N` tensor with every element set to `value`.
+    pub fn full(depths: usize, rows: usize, value: T) -> Self {
+        Tensor {
+            data: vec![value; depths * rows * N],
+            depths,
+            rows,
+        }
+    }
+
+    /// Builds a `depths x rows x N` tensor from a buffer in depth, row,
+    /// column order, which must hold exactly `depths * rows * N` elements.
+    pub fn from_vec(depths: usize, rows: usize, data: Vec<T>) -> Result<Self, TensorError> {
+        if data.len() != depths * rows * N {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements", depths * rows * N),
+                found: format!("{} elements", data.len()),
+                operation: "Tensor from_vec".to_string(),
+            });
+        }
+        Ok(Tensor { data, depths, rows })
+    }
+
+    /// Returns the buffer in depth, row, column order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Builds a tensor with one depth per matrix. All matrices must have the
+    /// same number of rows; an empty `Vec` gives an empty `0x0xN` tensor.
+    pub fn from_matrices(matrices: Vec<Matrix<T, N>>) -> Result<Self, TensorError> {
+        let rows = matrices.first().map_or(0, |m| m.rows);
+        if let Some(m) = matrices.iter().find(|m| m.rows != rows) {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", rows, N),
+                found: format!("{}x{}", m.rows, N),
+                operation: "Tensor from_matrices".to_string(),
+            });
+        }
+        let mut data = Vec::with_capacity(matrices.len() * rows * N);
+        for m in &matrices {
+            data.extend_from_slice(m.as_slice());
+        }
+        Ok(Tensor {
+            data,
+            depths: matrices.len(),
+            rows,
+        })
+    }
+
+    /// Splits the tensor into its depth matrices.
+    pub fn into_matrices(self) -> Vec<Matrix<T, N>> {
+        self.depth_slices()
+            .map(|d| Matrix {
+                data: d.to_vec(),
+                rows: self.rows,
+                layout: PhantomData,
+            })
+            .collect()
+    }
+
+    /// Element strides of the depth and row axes; element
+    /// `(depth, row, col)` is at `depth * strides.0 + row * strides.1 + col`.
+    pub fn strides(&self) -> (usize, usize) {
+        (self.rows * N, N)
+    }
+
+    /// All elements as one slice in depth, row, column order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    // The flat buffer of each depth in turn
+    pub(crate) fn depth_slices(&self) -> impl Iterator<Item = &[T]> + '_ {
+        let stride = self.strides().0;
+        (0..self.depths).map(move |d| &self.data[d * stride..(d + 1) * stride])
+    }
+
+    // The elements of depth `depth`, which must be in bounds
+    pub(crate) fn depth_slice(&self, depth: usize) -> &[T] {
+        let stride = self.strides().0;
+        &self.data[depth * stride..(depth + 1) * stride]
+    }
+
+    pub(crate) fn depth_slice_mut(&mut self, depth: usize) -> &mut [T] {
+        let stride = self.strides().0;
+        &mut self.data[depth * stride..(depth + 1) * stride]
+    }
+
+    /// Regroups the rows into `depths` matrices of `rows` rows each, keeping
+    /// row-major element order. `depths * rows` must equal the current
+    /// `depths * rows`.
+    pub fn reshape(&self, depths: usize, rows: usize) -> Result<Self, TensorError> {
+        if depths * rows != self.depths * self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}x{}", self.depths, self.rows, N),
+                found: format!("{}x{}x{}", depths, rows, N),
+                operation: "Tensor reshape".to_string(),
+            });
+        }
+        Ok(Tensor {
+            data: self.data.clone(),
+            depths,
+            rows,
+        })
+    }
+
+    /// All elements in depth, row, column order.
+    pub fn flatten(&self) -> Vec<T> {
+        self.data.clone()
+    }
+
+    /// Flattens into a matrix with `M` columns in row-major element order.
+    ///
+    /// With `M == N` the depths are stacked vertically; with
+    /// `M == rows * N` every depth becomes one row, e.g. a design matrix with
+    /// one sample per depth.
+    pub fn to_matrix<const M: usize>(&self) -> Result<Matrix<T, M>, TensorError> {
+        let total = self.depths * self.rows * N;
+        if M == 0 || !total.is_multiple_of(M) {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{} elements", total),
+                found: format!("{} columns", M),
+                operation: "Tensor to_matrix".to_string(),
+            });
         }
-        Tensor { data, depths, rows }
+        Ok(Matrix {
+            data: self.data.clone(),
+            rows: total / M,
+            layout: PhantomData,
+        })
+    }
+
+    /// Copies the depths in `range` into a new tensor.
+    pub fn slice_depths<R: RangeBounds<usize>>(&self, range: R) -> Result<Self, TensorError> {
+        let (start, end) = resolve_range(range, self.depths)?;
+        let stride = self.strides().0;
+        Ok(Tensor {
+            data: self.data[start * stride..end * stride].to_vec(),
+            depths: end - start,
+            rows: self.rows,
+        })
+    }
+
+    /// Copies the rows in `range` of every depth into a new tensor.
+    pub fn slice_rows<R: RangeBounds<usize>>(&self, range: R) -> Result<Self, TensorError> {
+        let (start, end) = resolve_range(range, self.rows)?;
+        let data = self
+            .depth_slices()
+            .flat_map(|d| d[start * N..end * N].iter().copied())
+            .collect();
+        Ok(Tensor {
+            data,
+            depths: self.depths,
+            rows: end - start,
+        })
+    }
+
+    /// Concatenates `other` after `self` along `axis`.
+    ///
+    /// Along [`Axis::Depth`] the row counts must match; along [`Axis::Row`]
+    /// the depth counts must.
+    pub fn concat(&self, other: &Self, axis: Axis) -> Result<Self, TensorError> {
+        let compatible = match axis {
+            Axis::Depth => self.rows == other.rows,
+            Axis::Row => self.depths == other.depths,
+        };
+        if !compatible {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}x{}", self.depths, self.rows, N),
+                found: format!("{}x{}x{}", other.depths, other.rows, N),
+                operation: format!("Tensor concat along {:?}", axis),
+            });
+        }
+
+        match axis {
+            Axis::Depth => {
+                let mut data = self.data.clone();
+                data.extend_from_slice(&other.data);
+                Ok(Tensor {
+                    data,
+                    depths: self.depths + other.depths,
+                    rows: self.rows,
+                })
+            }
+            Axis::Row => {
+                let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+                for (a, b) in self.depth_slices().zip(other.depth_slices()) {
+                    data.extend_from_slice(a);
+                    data.extend_from_slice(b);
+                }
+                Ok(Tensor {
+                    data,
+                    depths: self.depths,
+                    rows: self.rows + other.rows,
+                })
+            }
+        }
+    }
+
+    /// Swaps depths `a` and `b` in place.
+    pub fn swap_depths(&mut self, a: usize, b: usize) -> Result<(), TensorError> {
+        for depth in [a, b] {
+            if depth >= self.depths {
+                return Err(TensorError::OutOfBounds {
+                    index: depth.to_string(),
+                    size: self.depths.to_string(),
+                });
+            }
+        }
+        if a != b {
+            let stride = self.strides().0;
+            let (lo, hi) = (a.min(b), a.max(b));
+            let (head, tail) = self.data.split_at_mut(hi * stride);
+            head[lo * stride..(lo + 1) * stride].swap_with_slice(&mut tail[..stride]);
+        }
+        Ok(())
+    }
+
+    /// Reorders depths in place so that new depth `i` is old depth
+    /// `order[i]`. `order` must be a permutation of `0..depths`.
+    pub fn reorder_depths(&mut self, order: &[usize]) -> Result<(), TensorError> {
+        if order.len() != self.depths {
+            return Err(TensorError::DimensionMismatch {
+                expected: self.depths.to_string(),
+                found: order.len().to_string(),
+                operation: "Tensor reorder_depths".to_string(),
+            });
+        }
+        let mut seen = vec![false; self.depths];
+        for &d in order {
+            if d >= self.depths {
+                return Err(TensorError::OutOfBounds {
+                    index: d.to_string(),
+                    size: self.depths.to_string(),
+                });
+            }
+            if std::mem::replace(&mut seen[d], true) {
+                return Err(TensorError::InvalidOperation(format!(
+                    "reorder_depths: depth {} appears more than once",
+                    d
+                )));
+            }
+        }
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for &d in order {
+            data.extend_from_slice(self.depth_slice(d));
+        }
+        self.data = data;
+        Ok(())
+    }
+
+    /// Iterates over views of the depth slices.
+    pub fn iter(&self) -> impl Iterator<Item = MatrixView<'_, T, N>> + '_ {
+        let rows = self.rows;
+        self.depth_slices()
+            .map(move |d| MatrixView::contiguous(d, rows))
+    }
+
+    /// Iterates over the rows of each depth slice, mutably.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut [Vector<T, N>]> + '_ {
+        let (rows, stride) = (self.rows, self.strides().0);
+        let mut rest = self.data.as_mut_slice();
+        (0..self.depths).map(move |_| {
+            let (depth, tail) = std::mem::take(&mut rest).split_at_mut(stride);
+            rest = tail;
+            rows_of_mut(depth, rows)
+        })
+    }
+
+    /// Iterates over every element in depth, row, column order.
+    pub fn iter_flat(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_flat_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Like [`Tensor::iter_flat`], paired with each element's
+    /// `(depth, row, col)` index.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize, usize), &T)> + '_ {
+        let (depth_stride, row_stride) = self.strides();
+        self.data.iter().enumerate().map(move |(k, x)| {
+            let within = k % depth_stride;
+            (
+                (k / depth_stride, within / row_stride, within % row_stride),
+                x,
+            )
+        })
     }
 
     pub fn shape(&self) -> (usize, usize, usize) {
         (self.depths, self.rows, N)
     }
 
-    pub fn get(&self, depth: usize) -> Result<&Matrix<T, N>, TensorError> {
+    pub fn map<U: AllowedNumericTypes, F: FnMut(T) -> U>(&self, f: F) -> Tensor<U, N> {
+        Tensor {
+            data: self.data.iter().copied().map(f).collect(),
+            depths: self.depths,
+            rows: self.rows,
+        }
+    }
+
+    pub fn zip_map<U, V, F>(
+        &self,
+        other: &Tensor<U, N>,
+        mut f: F,
+    ) -> Result<Tensor<V, N>, TensorError>
+    where
+        U: AllowedNumericTypes,
+        V: AllowedNumericTypes,
+        F: FnMut(T, U) -> V,
+    {
+        if self.depths != other.depths || self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}x{}", self.depths, self.rows, N),
+                found: format!("{}x{}x{}", other.depths, other.rows, N),
+                operation: "Tensor zip_map".to_string(),
+            });
+        }
+        Ok(Tensor {
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+            depths: self.depths,
+            rows: self.rows,
+        })
+    }
+
+    pub fn cast<U: AllowedNumericTypes + CastFrom<T>>(&self) -> Tensor<U, N> {
+        self.map(U::cast_from)
+    }
+
+    fn check_depth(&self, depth: usize) -> Result<(), TensorError> {
         if depth >= self.depths {
             return Err(TensorError::OutOfBounds {
                 index: depth.to_string(),
                 size: self.depths.to_string(),
             });
         }
-        Ok(&self.data[depth])
+        Ok(())
     }
 
-    pub fn get_mut(&mut self, depth: usize) -> Result<&mut Matrix<T, N>, TensorError> {
-        if depth >= self.depths {
+    /// A view of depth slice `depth`, borrowed from the tensor's buffer.
+    pub fn get(&self, depth: usize) -> Result<MatrixView<'_, T, N>, TensorError> {
+        self.check_depth(depth)?;
+        Ok(MatrixView::contiguous(self.depth_slice(depth), self.rows))
+    }
+
+    /// The rows of depth slice `depth`, mutably.
+    pub fn get_mut(&mut self, depth: usize) -> Result<&mut [Vector<T, N>], TensorError> {
+        self.check_depth(depth)?;
+        Ok(&mut self[depth])
+    }
+
+    fn check_index(&self, depth: usize, row: usize, col: usize) -> Result<(), TensorError> {
+        if depth >= self.depths || row >= self.rows || col >= N {
             return Err(TensorError::OutOfBounds {
-                index: depth.to_string(),
-                size: self.depths.to_string(),
+                index: format!("({}, {}, {})", depth, row, col),
+                size: format!("{}x{}x{}", self.depths, self.rows, N),
             });
         }
-        Ok(&mut self.data[depth])
+        Ok(())
+    }
+
+    // Flat offset of an in-bounds element
+    fn offset(&self, depth: usize, row: usize, col: usize) -> usize {
+        let (depth_stride, row_stride) = self.strides();
+        depth * depth_stride + row * row_stride + col
+    }
+
+    pub fn get_at(&self, depth: usize, row: usize, col: usize) -> Result<&T, TensorError> {
+        self.check_index(depth, row, col)?;
+        Ok(&self.data[self.offset(depth, row, col)])
+    }
+
+    pub fn get_at_mut(
+        &mut self,
+        depth: usize,
+        row: usize,
+        col: usize,
+    ) -> Result<&mut T, TensorError> {
+        self.check_index(depth, row, col)?;
+        let offset = self.offset(depth, row, col);
+        Ok(&mut self.data[offset])
+    }
+
+    pub fn scalar_add(&self, scalar: T) -> Self {
+        self.map(|x| x + scalar)
+    }
+
+    pub fn scalar_sub(&self, scalar: T) -> Self {
+        self.map(|x| x - scalar)
     }
 
     pub fn scalar_mul(&self, scalar: T) -> Self {
-        let mut result_data = Vec::with_capacity(self.depths);
-        for i in 0..self.depths {
-            result_data.push(self.data[i].scalar_mul(scalar));
+        self.map(|x| x * scalar)
+    }
+
+    pub fn scalar_div(&self, scalar: T) -> Result<Self, TensorError> {
+        if scalar.is_zero() {
+            return Err(TensorError::DivisionByZero);
         }
-        Tensor {
-            data: result_data,
+        Ok(self.map(|x| x / scalar))
+    }
+}
+
+// Parallel iteration for user-written kernels
+#[cfg(feature = "parallel")]
+impl<T: AllowedNumericTypes + Send + Sync, const N: usize> Matrix<T, N> {
+    pub fn par_iter_rows(&self) -> rayon::slice::Iter<'_, Vector<T, N>> {
+        use rayon::prelude::*;
+        self.as_rows().par_iter()
+    }
+
+    pub fn par_iter_rows_mut(&mut self) -> rayon::slice::IterMut<'_, Vector<T, N>> {
+        use rayon::prelude::*;
+        self.as_rows_mut().par_iter_mut()
+    }
+
+    /// Every element in row-major order.
+    pub fn par_iter_flat(&self) -> rayon::slice::Iter<'_, T> {
+        use rayon::prelude::*;
+        self.as_slice().par_iter()
+    }
+
+    pub fn par_iter_flat_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
+        use rayon::prelude::*;
+        self.as_mut_slice().par_iter_mut()
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: AllowedNumericTypes + Send + Sync, const N: usize> Tensor<T, N> {
+    /// Views of the depth slices, in parallel.
+    pub fn par_iter_depths(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = MatrixView<'_, T, N>> + '_ {
+        use rayon::prelude::*;
+        (0..self.depths)
+            .into_par_iter()
+            .map(move |d| MatrixView::contiguous(self.depth_slice(d), self.rows))
+    }
+
+    /// The rows of each depth slice, mutably and in parallel.
+    pub fn par_iter_depths_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut [Vector<T, N>]> + '_ {
+        use rayon::prelude::*;
+        let depths: Vec<&mut [Vector<T, N>]> = self.iter_mut().collect();
+        depths.into_par_iter()
+    }
+
+    /// Every element; items keep depth, row, column order when collected.
+    pub fn par_iter_flat(&self) -> rayon::slice::Iter<'_, T> {
+        use rayon::prelude::*;
+        self.data.par_iter()
+    }
+
+    pub fn par_iter_flat_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
+        use rayon::prelude::*;
+        self.data.par_iter_mut()
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> IntoIterator for Tensor<T, N> {
+    type Item = Matrix<T, N>;
+    type IntoIter = std::vec::IntoIter<Matrix<T, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_matrices().into_iter()
+    }
+}
+
+impl<'a, T: AllowedNumericTypes, const N: usize> IntoIterator for &'a Tensor<T, N> {
+    type Item = MatrixView<'a, T, N>;
+    type IntoIter = Box<dyn Iterator<Item = MatrixView<'a, T, N>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl<'a, T: AllowedNumericTypes, const N: usize> IntoIterator for &'a mut Tensor<T, N> {
+    type Item = &'a mut [Vector<T, N>];
+    type IntoIter = Box<dyn Iterator<Item = &'a mut [Vector<T, N>]> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter_mut())
+    }
+}
+
+/// The rows of one depth slice, so `t[d][r][c]` reaches an element.
+impl<T: AllowedNumericTypes, const N: usize> Index<usize> for Tensor<T, N> {
+    type Output = [Vector<T, N>];
+
+    fn index(&self, depth: usize) -> &Self::Output {
+        rows_of(self.depth_slice(depth), self.rows)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> IndexMut<usize> for Tensor<T, N> {
+    fn index_mut(&mut self, depth: usize) -> &mut Self::Output {
+        let rows = self.rows;
+        rows_of_mut(self.depth_slice_mut(depth), rows)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Index<(usize, usize, usize)> for Tensor<T, N> {
+    type Output = T;
+
+    fn index(&self, (depth, row, col): (usize, usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < N, "index out of bounds");
+        &self.data[self.offset(depth, row, col)]
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> IndexMut<(usize, usize, usize)> for Tensor<T, N> {
+    fn index_mut(&mut self, (depth, row, col): (usize, usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < N, "index out of bounds");
+        let offset = self.offset(depth, row, col);
+        &mut self.data[offset]
+    }
+}
+
+// Elements from which whole-matrix reductions are split across threads with
+// the `parallel` feature
+#[cfg(feature = "parallel")]
+pub(crate) const PARALLEL_MIN_LEN: usize = 1 << 15;
+
+// `count` as a divisor of type `T`. Fails if `T::from_f64` saturates, as for
+// more than 255 elements of `u8`; float types may round.
+pub(crate) fn count_as<T: AllowedNumericTypes>(
+    count: usize,
+    operation: &str,
+) -> Result<T, TensorError> {
+    let n = count as f64;
+    let divisor = T::from_f64(n);
+    if divisor.to_f64() != n && divisor == T::from_f64(2.0 * n) {
+        return Err(TensorError::InvalidOperation(format!(
+            "{}: {} elements cannot be counted in {}",
+            operation,
+            count,
+            std::any::type_name::<T>()
+        )));
+    }
+    Ok(divisor)
+}
+
+// Whole-matrix reductions
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Sum of all elements.
+    ///
+    /// With the `parallel` feature, matrices of at least 32768 elements are
+    /// summed as in [`Matrix::par_sum_deterministic`]; float results can
+    /// then differ from a sequential sum in the last bits, but not between
+    /// runs.
+    pub fn sum(&self) -> T {
+        #[cfg(feature = "parallel")]
+        if self.as_slice().len() >= PARALLEL_MIN_LEN {
+            return pairwise_sum(self.as_slice());
+        }
+        self.as_slice().iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Mean of all elements. Integer element types use truncating division;
+    /// fails if the matrix is empty or `T` cannot hold its element count.
+    pub fn mean(&self) -> Result<T, TensorError> {
+        self.mean_of(self.sum(), "mean")
+    }
+
+    fn mean_of(&self, sum: T, operation: &str) -> Result<T, TensorError> {
+        if self.rows * N == 0 {
+            return Err(TensorError::InvalidOperation(format!(
+                "{} of empty {}x{} matrix",
+                operation, self.rows, N
+            )));
+        }
+        Ok(sum / count_as(self.rows * N, operation)?)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// Parallel sum that is bit-identical across runs and thread counts.
+    ///
+    /// The elements are added over a fixed pairwise tree whose shape depends
+    /// only on the number of elements, not on how rayon schedules the work.
+    pub fn par_sum_deterministic(&self) -> T {
+        pairwise_sum(self.as_slice())
+    }
+
+    /// [`Matrix::mean`] over [`Matrix::par_sum_deterministic`].
+    pub fn par_mean_deterministic(&self) -> Result<T, TensorError> {
+        self.mean_of(self.par_sum_deterministic(), "mean")
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    /// Sum of all elements that is bit-identical across runs and thread
+    /// counts: each depth slice is summed pairwise as in
+    /// [`Matrix::par_sum_deterministic`], then the depth sums pairwise.
+    pub fn par_sum_deterministic(&self) -> T {
+        use rayon::prelude::*;
+
+        let sums: Vec<T> = (0..self.depths)
+            .into_par_iter()
+            .map(|d| pairwise_sum(self.depth_slice(d)))
+            .collect();
+        pairwise_sum(&sums)
+    }
+}
+
+// Elements summed serially at each leaf of the deterministic reduction tree
+#[cfg(feature = "parallel")]
+const PAIRWISE_LEAF: usize = 1024;
+
+// Sums `values` by splitting at fixed midpoints down to `PAIRWISE_LEAF`
+// elements; the two halves run in parallel, but the order of additions is
+// fixed by the length alone
+#[cfg(feature = "parallel")]
+fn pairwise_sum<T: AllowedNumericTypes>(values: &[T]) -> T {
+    if values.len() <= PAIRWISE_LEAF {
+        return values.iter().fold(T::zero(), |acc, &x| acc + x);
+    }
+    let (left, right) = values.split_at(values.len() / 2);
+    let (a, b) = rayon::join(|| pairwise_sum(left), || pairwise_sum(right));
+    a + b
+}
+
+// Per-depth reductions
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    fn check_nonempty_depths(&self, operation: &str) -> Result<(), TensorError> {
+        if self.depths > 0 && self.rows * N == 0 {
+            return Err(TensorError::InvalidOperation(format!(
+                "{} of empty {}x{} depth slices",
+                operation, self.rows, N
+            )));
+        }
+        Ok(())
+    }
+
+    /// Sum of all elements of each depth slice.
+    pub fn sum_per_depth(&self) -> Vec<T> {
+        self.depth_slices()
+            .map(|d| d.iter().fold(T::zero(), |acc, &x| acc + x))
+            .collect()
+    }
+
+    /// Mean of each depth slice. Integer element types use truncating
+    /// division; fails if the slices are empty or `T` cannot hold their
+    /// element count.
+    pub fn mean_per_depth(&self) -> Result<Vec<T>, TensorError> {
+        self.check_nonempty_depths("mean_per_depth")?;
+        let count = count_as(self.rows * N, "mean_per_depth")?;
+        Ok(self
+            .sum_per_depth()
+            .into_iter()
+            .map(|s| s / count)
+            .collect())
+    }
+
+    /// Column sums of each depth slice: row `d` of the result sums the rows
+    /// of depth `d`.
+    pub fn sum_rows_per_depth(&self) -> Matrix<T, N> {
+        let mut result = Matrix::new(self.depths);
+        for (d, out) in result.as_rows_mut().iter_mut().enumerate() {
+            for row in &self[d] {
+                *out = *out + *row;
+            }
+        }
+        result
+    }
+}
+
+impl<T: AllowedNumericTypes + PartialOrd, const N: usize> Tensor<T, N> {
+    /// Largest element of each depth slice; fails if the slices are empty.
+    pub fn max_per_depth(&self) -> Result<Vec<T>, TensorError> {
+        self.check_nonempty_depths("max_per_depth")?;
+        Ok(self
+            .depth_slices()
+            .map(|d| {
+                let mut it = d.iter();
+                let first = *it.next().expect("depth slices are non-empty");
+                it.fold(first, |acc, &x| if x > acc { x } else { acc })
+            })
+            .collect())
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    pub(crate) fn check_same_shape(
+        &self,
+        other: &Self,
+        operation: &str,
+    ) -> Result<(), TensorError> {
+        if self.depths != other.depths || self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}x{}", self.depths, self.rows, N),
+                found: format!("{}x{}x{}", other.depths, other.rows, N),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn hadamard_product(&self, other: &Self) -> Result<Self, TensorError> {
+        self.check_same_shape(other, "Tensor hadamard product")?;
+
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    // Applies `f` between every depth slice and `m`
+    fn broadcast_matrix<F: FnMut(T, T) -> T>(
+        &self,
+        m: &Matrix<T, N>,
+        operation: &str,
+        mut f: F,
+    ) -> Result<Self, TensorError> {
+        if m.rows != self.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", m.rows, N),
+                operation: operation.to_string(),
+            });
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(m.as_slice().iter().cycle())
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        Ok(Tensor {
+            data,
             depths: self.depths,
             rows: self.rows,
+        })
+    }
+
+    /// Adds `m` to every depth slice.
+    pub fn add_broadcast(&self, m: &Matrix<T, N>) -> Result<Self, TensorError> {
+        self.broadcast_matrix(m, "Tensor add_broadcast", |a, b| a + b)
+    }
+
+    /// Multiplies every depth slice element-wise by `m`.
+    pub fn mul_broadcast(&self, m: &Matrix<T, N>) -> Result<Self, TensorError> {
+        self.broadcast_matrix(m, "Tensor mul_broadcast", |a, b| a * b)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Add for Tensor<T, N> {
+    type Output = Result<Self, TensorError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_same_shape(&rhs, "Tensor addition")?;
+        self.zip_map(&rhs, |a, b| a + b)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Sub for Tensor<T, N> {
+    type Output = Result<Self, TensorError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_same_shape(&rhs, "Tensor subtraction")?;
+        self.zip_map(&rhs, |a, b| a - b)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Div for Tensor<T, N> {
+    type Output = Result<Self, TensorError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(&rhs)
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    /// Element-wise division; fails with `DivisionByZero` if any element of
+    /// `other` is zero, like `Vector` division.
+    pub fn checked_div(&self, other: &Self) -> Result<Self, TensorError> {
+        self.check_same_shape(other, "Tensor division")?;
+        if other.iter_flat().any(|x| x.is_zero()) {
+            return Err(TensorError::DivisionByZero);
         }
+        self.zip_map(other, |a, b| a / b)
     }
 }
 
-impl<T: AllowedNumericTypes, const N: usize> Index<usize> for Tensor<T, N> {
-    type Output = Matrix<T, N>;
+// Tolerance-based comparisons for float element types
+fn approx_eq_scalar<T: AllowedFloatTypes>(a: T, b: T, abs_tol: T, rel_tol: T) -> bool {
+    if a == b {
+        return true;
+    }
+    let diff = (a - b).abs();
+    let (a, b) = (a.abs(), b.abs());
+    let largest = if a > b { a } else { b };
+    diff <= abs_tol || diff <= rel_tol * largest
+}
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+impl<T: AllowedFloatTypes, const N: usize> Vector<T, N> {
+    /// Returns true if every pair of elements satisfies
+    /// `|a - b| <= max(abs_tol, rel_tol * max(|a|, |b|))`.
+    ///
+    /// NaN never compares equal.
+    pub fn approx_eq(&self, other: &Self, abs_tol: T, rel_tol: T) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(&a, &b)| approx_eq_scalar(a, b, abs_tol, rel_tol))
     }
 }
 
-impl<T: AllowedNumericTypes, const N: usize> IndexMut<usize> for Tensor<T, N> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+impl<T: AllowedFloatTypes, const N: usize> Matrix<T, N> {
+    /// Element-wise [`Vector::approx_eq`]; false if the shapes differ.
+    pub fn approx_eq(&self, other: &Self, abs_tol: T, rel_tol: T) -> bool {
+        self.rows == other.rows
+            && self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .all(|(&a, &b)| approx_eq_scalar(a, b, abs_tol, rel_tol))
+    }
+}
+
+impl<T: AllowedFloatTypes, const N: usize> Tensor<T, N> {
+    /// Element-wise [`Vector::approx_eq`]; false if the shapes differ.
+    pub fn approx_eq(&self, other: &Self, abs_tol: T, rel_tol: T) -> bool {
+        self.shape() == other.shape()
+            && self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .all(|(&a, &b)| approx_eq_scalar(a, b, abs_tol, rel_tol))
+    }
+}
+
+// In-place arithmetic: these overwrite `self` instead of allocating a result
+fn zip_in_place<T: Copy>(lhs: &mut [T], rhs: &[T], f: impl Fn(T, T) -> T) {
+    for (x, &y) in lhs.iter_mut().zip(rhs.iter()) {
+        *x = f(*x, y);
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Vector<T, N> {
+    pub fn add_in_place(&mut self, other: &Self) {
+        zip_in_place(&mut self.data, &other.data, |a, b| a + b);
+    }
+
+    pub fn sub_in_place(&mut self, other: &Self) {
+        zip_in_place(&mut self.data, &other.data, |a, b| a - b);
+    }
+
+    pub fn hadamard_in_place(&mut self, other: &Self) {
+        zip_in_place(&mut self.data, &other.data, |a, b| a * b);
+    }
+
+    pub fn scale_in_place(&mut self, scalar: T) {
+        self.data.iter_mut().for_each(|x| *x = *x * scalar);
+    }
+
+    /// `self += alpha * x`.
+    pub fn axpy_in_place(&mut self, alpha: T, x: &Self) {
+        T::axpy_slices(&mut self.data, alpha, &x.data);
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    fn check_same_rows(&self, other: &Self, operation: &str) -> Result<(), TensorError> {
+        if self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, N),
+                found: format!("{}x{}", other.rows, N),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn add_in_place(&mut self, other: &Self) -> Result<(), TensorError> {
+        self.check_same_rows(other, "Matrix addition")?;
+        zip_in_place(self.as_mut_slice(), other.as_slice(), |a, b| a + b);
+        Ok(())
+    }
+
+    pub fn sub_in_place(&mut self, other: &Self) -> Result<(), TensorError> {
+        self.check_same_rows(other, "Matrix subtraction")?;
+        zip_in_place(self.as_mut_slice(), other.as_slice(), |a, b| a - b);
+        Ok(())
+    }
+
+    pub fn hadamard_in_place(&mut self, other: &Self) -> Result<(), TensorError> {
+        self.check_same_rows(other, "Matrix hadamard product")?;
+        zip_in_place(self.as_mut_slice(), other.as_slice(), |a, b| a * b);
+        Ok(())
+    }
+
+    pub fn scale_in_place(&mut self, scalar: T) {
+        self.as_mut_slice()
+            .iter_mut()
+            .for_each(|x| *x = *x * scalar);
+    }
+
+    /// `self += alpha * x`.
+    pub fn axpy_in_place(&mut self, alpha: T, x: &Self) -> Result<(), TensorError> {
+        self.check_same_rows(x, "Matrix axpy")?;
+        T::axpy_slices(self.as_mut_slice(), alpha, x.as_slice());
+        Ok(())
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Tensor<T, N> {
+    // Applies `f` to the flat buffers of both tensors
+    fn zip_flat_in_place(
+        &mut self,
+        other: &Self,
+        operation: &str,
+        f: impl Fn(&mut [T], &[T]),
+    ) -> Result<(), TensorError> {
+        self.check_same_shape(other, operation)?;
+        f(&mut self.data, &other.data);
+        Ok(())
+    }
+
+    pub fn add_in_place(&mut self, other: &Self) -> Result<(), TensorError> {
+        self.zip_flat_in_place(other, "Tensor addition", |a, b| {
+            zip_in_place(a, b, |x, y| x + y)
+        })
+    }
+
+    pub fn sub_in_place(&mut self, other: &Self) -> Result<(), TensorError> {
+        self.zip_flat_in_place(other, "Tensor subtraction", |a, b| {
+            zip_in_place(a, b, |x, y| x - y)
+        })
+    }
+
+    pub fn hadamard_in_place(&mut self, other: &Self) -> Result<(), TensorError> {
+        self.zip_flat_in_place(other, "Tensor hadamard product", |a, b| {
+            zip_in_place(a, b, |x, y| x * y)
+        })
+    }
+
+    pub fn scale_in_place(&mut self, scalar: T) {
+        self.data.iter_mut().for_each(|x| *x = *x * scalar);
+    }
+
+    /// `self += alpha * x`.
+    pub fn axpy_in_place(&mut self, alpha: T, x: &Self) -> Result<(), TensorError> {
+        self.zip_flat_in_place(x, "Tensor axpy", |a, b| T::axpy_slices(a, alpha, b))
     }
 }
@@ -0,0 +1,419 @@
+//! Borrowed, non-owning views into a [`Matrix`] or one depth slice of a
+//! [`Tensor`](crate::tensor::Tensor).
+//!
+//! [`Matrix::row`], [`Matrix::col`] and [`Matrix::submatrix`] return views
+//! that index straight into the matrix's buffer through an offset and a
+//! stride instead of copying. Views support the common read-only arithmetic;
+//! the results are new owned values.
+//!
+//! A column is a strided [`VectorView`] whose length is the runtime row
+//! count; a [`MatrixView`] keeps the compile-time column count `C` of the
+//! window, checked at runtime like other shape-changing operations.
+
+use crate::error::TensorError;
+use crate::tensor::{AllowedNumericTypes, Matrix, Vector};
+use crate::tensor_impl::{resolve_range, rows_of};
+use std::ops::{Add, Index, Mul, RangeBounds, Sub};
+
+/// A strided view of `len` elements of a matrix.
+#[derive(Clone, Copy, Debug)]
+pub struct VectorView<'a, T> {
+    data: &'a [T],
+    offset: usize,
+    len: usize,
+    stride: usize,
+}
+
+/// A `rows x C` window of a matrix.
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixView<'a, T, const C: usize> {
+    data: &'a [T],
+    offset: usize,
+    rows: usize,
+    row_stride: usize,
+}
+
+impl<'a, T: AllowedNumericTypes> VectorView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| self.data[self.offset + index * self.stride])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        let (data, offset, stride) = (self.data, self.offset, self.stride);
+        (0..self.len).map(move |i| data[offset + i * stride])
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Copies into a [`Vector`]; `M` must equal the view's length.
+    pub fn to_vector<const M: usize>(&self) -> Result<Vector<T, M>, TensorError> {
+        self.check_len(M, "VectorView to_vector")?;
+        let mut it = self.iter();
+        Ok(Vector::from(std::array::from_fn(|_| {
+            it.next().expect("length checked")
+        })))
+    }
+
+    fn check_len(&self, len: usize, operation: &str) -> Result<(), TensorError> {
+        if self.len != len {
+            return Err(TensorError::DimensionMismatch {
+                expected: self.len.to_string(),
+                found: len.to_string(),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn dot(&self, other: &VectorView<'_, T>) -> Result<T, TensorError> {
+        self.check_len(other.len, "VectorView dot")?;
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .fold(T::zero(), |acc, (a, b)| acc + a * b))
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Vec<T> {
+        self.iter().map(|x| x * scalar).collect()
+    }
+
+    fn zip_with(
+        &self,
+        other: &VectorView<'_, T>,
+        operation: &str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Vec<T>, TensorError> {
+        self.check_len(other.len, operation)?;
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| f(a, b))
+            .collect())
+    }
+}
+
+impl<'b, T: AllowedNumericTypes> Add<VectorView<'b, T>> for VectorView<'_, T> {
+    type Output = Result<Vec<T>, TensorError>;
+
+    fn add(self, rhs: VectorView<'b, T>) -> Self::Output {
+        self.zip_with(&rhs, "VectorView addition", |a, b| a + b)
+    }
+}
+
+impl<'b, T: AllowedNumericTypes> Sub<VectorView<'b, T>> for VectorView<'_, T> {
+    type Output = Result<Vec<T>, TensorError>;
+
+    fn sub(self, rhs: VectorView<'b, T>) -> Self::Output {
+        self.zip_with(&rhs, "VectorView subtraction", |a, b| a - b)
+    }
+}
+
+impl<'b, T: AllowedNumericTypes> Mul<VectorView<'b, T>> for VectorView<'_, T> {
+    type Output = Result<Vec<T>, TensorError>;
+
+    /// Element-wise product.
+    fn mul(self, rhs: VectorView<'b, T>) -> Self::Output {
+        self.zip_with(&rhs, "VectorView hadamard product", |a, b| a * b)
+    }
+}
+
+impl<'a, T: AllowedNumericTypes, const C: usize> MatrixView<'a, T, C> {
+    // A view of a row-major buffer holding exactly `rows x C` elements
+    pub(crate) fn contiguous(data: &'a [T], rows: usize) -> Self {
+        debug_assert_eq!(data.len(), rows * C);
+        MatrixView {
+            data,
+            offset: 0,
+            rows,
+            row_stride: C,
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, C)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        (row < self.rows && col < C).then(|| self.data[self.offset + row * self.row_stride + col])
+    }
+
+    pub fn row(&self, row: usize) -> Result<VectorView<'a, T>, TensorError> {
+        check_index(row, self.rows)?;
+        Ok(VectorView {
+            data: self.data,
+            offset: self.offset + row * self.row_stride,
+            len: C,
+            stride: 1,
+        })
+    }
+
+    pub fn col(&self, col: usize) -> Result<VectorView<'a, T>, TensorError> {
+        check_index(col, C)?;
+        Ok(VectorView {
+            data: self.data,
+            offset: self.offset + col,
+            len: self.rows,
+            stride: self.row_stride,
+        })
+    }
+
+    // The elements of row `row`, which must be in bounds
+    fn row_slice(&self, row: usize) -> &'a [T] {
+        let start = self.offset + row * self.row_stride;
+        &self.data[start..start + C]
+    }
+
+    /// Copies the window into an owned matrix.
+    pub fn to_matrix(&self) -> Matrix<T, C> {
+        self.map(|x| x)
+    }
+
+    pub fn map(&self, f: impl Fn(T) -> T) -> Matrix<T, C> {
+        Matrix::from_vectors(
+            (0..self.rows)
+                .map(|r| {
+                    let row = self.row_slice(r);
+                    Vector::from(std::array::from_fn(|c| f(row[c])))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn scalar_mul(&self, scalar: T) -> Matrix<T, C> {
+        self.map(|x| x * scalar)
+    }
+
+    fn zip_with(
+        &self,
+        other: &MatrixView<'_, T, C>,
+        operation: &str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Matrix<T, C>, TensorError> {
+        if self.rows != other.rows {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", self.rows, C),
+                found: format!("{}x{}", other.rows, C),
+                operation: operation.to_string(),
+            });
+        }
+        Ok(Matrix::from_vectors(
+            (0..self.rows)
+                .map(|r| {
+                    let (a, b) = (self.row_slice(r), other.row_slice(r));
+                    Vector::from(std::array::from_fn(|c| f(a[c], b[c])))
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn hadamard_product(
+        &self,
+        other: &MatrixView<'_, T, C>,
+    ) -> Result<Matrix<T, C>, TensorError> {
+        self.zip_with(other, "MatrixView hadamard product", |a, b| a * b)
+    }
+
+    /// Matrix product with another view, whose row count must equal `C`.
+    pub fn mat_mul<const M: usize>(
+        &self,
+        rhs: &MatrixView<'_, T, M>,
+    ) -> Result<Matrix<T, M>, TensorError> {
+        if rhs.rows != C {
+            return Err(TensorError::DimensionMismatch {
+                expected: format!("{}x{}", C, M),
+                found: format!("{}x{}", rhs.rows, M),
+                operation: "MatrixView multiplication".to_string(),
+            });
+        }
+        let mut result: Matrix<T, M> = Matrix::new(self.rows);
+        for (r, out) in result.as_rows_mut().iter_mut().enumerate() {
+            for (&x, k) in self.row_slice(r).iter().zip(0..C) {
+                T::axpy_slices(&mut out.data, x, rhs.row_slice(k));
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<'b, T: AllowedNumericTypes, const C: usize> Add<MatrixView<'b, T, C>>
+    for MatrixView<'_, T, C>
+{
+    type Output = Result<Matrix<T, C>, TensorError>;
+
+    fn add(self, rhs: MatrixView<'b, T, C>) -> Self::Output {
+        self.zip_with(&rhs, "MatrixView addition", |a, b| a + b)
+    }
+}
+
+impl<'b, T: AllowedNumericTypes, const C: usize> Sub<MatrixView<'b, T, C>>
+    for MatrixView<'_, T, C>
+{
+    type Output = Result<Matrix<T, C>, TensorError>;
+
+    fn sub(self, rhs: MatrixView<'b, T, C>) -> Self::Output {
+        self.zip_with(&rhs, "MatrixView subtraction", |a, b| a - b)
+    }
+}
+
+/// Row `row` of the window, borrowed from the underlying buffer.
+impl<T: AllowedNumericTypes, const C: usize> Index<usize> for MatrixView<'_, T, C> {
+    type Output = Vector<T, C>;
+
+    fn index(&self, row: usize) -> &Self::Output {
+        assert!(
+            row < self.rows,
+            "row {} out of bounds for {} rows",
+            row,
+            self.rows
+        );
+        &rows_of(self.row_slice(row), 1)[0]
+    }
+}
+
+fn check_index(index: usize, size: usize) -> Result<(), TensorError> {
+    if index >= size {
+        return Err(TensorError::OutOfBounds {
+            index: index.to_string(),
+            size: size.to_string(),
+        });
+    }
+    Ok(())
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// A view of the whole matrix.
+    pub fn view(&self) -> MatrixView<'_, T, N> {
+        MatrixView::contiguous(self.as_slice(), self.rows)
+    }
+
+    pub fn row(&self, row: usize) -> Result<VectorView<'_, T>, TensorError> {
+        self.view().row(row)
+    }
+
+    /// A strided view of column `col`.
+    pub fn col(&self, col: usize) -> Result<VectorView<'_, T>, TensorError> {
+        self.view().col(col)
+    }
+
+    /// A view of the rows in `rows` and the `C` columns starting at
+    /// `first_col`.
+    pub fn submatrix<const C: usize, R: RangeBounds<usize>>(
+        &self,
+        rows: R,
+        first_col: usize,
+    ) -> Result<MatrixView<'_, T, C>, TensorError> {
+        let (start, end) = resolve_range(rows, self.rows)?;
+        if first_col + C > N {
+            return Err(TensorError::OutOfBounds {
+                index: format!("{}..{}", first_col, first_col + C),
+                size: N.to_string(),
+            });
+        }
+        Ok(MatrixView {
+            data: self.as_slice(),
+            offset: start * N + first_col,
+            rows: end - start,
+            row_stride: N,
+        })
+    }
+}
+
+/// A mutable strided view of `len` elements of a matrix, such as one
+/// column.
+#[derive(Debug)]
+pub struct VectorViewMut<'a, T> {
+    data: &'a mut [T],
+    offset: usize,
+    len: usize,
+    stride: usize,
+}
+
+impl<T: AllowedNumericTypes> VectorViewMut<'_, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| self.data[self.offset + index * self.stride])
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.len).then(|| &mut self.data[self.offset + index * self.stride])
+    }
+
+    /// A read-only view of the same elements.
+    pub fn as_view(&self) -> VectorView<'_, T> {
+        VectorView {
+            data: self.data,
+            offset: self.offset,
+            len: self.len,
+            stride: self.stride,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_view().to_vec()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let len = self.len;
+        self.data
+            .iter_mut()
+            .skip(self.offset)
+            .step_by(self.stride)
+            .take(len)
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.iter_mut().for_each(|x| *x = value);
+    }
+
+    /// Overwrites the elements with `values`, which must have the view's
+    /// length.
+    pub fn assign(&mut self, values: &[T]) -> Result<(), TensorError> {
+        self.as_view()
+            .check_len(values.len(), "VectorViewMut assign")?;
+        self.iter_mut().zip(values).for_each(|(x, &v)| *x = v);
+        Ok(())
+    }
+
+    pub fn scale(&mut self, scalar: T) {
+        self.iter_mut().for_each(|x| *x = *x * scalar);
+    }
+
+    /// `self += alpha * x`; `x` must have the view's length.
+    pub fn axpy(&mut self, alpha: T, x: &[T]) -> Result<(), TensorError> {
+        self.as_view().check_len(x.len(), "VectorViewMut axpy")?;
+        self.iter_mut()
+            .zip(x)
+            .for_each(|(y, &x)| *y = *y + alpha * x);
+        Ok(())
+    }
+}
+
+impl<T: AllowedNumericTypes, const N: usize> Matrix<T, N> {
+    /// A mutable strided view of column `col`.
+    pub fn col_mut(&mut self, col: usize) -> Result<VectorViewMut<'_, T>, TensorError> {
+        check_index(col, N)?;
+        Ok(VectorViewMut {
+            len: self.rows,
+            data: self.as_mut_slice(),
+            offset: col,
+            stride: N,
+        })
+    }
+}
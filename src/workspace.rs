@@ -0,0 +1,42 @@
+//! Reusable scratch memory for repeated decompositions.
+//!
+//! Routines such as [`Matrix::solve_in`](crate::tensor::Matrix::solve_in),
+//! [`Matrix::pinv_apply_in`](crate::tensor::Matrix::pinv_apply_in) and
+//! [`Matrix::mat_mul_in`](crate::tensor::Matrix::mat_mul_in) need a working
+//! copy of their input or other temporaries. Passing the same [`Workspace`]
+//! to every call lets them reuse one buffer, which only grows to the largest
+//! size requested, instead of allocating on each call.
+
+use crate::tensor::AllowedNumericTypes;
+
+/// A growable scratch buffer lent to algorithms for their temporaries.
+#[derive(Clone, Debug, Default)]
+pub struct Workspace<T> {
+    buffer: Vec<T>,
+}
+
+impl<T: AllowedNumericTypes> Workspace<T> {
+    pub fn new() -> Self {
+        Workspace { buffer: Vec::new() }
+    }
+
+    /// A workspace that can lend `len` elements without allocating.
+    pub fn with_capacity(len: usize) -> Self {
+        Workspace {
+            buffer: Vec::with_capacity(len),
+        }
+    }
+
+    /// Number of elements that can be lent without allocating.
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    // A scratch slice of `len` elements with unspecified contents
+    pub(crate) fn scratch(&mut self, len: usize) -> &mut [T] {
+        if self.buffer.len() < len {
+            self.buffer.resize(len, T::zero());
+        }
+        &mut self.buffer[..len]
+    }
+}
@@ -0,0 +1,33 @@
+use tensor_algebra_in_rust::tensor::Matrix;
+use tensor_algebra_in_rust::vector;
+
+#[test]
+fn vector_softmax_is_stable_and_sums_to_one() {
+    let v = vector![1.0f64, 2.0, 3.0];
+    let s = v.softmax();
+    let total: f64 = s.iter().sum();
+    assert!((total - 1.0).abs() < 1e-12);
+    assert!(s[0] < s[1] && s[1] < s[2]);
+
+    // Shifting the input does not change the result and does not overflow
+    let shifted = vector![1001.0f64, 1002.0, 1003.0].softmax();
+    for i in 0..3 {
+        assert!((shifted[i] - s[i]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn elementwise_activations() {
+    let v = vector![-2.0f32, 0.0, 3.0];
+    assert_eq!(v.relu(), vector![0.0, 0.0, 3.0]);
+    assert_eq!(v.sigmoid()[1], 0.5);
+    assert!((v.tanh()[2] - 3.0f32.tanh()).abs() < 1e-7);
+
+    let m = Matrix::<f64, 2>::from_vectors(vec![vector![0.0, 0.0], vector![-1.0, 1.0]]);
+    let s = m.softmax_rows();
+    assert_eq!(s[0], vector![0.5, 0.5]);
+    assert!((s[1][0] + s[1][1] - 1.0).abs() < 1e-12);
+    assert_eq!(m.relu()[1], vector![0.0, 1.0]);
+    assert_eq!(m.sigmoid()[0], vector![0.5, 0.5]);
+    assert_eq!(m.tanh()[0], vector![0.0, 0.0]);
+}
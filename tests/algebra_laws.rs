@@ -0,0 +1,37 @@
+use tensor_algebra_in_rust::linalg::Norm;
+use tensor_algebra_in_rust::tensor::Vector;
+use tensor_algebra_in_rust::{impl_algebra_tests, impl_allowed_numeric, vector};
+
+impl_algebra_tests!(f32_laws, f32, tolerance = 1e-6);
+impl_algebra_tests!(f64_laws, f64);
+impl_algebra_tests!(i32_laws, i32);
+impl_algebra_tests!(i64_laws, i64);
+impl_algebra_tests!(u32_laws, u32);
+impl_algebra_tests!(u64_laws, u64);
+impl_algebra_tests!(i8_laws, i8);
+impl_algebra_tests!(i16_laws, i16);
+impl_algebra_tests!(isize_laws, isize);
+impl_algebra_tests!(u8_laws, u8);
+impl_algebra_tests!(u16_laws, u16);
+impl_algebra_tests!(usize_laws, usize);
+impl_algebra_tests!(i128_laws, i128);
+impl_algebra_tests!(u128_laws, u128);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+struct Meters(f64);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Count(u32);
+
+impl_allowed_numeric!(Meters(f64), float);
+impl_allowed_numeric!(Count(u32));
+
+impl_algebra_tests!(meters_laws, Meters);
+impl_algebra_tests!(count_laws, Count);
+
+#[test]
+fn registered_newtypes_use_float_routines() {
+    let v: Vector<Meters, 2> = vector![Meters(3.0), Meters(4.0)];
+    assert_eq!(v.norm(Norm::L2), Meters(5.0));
+    assert_eq!((Count(7) / Count(2)), Count(3));
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "allocator")]
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::NonNull;
+use tensor_algebra_in_rust::allocator::{AllocMatrix, AllocTensor, Allocator, Global};
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
+use tensor_algebra_in_rust::{matrix, tensor};
+
+// Forwards to the global allocator and counts allocations
+#[derive(Default)]
+struct Counting {
+    allocations: Cell<usize>,
+}
+
+unsafe impl Allocator for &Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, allocator_api2::alloc::AllocError> {
+        self.allocations.set(self.allocations.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        Global.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn matrix_storage_uses_one_allocation_from_custom_allocator() {
+    let counter = Counting::default();
+    let mut m = AllocMatrix::<f64, 3, _>::new_in(4, &counter);
+    assert_eq!(counter.allocations.get(), 1);
+    assert_eq!(m.shape(), (4, 3));
+
+    m[2][1] = 5.0;
+    assert_eq!(m.get(2).unwrap(), &[0.0, 5.0, 0.0]);
+    assert!(m.get(4).is_err());
+
+    let dense: Matrix<f64, 3> = m.to_matrix();
+    assert_eq!(dense[2][1], 5.0);
+}
+
+#[test]
+fn round_trips_through_matrix() {
+    let m: Matrix<i32, 2> = matrix![1, 2; 3, 4];
+    let a = AllocMatrix::from_matrix_in(&m, Global);
+    assert_eq!(a.as_slice(), &[1, 2, 3, 4]);
+    assert_eq!(a.to_matrix(), m);
+    assert_eq!(AllocMatrix::<i32, 2>::new(1).as_slice(), &[0, 0]);
+}
+
+#[test]
+fn operations_stay_in_the_custom_allocator() {
+    let counter = Counting::default();
+    let a: Matrix<f64, 6> = Matrix::from_fn(3, |i, j| (i * 6 + j) as f64 * 0.25 - 2.0);
+    let b: Matrix<f64, 5> = Matrix::from_fn(6, |i, j| (i + 2 * j) as f64 - 3.5);
+    let (x, y) = (
+        AllocMatrix::from_matrix_in(&a, &counter),
+        AllocMatrix::from_matrix_in(&b, &counter),
+    );
+
+    // The result and the Kahan compensations
+    let product = x.mat_mul(&y).unwrap();
+    assert_eq!(counter.allocations.get(), 4);
+    assert_eq!(product.to_matrix(), (&a * &b).unwrap());
+    assert!(matches!(
+        y.mat_mul(&y),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    let mut z = x.map(|v| v * 2.0);
+    z.sub_in_place(&x).unwrap();
+    z.hadamard_in_place(&x).unwrap();
+    z.scale_in_place(0.5);
+    z.add_in_place(&x).unwrap();
+    assert_eq!(z.to_matrix(), a.map(|v| v * v * 0.5 + v));
+    assert_eq!(x.sum(), a.sum());
+    assert!(z.add_in_place(&AllocMatrix::new(2)).is_err());
+
+    let t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    let mut s = AllocTensor::from_tensor_in(&t, &counter);
+    let before = counter.allocations.get();
+    let w = AllocMatrix::from_matrix_in(&matrix![1, 0, 2; 0, 1, -1], &counter);
+    let batched = s.batched_mat_mul(&w).unwrap();
+    assert_eq!(batched.shape(), (2, 2, 3));
+    assert_eq!(&batched[(1, 0)], &[5, 6, 4]);
+    assert_eq!(counter.allocations.get(), before + 3);
+
+    s.add_in_place(&AllocTensor::from_tensor_in(&t, Global))
+        .unwrap();
+    assert_eq!(s.to_tensor(), t.map(|v| 2 * v));
+    assert_eq!(s.sum(), 72);
+    assert_eq!(
+        s.map(|v| v as f64 / 2.0).depth(1).unwrap(),
+        &[5.0, 6.0, 7.0, 8.0]
+    );
+    assert!(s.depth(2).is_err());
+    assert!(matches!(
+        s.sub_in_place(&AllocTensor::new(1, 2)),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    s[(0, 1)][0] = 0;
+    assert_eq!(s.as_slice()[2], 0);
+    let empty: Tensor<i32, 2> = AllocTensor::new(0, 3).to_tensor();
+    assert_eq!(empty.shape(), (0, 3, 2));
+}
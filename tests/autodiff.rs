@@ -0,0 +1,93 @@
+#![cfg(feature = "autodiff")]
+
+use tensor_algebra_in_rust::autodiff::Tape;
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+fn assert_close<const N: usize>(a: &Matrix<f64, N>, b: &Matrix<f64, N>) {
+    assert_eq!(a.shape(), b.shape());
+    for r in 0..a.shape().0 {
+        for c in 0..N {
+            assert!(
+                (a[r][c] - b[r][c]).abs() < 1e-6,
+                "expected {:?}, got {:?}",
+                b,
+                a
+            );
+        }
+    }
+}
+
+// Central differences of `f` with respect to each element of `x`
+fn numeric_grad<const N: usize>(
+    f: impl Fn(&Matrix<f64, N>) -> f64,
+    x: &Matrix<f64, N>,
+) -> Matrix<f64, N> {
+    let h = 1e-6;
+    let mut grad = Matrix::new(x.shape().0);
+    for r in 0..x.shape().0 {
+        for c in 0..N {
+            let (mut up, mut down) = (x.clone(), x.clone());
+            up[r][c] += h;
+            down[r][c] -= h;
+            grad[r][c] = (f(&up) - f(&down)) / (2.0 * h);
+        }
+    }
+    grad
+}
+
+#[test]
+fn two_layer_network_gradients_match_finite_differences() {
+    let x0: Matrix<f64, 3> = matrix![0.5, -1.0, 2.0; 1.5, 0.0, -0.5];
+    let w0: Matrix<f64, 2> = matrix![0.1, -0.2; 0.4, 0.3; -0.5, 0.2];
+    let target: Matrix<f64, 2> = matrix![1.0, 0.0; 0.0, 1.0];
+
+    let loss = |x: &Matrix<f64, 3>, w: &Matrix<f64, 2>| {
+        let tape = Tape::new();
+        let (x, w, t) = (
+            tape.var(x.clone()),
+            tape.var(w.clone()),
+            tape.var(target.clone()),
+        );
+        let err = x.matmul(&w).unwrap().tanh().sub(&t).unwrap();
+        err.hadamard(&err).unwrap().sum().scalar_mul(0.5).value()[0][0]
+    };
+
+    let tape = Tape::new();
+    let (x, w, t) = (
+        tape.var(x0.clone()),
+        tape.var(w0.clone()),
+        tape.var(target.clone()),
+    );
+    let err = x.matmul(&w).unwrap().tanh().sub(&t).unwrap();
+    let out = err.hadamard(&err).unwrap().sum().scalar_mul(0.5);
+    assert!((out.value()[0][0] - loss(&x0, &w0)).abs() < 1e-12);
+
+    let grads = out.backward();
+    assert_close(&grads.wrt(&w), &numeric_grad(|w| loss(&x0, w), &w0));
+    assert_close(&grads.wrt(&x), &numeric_grad(|x| loss(x, &w0), &x0));
+}
+
+#[test]
+fn add_accumulates_shared_inputs_and_shapes_are_checked() {
+    let tape = Tape::new();
+    let a = tape.var(matrix![1.0, 2.0]);
+    let b = tape.var(matrix![3.0, 4.0]);
+    // d/da sum(a + a * b) = 1 + b
+    let y = a.add(&a.hadamard(&b).unwrap()).unwrap().sum();
+    let grads = y.backward();
+    assert_eq!(grads.wrt(&a), matrix![4.0, 5.0]);
+    assert_eq!(grads.wrt(&b), matrix![1.0, 2.0]);
+
+    let tall = tape.var(matrix![1.0, 2.0; 3.0, 4.0]);
+    assert!(matches!(
+        a.add(&tall),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        a.matmul(&tall.sum()),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert_eq!(tape.len(), 7);
+}
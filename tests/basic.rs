@@ -1,6 +1,6 @@
 use tensor_algebra_in_rust::error::TensorError;
-use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
-use tensor_algebra_in_rust::vector;
+use tensor_algebra_in_rust::tensor::{Axis, Matrix, Tensor, Vector};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
 
 #[test]
 fn vector_elementwise_ops_and_scalar_ops() {
@@ -38,7 +38,7 @@ fn vector_division_by_zero_errors() {
     let a = vector![1, 2, 3];
     let z = vector![1, 0, 1];
 
-    assert_eq!(a.clone() / z, Err(TensorError::DivisionByZero));
+    assert_eq!(a / z, Err(TensorError::DivisionByZero));
     assert!(a.scalar_div(0).is_err());
 }
 
@@ -78,3 +78,667 @@ fn tensor_scalar_mul_and_shape_and_bounds() {
     // out of bounds check
     assert!(t.get(2).is_err());
 }
+
+#[test]
+fn cast_between_element_types() {
+    let v = vector![1, -2, 3];
+    assert_eq!(v.cast::<f64>(), vector![1.0, -2.0, 3.0]);
+
+    // float -> integer truncates toward zero and saturates
+    let f = vector![2.9f64, -2.9, 1e20];
+    assert_eq!(f.cast::<i32>(), vector![2, -2, i32::MAX]);
+    assert_eq!(vector![-1.0f32].cast::<u32>(), vector![0]);
+
+    let m = Matrix::<i64, 2>::from_vectors(vec![vector![1, 2], vector![3, 4]]);
+    let mf = m.cast::<f32>();
+    assert_eq!(mf[1], vector![3.0, 4.0]);
+
+    let t = Tensor::<f64, 2>::new(2, 3).scalar_mul(0.0).cast::<u64>();
+    assert_eq!(t.shape(), (2, 3, 2));
+    assert_eq!(t[1][2], vector![0, 0]);
+
+    // Integers convert exactly, without a detour through f64
+    let big = vector![i64::MAX, i64::MIN + 1];
+    assert_eq!(
+        big.cast::<i128>(),
+        vector![i64::MAX as i128, i64::MIN as i128 + 1]
+    );
+    assert_eq!(
+        vector![u128::MAX - 1].cast::<u128>(),
+        vector![u128::MAX - 1]
+    );
+    assert_eq!(
+        vector![(1u64 << 53) + 1].cast::<i64>(),
+        vector![(1i64 << 53) + 1]
+    );
+    assert_eq!(
+        vector![-1i64, 300, u64::MAX as i64].cast::<u8>(),
+        vector![0, 255, 0]
+    );
+    assert_eq!(vector![u64::MAX].cast::<i64>(), vector![i64::MAX]);
+}
+
+#[test]
+fn approx_eq_with_absolute_and_relative_tolerance() {
+    let a = vector![1.0, 1000.0, 0.0];
+    let b = vector![1.0 + 1e-10, 1000.0 + 1e-7, 1e-13];
+    assert!(a.approx_eq(&b, 1e-12, 1e-9));
+    assert!(!a.approx_eq(&b, 1e-14, 1e-9));
+    assert!(!vector![f64::NAN].approx_eq(&vector![f64::NAN], 1.0, 1.0));
+    assert!(vector![f64::INFINITY].approx_eq(&vector![f64::INFINITY], 0.0, 0.0));
+
+    let m1 = Matrix::<f32, 2>::from_vectors(vec![vector![0.1 + 0.2, 1.0]]);
+    let m2 = Matrix::<f32, 2>::from_vectors(vec![vector![0.3, 1.0]]);
+    assert!(m1.approx_eq(&m2, 1e-6, 0.0));
+    assert!(!m1.approx_eq(&Matrix::new(2), 1e-6, 0.0));
+
+    let t1 = Tensor::<f64, 2>::new(2, 2);
+    let t2 = t1.scalar_mul(3.0);
+    assert!(t1.approx_eq(&t2, 0.0, 0.0));
+    assert!(!t1.approx_eq(&Tensor::new(1, 2), 1.0, 1.0));
+}
+
+#[test]
+fn wrapped_access_uses_periodic_indices() {
+    let v = vector![1, 2, 3];
+    assert_eq!(*v.get_wrapped(-1).unwrap(), 3);
+    assert_eq!(*v.get_wrapped(4).unwrap(), 2);
+    assert!(Vector::<i32, 0>::new().get_wrapped(0).is_err());
+
+    let m = Matrix::<i32, 3>::from_vectors(vec![vector![1, 2, 3], vector![4, 5, 6]]);
+    assert_eq!(*m.get_wrapped(-1, -1).unwrap(), 6);
+    assert_eq!(*m.get_wrapped(2, 3).unwrap(), 1);
+    assert_eq!(*m.get_wrapped(-3, 7).unwrap(), 5);
+    assert!(Matrix::<i32, 3>::new(0).get_wrapped(0, 0).is_err());
+}
+
+#[test]
+fn matrix_storage_is_one_row_major_buffer() {
+    let m = Matrix::<i32, 3>::from_vec(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(m.as_rows(), &[vector![1, 2, 3], vector![4, 5, 6]]);
+    assert_eq!(m[1][2], 6);
+    assert!(matches!(
+        Matrix::<i32, 3>::from_vec(2, vec![1, 2, 3]),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    let mut m = m;
+    m.as_rows_mut()[0] = vector![7, 8, 9];
+    assert_eq!(m.into_vec(), vec![7, 8, 9, 4, 5, 6]);
+}
+
+#[test]
+fn tensor_elementwise_add_sub_and_hadamard() {
+    let mut a = Tensor::<i32, 2>::new(2, 2);
+    let mut b = Tensor::<i32, 2>::new(2, 2);
+    for d in 0..2 {
+        for r in 0..2 {
+            a[d][r] = vector![1 + d as i32, 2 + r as i32];
+            b[d][r] = vector![3, 4];
+        }
+    }
+
+    let sum = (a.clone() + b.clone()).unwrap();
+    assert_eq!(sum[1][1], vector![5, 7]);
+
+    let diff = (a.clone() - b.clone()).unwrap();
+    assert_eq!(diff[0][0], vector![-2, -2]);
+
+    let prod = a.hadamard_product(&b).unwrap();
+    assert_eq!(prod[1][0], vector![6, 8]);
+
+    let m = Matrix::<i32, 2>::from_vectors(vec![vector![5, 5]]);
+    let n = Matrix::<i32, 2>::from_vectors(vec![vector![2, 3]]);
+    assert_eq!((m.clone() - n.clone()).unwrap()[0], vector![3, 2]);
+    assert_eq!(m.hadamard_product(&n).unwrap()[0], vector![10, 15]);
+
+    // shape mismatch in depths or rows
+    let wrong_depths = Tensor::<i32, 2>::new(3, 2);
+    let wrong_rows = Tensor::<i32, 2>::new(2, 1);
+    assert!((a.clone() + wrong_depths).is_err());
+    assert!((a.clone() - wrong_rows.clone()).is_err());
+    match a.hadamard_product(&wrong_rows).unwrap_err() {
+        TensorError::DimensionMismatch { .. } => {}
+        other => panic!("Expected DimensionMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn tensor_scalar_ops_parity() {
+    let t = Tensor::<i32, 2>::new(2, 2).scalar_add(6);
+    assert_eq!(t[1][1], vector![6, 6]);
+
+    let s = t.scalar_sub(2);
+    assert_eq!(s[0][0], vector![4, 4]);
+
+    let d = s.scalar_div(2).unwrap();
+    assert_eq!(d[1][0], vector![2, 2]);
+    assert_eq!(s.scalar_div(0), Err(TensorError::DivisionByZero));
+
+    let m = Matrix::<f64, 2>::from_vectors(vec![vector![1.0, 2.0]]);
+    assert_eq!(m.scalar_add(1.0)[0], vector![2.0, 3.0]);
+    assert_eq!(m.scalar_sub(1.0)[0], vector![0.0, 1.0]);
+    assert_eq!(m.scalar_div(2.0).unwrap()[0], vector![0.5, 1.0]);
+    assert!(m.scalar_div(0.0).is_err());
+    assert_eq!(vector![3, 4].scalar_sub(1), vector![2, 3]);
+}
+
+#[test]
+fn tensor_map_and_zip_map() {
+    let t = Tensor::<i32, 3>::new(2, 2).scalar_add(2);
+    let squared = t.map(|x| x * x);
+    assert_eq!(squared[1][1], vector![4, 4, 4]);
+
+    let as_float = t.map(|x| x as f64 / 4.0);
+    assert_eq!(as_float[0][0], vector![0.5, 0.5, 0.5]);
+
+    let other = Tensor::<f64, 3>::new(2, 2).scalar_add(1.5);
+    let mixed = t.zip_map(&other, |a, b| a as f64 * b).unwrap();
+    assert_eq!(mixed[1][0], vector![3.0, 3.0, 3.0]);
+
+    assert!(t
+        .zip_map(&Tensor::<i32, 3>::new(2, 3), |a, b| a + b)
+        .is_err());
+    assert!(t
+        .zip_map(&Tensor::<i32, 3>::new(1, 2), |a, b| a + b)
+        .is_err());
+
+    let m = Matrix::<i32, 2>::from_vectors(vec![vector![1, 2]]);
+    let n = Matrix::<i32, 2>::from_vectors(vec![vector![3, 4]]);
+    assert_eq!(m.zip_map(&n, |a, b| a.max(b)).unwrap()[0], vector![3, 4]);
+    assert_eq!(
+        vector![1, 5].zip_map(&vector![2, 2], |a, b| a - b),
+        vector![-1, 3]
+    );
+}
+
+#[test]
+fn tensor_three_index_access() {
+    let mut t = Tensor::<i32, 3>::new(2, 2);
+    t[(1, 0, 2)] = 7;
+    assert_eq!(t[(1, 0, 2)], 7);
+    assert_eq!(t[1][0][2], 7);
+
+    *t.get_at_mut(0, 1, 1).unwrap() = 4;
+    assert_eq!(*t.get_at(0, 1, 1).unwrap(), 4);
+
+    // every axis is bounds-checked
+    assert!(t.get_at(2, 0, 0).is_err());
+    assert!(t.get_at(0, 2, 0).is_err());
+    assert!(t.get_at_mut(0, 0, 3).is_err());
+    match t.get_at(0, 0, 3).unwrap_err() {
+        TensorError::OutOfBounds { .. } => {}
+        other => panic!("Expected OutOfBounds, got {:?}", other),
+    }
+}
+
+#[test]
+fn tensor_macro_builds_from_nested_literals() {
+    let t = tensor![
+        [[1, 2, 3]; [4, 5, 6]];
+        [[7, 8, 9]; [10, 11, 12]]
+    ];
+    assert_eq!(t.shape(), (2, 2, 3));
+    assert_eq!(t[(1, 0, 2)], 9);
+    assert_eq!(t[0][1], vector![4, 5, 6]);
+
+    let single: Tensor<f64, 2> = tensor![[[0.5, 1.5]]];
+    assert_eq!(single.shape(), (1, 1, 2));
+}
+
+#[test]
+#[should_panic(expected = "same number of rows")]
+fn tensor_macro_rejects_ragged_depths() {
+    let _ = tensor![[[1, 2]; [3, 4]]; [[5, 6]]];
+}
+
+#[test]
+fn tensor_from_matrices_stack_and_unstack() {
+    let a = Matrix::from_vectors(vec![vector![1, 2], vector![3, 4]]);
+    let b = a.scalar_mul(10);
+
+    let t = Tensor::from_matrices(vec![a.clone(), b.clone()]).unwrap();
+    assert_eq!(t.shape(), (2, 2, 2));
+    assert_eq!(t[(1, 1, 0)], 30);
+    assert_eq!(Matrix::stack(&[a.clone(), b.clone()]).unwrap(), t);
+    assert_eq!(t.into_matrices(), vec![a.clone(), b]);
+
+    let empty = Tensor::<i32, 2>::from_matrices(Vec::new()).unwrap();
+    assert_eq!(empty.shape(), (0, 0, 2));
+
+    let short = Matrix::from_vectors(vec![vector![5, 6]]);
+    match Matrix::stack(&[a, short]) {
+        Err(TensorError::DimensionMismatch {
+            expected, found, ..
+        }) => {
+            assert_eq!(expected, "2x2");
+            assert_eq!(found, "1x2");
+        }
+        other => panic!("Expected DimensionMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn tensor_reshape_and_matrix_round_trip() {
+    let t = tensor![[[1, 2]; [3, 4]; [5, 6]]; [[7, 8]; [9, 10]; [11, 12]]];
+
+    let r = t.reshape(3, 2).unwrap();
+    assert_eq!(r.shape(), (3, 2, 2));
+    assert_eq!(r[1][0], vector![5, 6]);
+    assert_eq!(r.reshape(2, 3).unwrap(), t);
+
+    // One sample per depth
+    let design = t.to_matrix::<6>().unwrap();
+    assert_eq!(design.shape(), (2, 6));
+    assert_eq!(design[1], vector![7, 8, 9, 10, 11, 12]);
+    assert_eq!(design.to_tensor::<2>(2, 3).unwrap(), t);
+
+    let stacked = t.to_matrix::<2>().unwrap();
+    assert_eq!(stacked.shape(), (6, 2));
+
+    assert!(matches!(
+        t.reshape(4, 2),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(t.to_matrix::<5>().is_err());
+    assert!(design.to_tensor::<4>(1, 2).is_err());
+}
+
+#[test]
+fn matrix_reshape_regroups_columns() {
+    let m: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6];
+
+    let wide = m.reshape::<6>().unwrap();
+    assert_eq!(wide, matrix![1, 2, 3, 4, 5, 6]);
+    let narrow = m.reshape::<2>().unwrap();
+    assert_eq!(narrow, matrix![1, 2; 3, 4; 5, 6]);
+    assert_eq!(narrow.reshape::<3>().unwrap(), m);
+    assert_eq!(
+        Matrix::<i32, 3>::new(0).reshape::<5>().unwrap().shape(),
+        (0, 5)
+    );
+
+    assert!(matches!(
+        m.reshape::<4>(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(m.reshape::<0>().is_err());
+}
+
+#[test]
+fn matrix_and_tensor_flatten() {
+    let m: Matrix<i32, 2> = matrix![1, 2; 3, 4; 5, 6];
+    assert_eq!(m.flatten(), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        m.flatten_to_vector::<6>().unwrap(),
+        vector![1, 2, 3, 4, 5, 6]
+    );
+    assert!(matches!(
+        m.flatten_to_vector::<4>(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    let t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    assert_eq!(t.flatten(), (1..=8).collect::<Vec<_>>());
+    assert!(Tensor::<f64, 3>::new(0, 2).flatten().is_empty());
+}
+
+#[test]
+fn vector_and_matrix_slices() {
+    let mut v = vector![1, 2, 3];
+    v.as_mut_slice()[1] = 20;
+    assert_eq!(v.as_slice(), &[1, 20, 3]);
+
+    let mut m: Matrix<i32, 2> = matrix![1, 2; 3, 4];
+    m.as_mut_slice()[3] = 40;
+    assert_eq!(m.as_slice(), &[1, 2, 3, 40]);
+    assert_eq!(m[1][1], 40);
+    assert!(Matrix::<i32, 2>::new(0).as_slice().is_empty());
+}
+
+#[test]
+fn tensor_slice_depths_and_rows() {
+    let t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]; [[9, 10]; [11, 12]]];
+
+    let batch = t.slice_depths(1..).unwrap();
+    assert_eq!(batch, tensor![[[5, 6]; [7, 8]]; [[9, 10]; [11, 12]]]);
+    assert_eq!(t.slice_depths(..=0).unwrap().shape(), (1, 2, 2));
+    assert_eq!(t.slice_depths(2..2).unwrap().shape(), (0, 2, 2));
+
+    let second_rows = t.slice_rows(1..2).unwrap();
+    assert_eq!(second_rows, tensor![[[3, 4]]; [[7, 8]]; [[11, 12]]]);
+
+    assert!(matches!(
+        t.slice_depths(2..4),
+        Err(TensorError::OutOfBounds { .. })
+    ));
+    assert!(t.slice_rows(..3).is_err());
+}
+
+#[test]
+fn tensor_broadcast_matrix_over_depths() {
+    let t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    let bias = Matrix::from_vectors(vec![vector![10, 20], vector![30, 40]]);
+
+    let added = t.add_broadcast(&bias).unwrap();
+    assert_eq!(added, tensor![[[11, 22]; [33, 44]]; [[15, 26]; [37, 48]]]);
+
+    let scaled = t.mul_broadcast(&bias).unwrap();
+    assert_eq!(scaled[(1, 1, 1)], 320);
+
+    let wrong = Matrix::from_vectors(vec![vector![1, 1]]);
+    match t.add_broadcast(&wrong) {
+        Err(TensorError::DimensionMismatch {
+            expected,
+            found,
+            operation,
+        }) => {
+            assert_eq!(expected, "2x2");
+            assert_eq!(found, "1x2");
+            assert_eq!(operation, "Tensor add_broadcast");
+        }
+        other => panic!("Expected DimensionMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn tensor_storage_is_one_strided_buffer() {
+    let mut t = Tensor::<i32, 2>::from_vec(2, 3, (0..12).collect()).unwrap();
+    assert_eq!(t.strides(), (6, 2));
+    assert_eq!(t[(1, 2, 1)], 11);
+    assert_eq!(t.as_slice()[6 + 2 * 2 + 1], 11);
+
+    let depth = t.get(1).unwrap();
+    assert_eq!(depth.shape(), (3, 2));
+    assert_eq!(depth[2], vector![10, 11]);
+    assert_eq!(depth.to_matrix(), matrix![6, 7; 8, 9; 10, 11]);
+    assert!(t.get(2).is_err());
+
+    t.get_mut(0).unwrap()[1] = vector![-1, -1];
+    assert_eq!(
+        t.iter().map(|m| m.to_matrix().sum()).collect::<Vec<_>>(),
+        vec![8, 51]
+    );
+    assert!(Tensor::<i32, 2>::from_vec(2, 3, vec![0; 11]).is_err());
+}
+
+#[test]
+fn tensor_flat_and_indexed_iteration() {
+    let mut t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+
+    assert_eq!(t.iter_flat().sum::<i32>(), 36);
+    let (index, &value) = t.indexed_iter().nth(6).unwrap();
+    assert_eq!(index, (1, 1, 0));
+    assert_eq!(value, t[index]);
+    assert_eq!(t.indexed_iter().count(), 8);
+
+    for x in t.iter_flat_mut() {
+        *x *= 2;
+    }
+    for m in &mut t {
+        m[0][0] = 0;
+    }
+    let depth_sums: Vec<i32> = (&t)
+        .into_iter()
+        .map(|m| (0..2).map(|r| m[r].iter().sum::<i32>()).sum())
+        .collect();
+    assert_eq!(depth_sums, vec![18, 42]);
+
+    let matrices: Vec<Matrix<i32, 2>> = t.into_iter().collect();
+    assert_eq!(matrices.len(), 2);
+}
+
+#[test]
+fn tensor_concat_along_depth_and_rows() {
+    let a = tensor![[[1, 2]; [3, 4]]];
+    let b = tensor![[[5, 6]; [7, 8]]; [[9, 10]; [11, 12]]];
+
+    let depth = a.concat(&b, Axis::Depth).unwrap();
+    assert_eq!(depth.shape(), (3, 2, 2));
+    assert_eq!(depth[2][0], vector![9, 10]);
+
+    let c = tensor![[[0, 0]]];
+    let rows = a.concat(&c, Axis::Row).unwrap();
+    assert_eq!(rows, tensor![[[1, 2]; [3, 4]; [0, 0]]]);
+
+    match a.concat(&b, Axis::Row) {
+        Err(TensorError::DimensionMismatch { operation, .. }) => {
+            assert_eq!(operation, "Tensor concat along Row");
+        }
+        other => panic!("Expected DimensionMismatch, got {:?}", other),
+    }
+    assert!(a.concat(&c, Axis::Depth).is_err());
+}
+
+#[test]
+fn tensor_per_depth_reductions() {
+    let t = tensor![[[1.0, 2.0]; [3.0, 6.0]]; [[-1.0, -5.0]; [0.0, -2.0]]];
+
+    assert_eq!(t.sum_per_depth(), vec![12.0, -8.0]);
+    assert_eq!(t.mean_per_depth().unwrap(), vec![3.0, -2.0]);
+    assert_eq!(t.max_per_depth().unwrap(), vec![6.0, 0.0]);
+    assert_eq!(
+        t.sum_rows_per_depth(),
+        Matrix::from_vectors(vec![vector![4.0, 8.0], vector![-1.0, -7.0]])
+    );
+
+    let empty: Tensor<f64, 2> = Tensor::new(3, 0);
+    assert_eq!(empty.sum_per_depth(), vec![0.0; 3]);
+    assert!(matches!(
+        empty.max_per_depth(),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(empty.mean_per_depth().is_err());
+    // 200 elements per slice cannot be counted in i8
+    assert!(Tensor::<i8, 20>::new(1, 10).mean_per_depth().is_err());
+}
+
+#[test]
+fn swap_and_reorder_rows_and_depths() {
+    let mut m = Matrix::from_vectors(vec![vector![1, 2], vector![3, 4], vector![5, 6]]);
+    m.swap_rows(0, 2).unwrap();
+    assert_eq!(m[0], vector![5, 6]);
+    assert!(m.swap_rows(1, 3).is_err());
+
+    let mut t = tensor![[[1]]; [[2]]; [[3]]; [[4]]];
+    t.swap_depths(0, 3).unwrap();
+    assert_eq!(t, tensor![[[4]]; [[2]]; [[3]]; [[1]]]);
+
+    t.reorder_depths(&[3, 2, 1, 0]).unwrap();
+    assert_eq!(t, tensor![[[1]]; [[3]]; [[2]]; [[4]]]);
+
+    assert!(matches!(
+        t.swap_depths(4, 0),
+        Err(TensorError::OutOfBounds { .. })
+    ));
+    assert!(matches!(
+        t.reorder_depths(&[0, 1, 2]),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        t.reorder_depths(&[0, 1, 1, 2]),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(t.reorder_depths(&[0, 1, 2, 9]).is_err());
+    assert_eq!(t, tensor![[[1]]; [[3]]; [[2]]; [[4]]]);
+}
+
+#[test]
+fn tensor_division_checks_zero_and_shape() {
+    let a = tensor![[[2.0, 9.0]]; [[-4.0, 1.0]]];
+    let b = tensor![[[2.0, 3.0]]; [[0.5, 4.0]]];
+    assert_eq!(
+        a.checked_div(&b).unwrap(),
+        tensor![[[1.0, 3.0]]; [[-8.0, 0.25]]]
+    );
+    assert_eq!((a.clone() / b).unwrap()[(1, 0, 0)], -8.0);
+
+    let zero = tensor![[[1.0, 1.0]]; [[1.0, 0.0]]];
+    assert_eq!(a.checked_div(&zero), Err(TensorError::DivisionByZero));
+    assert!(matches!(
+        a / tensor![[[1.0, 1.0]]],
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn tensor_filled_constructors() {
+    let z: Tensor<i32, 3> = Tensor::zeros(2, 4);
+    assert_eq!(z, Tensor::new(2, 4));
+    let o: Tensor<f64, 2> = Tensor::ones(1, 2);
+    assert!(o.iter_flat().all(|&x| x == 1.0));
+    let f: Tensor<u32, 2> = Tensor::full(3, 1, 7);
+    assert_eq!(f.shape(), (3, 1, 2));
+    assert_eq!(f.sum_per_depth(), vec![14; 3]);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn tensor_random_with_rand_generator() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let t: Tensor<f64, 4> = Tensor::random(2, 3, &mut rng);
+    assert_eq!(t.shape(), (2, 3, 4));
+    assert!(t.iter_flat().all(|&x| (0.0..1.0).contains(&x)));
+
+    let mut again = rand::rngs::StdRng::seed_from_u64(7);
+    assert_eq!(Tensor::random(2, 3, &mut again), t);
+}
+
+#[test]
+fn small_integer_and_index_element_types() {
+    let pixels: Matrix<u8, 3> = Matrix::from_vectors(vec![vector![0, 128, 255]]);
+    assert_eq!(pixels.cast::<f32>()[0], vector![0.0, 128.0, 255.0]);
+    assert_eq!(vector![300.0, -5.0, 12.7].cast::<u8>(), vector![255, 0, 12]);
+
+    let indices: Tensor<usize, 2> = Tensor::full(1, 2, 3);
+    assert_eq!(indices.sum_per_depth(), vec![12]);
+    assert_eq!(vector![-3i16, 4].scalar_mul(2), vector![-6, 8]);
+}
+
+#[test]
+fn integer_vectors_and_matrices_hash_and_sort() {
+    use std::collections::{HashMap, HashSet};
+
+    let points = vec![vector![1, 2], vector![0, 5], vector![1, 2], vector![1, -3]];
+    let unique: HashSet<Vector<i32, 2>> = points.iter().cloned().collect();
+    assert_eq!(unique.len(), 3);
+
+    let mut sorted = points.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted, vec![vector![0, 5], vector![1, -3], vector![1, 2]]);
+    assert!(vector![2u8, 0] > vector![1, 9]);
+    // The element-wise reductions are named apart from `Ord::max`
+    assert_eq!(vector![4, 9, 2].max_element().unwrap(), 9);
+    assert_eq!(Ord::max(vector![1, 9], vector![2, 0]), vector![2, 0]);
+
+    let mut counts: HashMap<Matrix<i64, 2>, usize> = HashMap::new();
+    let m = Matrix::from_vectors(vec![vector![1, 0], vector![0, 1]]);
+    *counts.entry(m.clone()).or_default() += 1;
+    *counts.entry(m).or_default() += 1;
+    assert_eq!(counts.values().copied().collect::<Vec<_>>(), vec![2]);
+
+    let t = tensor![[[1, 2]]; [[3, 4]]];
+    assert!(HashSet::from([t.clone()]).contains(&t));
+}
+
+#[test]
+fn in_place_ops_match_allocating_ops() {
+    let mut v = vector![1, 2, 3];
+    let w = vector![4, 5, 6];
+    v.add_in_place(&w);
+    assert_eq!(v, vector![5, 7, 9]);
+    v.sub_in_place(&w);
+    v.hadamard_in_place(&w);
+    assert_eq!(v, vector![4, 10, 18]);
+    v.scale_in_place(2);
+    v.axpy_in_place(-1, &w);
+    assert_eq!(v, vector![4, 15, 30]);
+
+    let a = Matrix::from_vectors(vec![vector![1.0, 2.0], vector![3.0, 4.0]]);
+    let b = Matrix::from_vectors(vec![vector![0.5, -1.0], vector![2.0, 0.0]]);
+    let mut m = a.clone();
+    m.add_in_place(&b).unwrap();
+    assert_eq!(m, (a.clone() + b.clone()).unwrap());
+    m.hadamard_in_place(&b).unwrap();
+    m.sub_in_place(&a).unwrap();
+    m.scale_in_place(3.0);
+    m.axpy_in_place(2.0, &b).unwrap();
+    assert_eq!(
+        m,
+        Matrix::from_vectors(vec![vector![0.25, -11.0], vector![25.0, -12.0]])
+    );
+    let short = Matrix::from_vectors(vec![vector![1.0, 1.0]]);
+    assert!(matches!(
+        m.add_in_place(&short),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    let t: Tensor<i32, 2> = tensor![[[1, 2]]; [[3, 4]]];
+    let mut u = t.clone();
+    u.add_in_place(&t).unwrap();
+    u.hadamard_in_place(&t).unwrap();
+    u.axpy_in_place(-1, &t).unwrap();
+    u.scale_in_place(2);
+    u.sub_in_place(&t).unwrap();
+    assert_eq!(u, tensor![[[1, 10]]; [[27, 52]]]);
+    assert!(u.add_in_place(&t.slice_depths(..1).unwrap()).is_err());
+}
+
+#[test]
+fn matrix_add_sub_by_reference() {
+    let a = Matrix::from_vectors(vec![vector![1, 2], vector![3, 4]]);
+    let b = Matrix::from_vectors(vec![vector![10, 20], vector![30, 40]]);
+    let sum = Matrix::from_vectors(vec![vector![11, 22], vector![33, 44]]);
+
+    assert_eq!((&a + &b).unwrap(), sum);
+    assert_eq!((&a + b.clone()).unwrap(), sum);
+    assert_eq!((a.clone() + &b).unwrap(), sum);
+    assert_eq!((&sum - &b).unwrap(), a);
+    assert_eq!((&sum - a.clone()).unwrap(), b);
+
+    let short = Matrix::from_vectors(vec![vector![1, 2]]);
+    assert!(matches!(
+        &a - &short,
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_iterators_cover_every_element_in_order() {
+    use rayon::prelude::*;
+
+    let mut m = Matrix::from_vectors(vec![vector![1, 2, 3], vector![4, 5, 6]]);
+    let row_sums: Vec<i32> = m.par_iter_rows().map(|r| r.iter().sum()).collect();
+    assert_eq!(row_sums, vec![6, 15]);
+    m.par_iter_flat_mut().for_each(|x| *x *= 10);
+    m.par_iter_rows_mut().for_each(|r| r[0] = 0);
+    let flat: Vec<i32> = m.par_iter_flat().copied().collect();
+    assert_eq!(flat, vec![0, 20, 30, 0, 50, 60]);
+
+    let mut t: Tensor<i32, 2> = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    t.par_iter_depths_mut()
+        .enumerate()
+        .for_each(|(d, rows)| rows.iter_mut().for_each(|r| r.scale_in_place(d as i32 + 1)));
+    t.par_iter_flat_mut().for_each(|x| *x += 1);
+    let flat: Vec<i32> = t.par_iter_flat().copied().collect();
+    assert_eq!(flat, vec![2, 3, 4, 5, 11, 13, 15, 17]);
+    assert_eq!(t.par_iter_depths().count(), 2);
+}
+
+#[test]
+fn test_matrix_from_fn() {
+    let m: Matrix<i32, 3> = Matrix::from_fn(2, |i, j| (10 * i + j) as i32);
+    assert_eq!(m, matrix![0, 1, 2; 10, 11, 12]);
+    assert_eq!(Matrix::<f64, 4>::from_fn(0, |_, _| 1.0).shape(), (0, 4));
+    assert_eq!(Matrix::<f64, 0>::from_fn(3, |_, _| 1.0).shape(), (3, 0));
+
+    // A panicking generator leaves nothing half-built behind
+    let result = std::panic::catch_unwind(|| {
+        Matrix::<f64, 2>::from_fn(3, |i, _| if i == 2 { panic!("stop") } else { 1.0 })
+    });
+    assert!(result.is_err());
+}
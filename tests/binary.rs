@@ -0,0 +1,73 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::io::binary::{read_binary, write_binary, Endian};
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor, Vector};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
+
+#[test]
+fn binary_round_trips_in_either_byte_order() {
+    let m: Matrix<f64, 3> = matrix![1.0, -2.5, 3.0; 4.0, 5.0, 6.25];
+    let mut little = Vec::new();
+    write_binary(&mut little, &m, Endian::Little).unwrap();
+    let mut big = Vec::new();
+    write_binary(&mut big, &m, Endian::Big).unwrap();
+    assert_eq!(&little[..13], b"TALGBIN\0\x01\x00f8\x02");
+    assert_eq!(&big[8..10], b"\x01\x01");
+    assert_eq!(little.len(), 13 + 2 * 8 + 6 * 8);
+    assert_eq!(&little[13..21], &2u64.to_le_bytes());
+    assert_eq!(&big[13..21], &2u64.to_be_bytes());
+    assert_eq!(&big[29..37], &1.0f64.to_be_bytes());
+    for bytes in [little, big] {
+        assert_eq!(
+            read_binary::<Matrix<f64, 3>, _>(&mut bytes.as_slice()).unwrap(),
+            m
+        );
+    }
+
+    let t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    let mut bytes = Vec::new();
+    write_binary(&mut bytes, &t, Endian::Native).unwrap();
+    assert_eq!(
+        read_binary::<Tensor<i32, 2>, _>(&mut bytes.as_slice()).unwrap(),
+        t
+    );
+
+    let empty = Tensor::<i32, 2>::new(0, 3);
+    let mut bytes = Vec::new();
+    write_binary(&mut bytes, &empty, Endian::Little).unwrap();
+    let back = read_binary::<Tensor<i32, 2>, _>(&mut bytes.as_slice()).unwrap();
+    assert_eq!(back.shape(), (0, 3, 2));
+
+    let v = vector![1.5f32, 2.5];
+    let mut bytes = Vec::new();
+    write_binary(&mut bytes, &v, Endian::Big).unwrap();
+    assert_eq!(
+        read_binary::<Vector<f32, 2>, _>(&mut bytes.as_slice()).unwrap(),
+        v
+    );
+}
+
+#[test]
+fn binary_checks_header_dtype_and_shape() {
+    let m: Matrix<i64, 2> = matrix![1, 2; 3, 4];
+    let mut bytes = Vec::new();
+    write_binary(&mut bytes, &m, Endian::Little).unwrap();
+
+    assert!(matches!(
+        read_binary::<Matrix<i32, 2>, _>(&mut bytes.as_slice()),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        read_binary::<Matrix<i64, 4>, _>(&mut bytes.as_slice()),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(read_binary::<Tensor<i64, 2>, _>(&mut bytes.as_slice()).is_err());
+    assert!(read_binary::<Matrix<i64, 2>, _>(&mut &bytes[..bytes.len() - 1]).is_err());
+
+    let mut future = bytes.clone();
+    future[8] = 2;
+    assert!(matches!(
+        read_binary::<Matrix<i64, 2>, _>(&mut future.as_slice()),
+        Err(TensorError::Other(_))
+    ));
+    assert!(read_binary::<Matrix<i64, 2>, _>(&mut &b"not a tensor file"[..]).is_err());
+}
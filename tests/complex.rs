@@ -0,0 +1,40 @@
+#![cfg(feature = "complex")]
+
+use tensor_algebra_in_rust::complex::Complex;
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+use tensor_algebra_in_rust::{matrix, vector};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn hermitian_dot_conjugates_the_left_operand() {
+    let v: Vector<Complex<f64>, 2> = vector![c(1.0, 2.0), c(0.0, -1.0)];
+    let w: Vector<Complex<f64>, 2> = vector![c(3.0, 0.0), c(1.0, 1.0)];
+
+    // (1 - 2i) * 3 + (0 + i) * (1 + i) = 2 - 5i
+    assert_eq!(v.hermitian_dot(&w), c(2.0, -5.0));
+    // The bilinear dot does not conjugate: (1 + 2i) * 3 + (-i) * (1 + i) = 4 + 5i
+    assert_eq!(v.dot(&w), c(4.0, 5.0));
+    assert_eq!(v.hermitian_dot(&v), c(6.0, 0.0));
+}
+
+#[test]
+fn conj_transpose_and_matmul() {
+    let a: Matrix<Complex<f64>, 2> = matrix![c(1.0, 1.0), c(2.0, 0.0); c(0.0, -3.0), c(4.0, 2.0)];
+    let ah: Matrix<Complex<f64>, 2> = a.conj_transpose().unwrap();
+    assert_eq!(
+        ah,
+        matrix![c(1.0, -1.0), c(0.0, 3.0); c(2.0, 0.0), c(4.0, -2.0)]
+    );
+
+    // A^H A is Hermitian with a real diagonal
+    let gram = (&ah * &a).unwrap();
+    assert_eq!(gram[0][0], c(11.0, 0.0));
+    assert_eq!(gram[1][1], c(24.0, 0.0));
+    assert_eq!(gram[0][1], gram[1][0].conj());
+
+    let f32s: Matrix<Complex<f32>, 1> = matrix![Complex::new(1.0f32, 1.0)];
+    assert!(f32s.conj_transpose::<2>().is_err());
+}
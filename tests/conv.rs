@@ -0,0 +1,124 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
+
+#[test]
+fn conv2d_valid_strided_and_padded() {
+    let x: Matrix<i32, 4> = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12
+    ];
+    let k: Matrix<i32, 2> = matrix![1, 0; 0, -1];
+
+    let valid: Matrix<i32, 3> = x.conv2d(&k, 1, 0).unwrap();
+    assert_eq!(valid, matrix![-5, -5, -5; -5, -5, -5]);
+
+    let strided: Matrix<i32, 2> = x.conv2d(&k, 2, 0).unwrap();
+    assert_eq!(strided, matrix![-5, -5]);
+
+    // Padding 1 with stride 2: windows start at -1, 1, 3
+    let padded: Matrix<i32, 3> = x.conv2d(&k, 2, 1).unwrap();
+    assert_eq!(padded, matrix![-1, -3, 0; -9, -5, 8]);
+
+    let box3: Matrix<i32, 3> = matrix![1, 1, 1; 1, 1, 1; 1, 1, 1];
+    let same: Matrix<i32, 4> = x.conv2d(&box3, 1, 1).unwrap();
+    assert_eq!((same[0][0], same[1][1]), (14, 54));
+}
+
+#[test]
+fn conv2d_batched_and_errors() {
+    let t: Tensor<f64, 3> = tensor![[[1.0, 2.0, 3.0]]; [[4.0, 5.0, 6.0]]];
+    let k: Matrix<f64, 2> = matrix![0.5, 0.5];
+    let out: Tensor<f64, 2> = t.conv2d(&k, 1, 0).unwrap();
+    assert_eq!(out, tensor![[[1.5, 2.5]]; [[4.5, 5.5]]]);
+
+    assert!(matches!(
+        t.conv2d::<2, 3>(&k, 1, 0),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    let first = t.get(0).unwrap().to_matrix();
+    assert!(matches!(
+        first.conv2d::<2, 2>(&k, 0, 0),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    let tall: Matrix<f64, 2> = matrix![1.0, 1.0; 1.0, 1.0];
+    assert!(first.conv2d::<2, 2>(&tall, 1, 0).is_err());
+}
+
+#[test]
+fn max_and_avg_pooling() {
+    let x: Matrix<f64, 4> = matrix![
+        1.0, 2.0, 5.0, 6.0;
+        3.0, 4.0, 7.0, 8.0;
+        -1.0, -2.0, 0.0, 0.5;
+        -3.0, -4.0, 0.5, 1.0
+    ];
+    let max: Matrix<f64, 2> = x.max_pool(2, 2).unwrap();
+    assert_eq!(max, matrix![4.0, 8.0; -1.0, 1.0]);
+    let avg: Matrix<f64, 2> = x.avg_pool(2, 2).unwrap();
+    assert_eq!(avg, matrix![2.5, 6.5; -2.5, 0.5]);
+
+    let overlapping: Matrix<f64, 3> = x.max_pool(2, 1).unwrap();
+    assert_eq!(overlapping.shape(), (3, 3));
+    assert_eq!(overlapping[1], vector![4.0, 7.0, 8.0]);
+
+    let t: Tensor<i32, 2> = tensor![[[1, 2]; [3, 4]]; [[5, 9]; [7, 8]]];
+    let pooled: Tensor<i32, 1> = t.max_pool(2, 2).unwrap();
+    assert_eq!(pooled, tensor![[[4]]; [[9]]]);
+    let mean: Tensor<i32, 1> = t.avg_pool(2, 2).unwrap();
+    assert_eq!(mean, tensor![[[2]]; [[7]]]);
+
+    assert!(x.max_pool::<3>(2, 2).is_err());
+    assert!(x.avg_pool::<1>(5, 1).is_err());
+
+    // An empty batch is checked like a full one
+    let empty: Tensor<f64, 4> = Tensor::new(0, 4);
+    assert!(empty.max_pool::<3>(2, 2).is_err());
+    assert!(empty.avg_pool::<3>(2, 2).is_err());
+    assert_eq!(empty.max_pool::<2>(2, 2).unwrap().shape(), (0, 2, 2));
+
+    // A 12x12 window holds more elements than i8 can count
+    let bytes = Matrix::<i8, 12>::new(12);
+    assert!(matches!(
+        bytes.avg_pool::<1>(12, 1),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(Tensor::<i8, 12>::new(0, 12).avg_pool::<1>(12, 1).is_err());
+}
+
+#[test]
+fn im2col_matmul_matches_conv2d_and_col2im_folds_back() {
+    let t: Tensor<f64, 4> = tensor![
+        [[1.0, 2.0, 3.0, 4.0]; [5.0, 6.0, 7.0, 8.0]; [9.0, 10.0, 11.0, 12.0]];
+        [[0.5, 0.0, -1.0, 2.0]; [1.0, 1.0, 1.0, 1.0]; [3.0, 2.0, 1.0, 0.0]]
+    ];
+    let k0: Matrix<f64, 2> = matrix![1.0, -1.0; 2.0, 0.0];
+    let k1: Matrix<f64, 2> = matrix![0.0, 1.0; 1.0, 3.0];
+
+    // Stride 1, padding 1 gives a 4x5 output grid
+    let cols: Matrix<f64, 8> = t.im2col((2, 2), 1, 1).unwrap();
+    assert_eq!(cols.shape(), (20, 8));
+
+    let filter: Matrix<f64, 1> = matrix![1.0; -1.0; 2.0; 0.0; 0.0; 1.0; 1.0; 3.0];
+    let via_matmul = (&cols * &filter).unwrap();
+
+    let (d0, d1) = (t.get(0).unwrap().to_matrix(), t.get(1).unwrap().to_matrix());
+    let expected: Matrix<f64, 5> =
+        (d0.conv2d(&k0, 1, 1).unwrap() + d1.conv2d(&k1, 1, 1).unwrap()).unwrap();
+    for p in 0..20 {
+        assert!((via_matmul[p][0] - expected[p / 5][p % 5]).abs() < 1e-12);
+    }
+
+    // Non-overlapping patches fold back exactly
+    let patches: Matrix<f64, 8> = t.slice_rows(..2).unwrap().im2col((2, 2), 2, 0).unwrap();
+    let folded: Tensor<f64, 4> = patches.col2im((2, 2), (2, 2), 2, 0).unwrap();
+    assert_eq!(folded, t.slice_rows(..2).unwrap());
+
+    // Overlapping patches are summed: interior elements appear in four patches
+    let overlapped: Tensor<f64, 4> = cols.col2im((2, 3), (2, 2), 1, 1).unwrap();
+    assert_eq!(overlapped[(0, 1, 1)], 4.0 * t[(0, 1, 1)]);
+
+    assert!(t.im2col::<4>((2, 2), 1, 0).is_err());
+    assert!(cols.col2im::<4>((2, 2), (2, 2), 1, 1).is_err());
+}
@@ -0,0 +1,61 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::io::csv::CsvOptions;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn csv_round_trips() {
+    let m: Matrix<f64, 3> = matrix![1.0, 2.5, -3.0; 0.1, 1e-12, 6.0];
+    let mut out = Vec::new();
+    m.to_csv(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        "1,2.5,-3\n0.1,0.000000000001,6\n"
+    );
+    assert_eq!(
+        Matrix::<f64, 3>::from_csv(out.as_slice(), CsvOptions::default()).unwrap(),
+        m
+    );
+
+    let options = CsvOptions {
+        delimiter: ';',
+        has_header: true,
+    };
+    let ints: Matrix<i32, 2> = matrix![1, -2; 3, 4];
+    let mut out = Vec::new();
+    ints.to_csv_with(&mut out, options, Some(&["x", "y"]))
+        .unwrap();
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), "x;y\n1;-2\n3;4\n");
+    assert_eq!(
+        Matrix::<i32, 2>::from_csv(out.as_slice(), options).unwrap(),
+        ints
+    );
+}
+
+#[test]
+fn csv_reads_loose_input_and_reports_bad_rows() {
+    let text = "\"a\", \"b\"\r\n 1 , \"2\"\r\n\r\n3,4\r\n";
+    let options = CsvOptions {
+        has_header: true,
+        ..CsvOptions::default()
+    };
+    let m = Matrix::<i64, 2>::from_csv(text.as_bytes(), options).unwrap();
+    assert_eq!(m, matrix![1i64, 2; 3, 4]);
+
+    let err = Matrix::<i64, 2>::from_csv("1,2\n3,4,5\n".as_bytes(), CsvOptions::default());
+    match err {
+        Err(TensorError::DimensionMismatch {
+            expected,
+            found,
+            operation,
+        }) => {
+            assert_eq!((expected.as_str(), found.as_str()), ("1x2", "1x3"));
+            assert!(operation.contains("line 2"));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(
+        Matrix::<f32, 2>::from_csv("1,x\n".as_bytes(), CsvOptions::default()),
+        Err(TensorError::Other(_))
+    ));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "decimal")]
+
+use rust_decimal::Decimal;
+use tensor_algebra_in_rust::tensor::{AllowedFloatTypes, AllowedNumericTypes, Matrix, Vector};
+use tensor_algebra_in_rust::{impl_algebra_tests, matrix, vector};
+
+impl_algebra_tests!(decimal_laws, Decimal);
+
+fn d(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn decimal_sums_and_scaling_are_exact() {
+    // 0.1 + 0.2 is exactly 0.3 in base 10
+    let prices: Vector<Decimal, 3> = vector![d("0.10"), d("0.20"), d("19.99")];
+    let total = prices.iter().fold(Decimal::zero(), |acc, &x| acc + x);
+    assert_eq!(total, d("20.29"));
+    assert_eq!(
+        prices.scalar_mul(d("3")),
+        vector![d("0.30"), d("0.60"), d("59.97")]
+    );
+
+    assert_eq!(Decimal::from_f64(0.25), d("0.25"));
+    assert_eq!(Decimal::from_f64(f64::NAN), Decimal::ZERO);
+    assert_eq!(Decimal::from_f64(1e40), Decimal::MAX);
+    assert_eq!(d("-4.5").abs(), d("4.5"));
+    assert_eq!(AllowedFloatTypes::sqrt(d("2.25")), d("1.5"));
+}
+
+#[test]
+fn decimal_solve_and_determinant() {
+    let a: Matrix<Decimal, 2> = matrix![d("2"), d("1"); d("1"), d("3")];
+    assert_eq!(a.determinant().unwrap(), d("5"));
+    let x = a.solve(&vector![d("3"), d("5")]).unwrap();
+    assert_eq!(x, vector![d("0.8"), d("1.4")]);
+}
+
+#[test]
+fn decimal_activations_saturate_instead_of_overflowing() {
+    assert_eq!(AllowedFloatTypes::exp(d("1000")), Decimal::MAX);
+    assert_eq!(AllowedFloatTypes::exp(d("-1000")), Decimal::ZERO);
+    assert_eq!(AllowedFloatTypes::exp(Decimal::ZERO), Decimal::ONE);
+
+    let x: Vector<Decimal, 4> = vector![d("-1000"), d("-100"), d("100"), d("1000")];
+    let s = x.sigmoid();
+    assert!(s[0] >= Decimal::ZERO && s[0] < d("1e-20"));
+    assert!(s[1] >= Decimal::ZERO && s[1] < d("1e-20"));
+    assert!(s[2] > d("0.99999999999999999999") && s[2] <= Decimal::ONE);
+    assert!(s[3] > d("0.99999999999999999999") && s[3] <= Decimal::ONE);
+    assert_eq!(vector![Decimal::ZERO].sigmoid(), vector![d("0.5")]);
+    assert_eq!(x.tanh()[3], Decimal::ONE);
+}
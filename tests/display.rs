@@ -0,0 +1,54 @@
+use tensor_algebra_in_rust::display::{print_options, set_print_options, PrintOptions};
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor, Vector};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
+
+#[test]
+fn small_values_print_in_full_and_aligned() {
+    assert_eq!(vector![1, 2, 3].to_string(), "[1, 2, 3]");
+    let m: Matrix<i32, 2> = matrix![1, -20; 300, 4];
+    assert_eq!(m.to_string(), "[[  1, -20],\n [300,   4]]");
+
+    let t: Tensor<f64, 2> = tensor![[[0.5, 1.0]]; [[2.0, 3.25]]];
+    let opts = PrintOptions {
+        precision: Some(2),
+        ..PrintOptions::default()
+    };
+    assert_eq!(
+        t.display_with(opts).to_string(),
+        "[[[0.50, 1.00]],\n\n [[2.00, 3.25]]]"
+    );
+}
+
+#[test]
+fn large_values_are_summarized_with_edge_items() {
+    let opts = PrintOptions {
+        max_rows: 4,
+        max_cols: 4,
+        edge_items: 1,
+        precision: None,
+    };
+    let v = Vector::<i32, 6>::from([1, 2, 3, 4, 5, 6]);
+    assert_eq!(v.display_with(opts).to_string(), "[  1, ...,   6]");
+
+    let m = Matrix::<i32, 5>::from_vectors(
+        (0..5)
+            .map(|r| Vector::from(std::array::from_fn(|c| (r * 5 + c) as i32)))
+            .collect(),
+    );
+    assert_eq!(
+        m.display_with(opts).to_string(),
+        "[[  0, ...,   4],\n ...,\n [ 20, ...,  24]]"
+    );
+}
+
+#[test]
+fn global_options_drive_display() {
+    let before = print_options();
+    set_print_options(PrintOptions {
+        precision: Some(1),
+        ..before
+    });
+    assert_eq!(vector![0.25f32, 2.0].to_string(), "[0.2, 2.0]");
+    set_print_options(before);
+    assert_eq!(print_options(), before);
+}
@@ -0,0 +1,76 @@
+use tensor_algebra_in_rust::distributions::{
+    Bernoulli, Dirichlet, Exponential, Normal, Rng, Uniform,
+};
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
+
+fn mean<const N: usize>(t: &Tensor<f64, N>) -> f64 {
+    let (depths, rows, cols) = t.shape();
+    let mut sum = 0.0;
+    for d in 0..depths {
+        for r in 0..rows {
+            sum += t[d][r].iter().sum::<f64>();
+        }
+    }
+    sum / (depths * rows * cols) as f64
+}
+
+#[test]
+fn random_from_is_reproducible_per_seed() {
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let a = Tensor::<f64, 4>::random_from(&dist, (3, 5), 42).unwrap();
+    let b = Tensor::<f64, 4>::random_from(&dist, (3, 5), 42).unwrap();
+    let c = Tensor::<f64, 4>::random_from(&dist, (3, 5), 43).unwrap();
+    assert_eq!(a.shape(), (3, 5, 4));
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let mut r1 = Rng::seed_from_u64(7);
+    let mut r2 = Rng::seed_from_u64(7);
+    assert_eq!(r1.next_u64(), r2.next_u64());
+}
+
+#[test]
+fn univariate_sample_statistics() {
+    let shape = (10, 100);
+    let u = Tensor::<f64, 10>::random_from(&Uniform::new(2.0, 4.0).unwrap(), shape, 1).unwrap();
+    assert!((mean(&u) - 3.0).abs() < 0.05);
+
+    let n = Tensor::<f64, 10>::random_from(&Normal::new(5.0, 2.0).unwrap(), shape, 2).unwrap();
+    assert!((mean(&n) - 5.0).abs() < 0.1);
+
+    let e = Tensor::<f64, 10>::random_from(&Exponential::new(4.0).unwrap(), shape, 3).unwrap();
+    assert!((mean(&e) - 0.25).abs() < 0.02);
+
+    let b = Tensor::<f32, 10>::random_from(&Bernoulli::new(0.3).unwrap(), shape, 4).unwrap();
+    for d in 0..10 {
+        for r in 0..100 {
+            assert!(b[d][r].iter().all(|&x| x == 0.0 || x == 1.0));
+        }
+    }
+}
+
+#[test]
+fn dirichlet_rows_lie_on_simplex() {
+    let dist = Dirichlet::new(vec![0.5, 1.0, 2.0]).unwrap();
+    let m = Matrix::<f64, 3>::random_from(&dist, 50, 9).unwrap();
+    for i in 0..50 {
+        assert!(m[i].iter().all(|&x| x > 0.0));
+        assert!((m[i].iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    let wrong = Matrix::<f64, 2>::random_from(&dist, 1, 9).unwrap_err();
+    match wrong {
+        TensorError::DimensionMismatch { .. } => {}
+        other => panic!("Expected DimensionMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    assert!(Uniform::new(1.0, 1.0).is_err());
+    assert!(Normal::new(0.0, -1.0).is_err());
+    assert!(Bernoulli::new(1.5).is_err());
+    assert!(Exponential::new(0.0).is_err());
+    assert!(Dirichlet::symmetric(1.0, 0).is_err());
+}
@@ -0,0 +1,68 @@
+use tensor_algebra_in_rust::dual::{derivative, gradient, Dual};
+use tensor_algebra_in_rust::linalg::Norm;
+use tensor_algebra_in_rust::tensor::{AllowedFloatTypes, Matrix, Vector};
+use tensor_algebra_in_rust::{impl_algebra_tests, matrix, vector};
+
+impl_algebra_tests!(dual_laws, Dual<f64>);
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-10, "expected {}, got {}", b, a);
+}
+
+#[test]
+fn derivative_of_scalar_expressions() {
+    // d/dx (x^3 / (1 + x)) at x = 2 is (3x^2 (1 + x) - x^3) / (1 + x)^2 = 28 / 9
+    let (y, dy) = derivative(|x| x * x * x / (Dual::constant(1.0) + x), 2.0);
+    assert_close(y, 8.0 / 3.0);
+    assert_close(dy, 28.0 / 9.0);
+
+    let (_, d_exp) = derivative(|x: Dual<f64>| x.sqrt().exp(), 4.0);
+    assert_close(d_exp, 2f64.exp() / 4.0);
+    let (_, d_abs) = derivative(|x: Dual<f64>| x.abs(), -3.0);
+    assert_close(d_abs, -1.0);
+}
+
+#[test]
+fn gradient_flows_through_vector_and_matrix_ops() {
+    let x = vector![1.0, -2.0, 0.5];
+
+    let (value, grad) = gradient(|v| v.dot(v), &x);
+    assert_close(value, 5.25);
+    assert_eq!(grad, vector![2.0, -4.0, 1.0]);
+
+    let (norm, grad) = gradient(|v| v.norm(Norm::L2), &x);
+    assert_close(norm, 5.25f64.sqrt());
+    for i in 0..3 {
+        assert_close(grad[i], x[i] / norm);
+    }
+
+    // f(x) = sum(tanh(A x)), so grad = A^T (1 - tanh(A x)^2)
+    let a: Matrix<f64, 3> = matrix![1.0, 0.0, 2.0; -1.0, 3.0, 0.5];
+    let a_dual: Matrix<Dual<f64>, 3> = a.map(Dual::constant);
+    let (_, grad) = gradient(
+        |v: &Vector<Dual<f64>, 3>| {
+            let column = Matrix::from_vectors(v.iter().map(|&x| vector![x]).collect());
+            let ax = (&a_dual * &column).unwrap();
+            (0..2).fold(Dual::constant(0.0), |acc, r| acc + ax[r][0].tanh())
+        },
+        &x,
+    );
+    let ax = [1.0 * 1.0 + 2.0 * 0.5, -1.0 - 6.0 + 0.25];
+    let sech2 = ax.map(|z: f64| 1.0 - z.tanh() * z.tanh());
+    for j in 0..3 {
+        assert_close(grad[j], a[0][j] * sech2[0] + a[1][j] * sech2[1]);
+    }
+}
+
+#[test]
+fn equality_and_ordering_both_compare_values() {
+    use std::cmp::Ordering;
+    use tensor_algebra_in_rust::tensor::AllowedNumericTypes;
+
+    let (a, b) = (Dual::new(1.0, 2.0), Dual::new(1.0, 5.0));
+    assert_eq!(a, b);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+    assert!(Dual::new(1.0, 9.0) < Dual::new(2.0, 0.0));
+    assert_ne!(Dual::new(1.0, 0.0), Dual::new(2.0, 0.0));
+    assert!(Dual::new(0.0, 3.0).is_zero());
+}
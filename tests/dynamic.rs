@@ -0,0 +1,57 @@
+use tensor_algebra_in_rust::dynamic::{DynMatrix, DynVector};
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+
+#[test]
+fn dyn_matrix_operations() {
+    let a = DynMatrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    let b = DynMatrix::from_vec(vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0], 3, 2).unwrap();
+    assert_eq!(a.shape(), (2, 3));
+    assert_eq!(a[(1, 2)], 6.0);
+    assert_eq!(a.get(2, 0), None);
+    assert_eq!(a.row(1), Some(&[4.0, 5.0, 6.0][..]));
+
+    let product = (&a * &b).unwrap();
+    assert_eq!(product.as_slice(), &[4.0, 5.0, 10.0, 11.0]);
+    assert_eq!(a.transpose().shape(), (3, 2));
+    assert_eq!(
+        (&a.transpose() - &b).unwrap().as_slice(),
+        &[0.0, 4.0, 2.0, 4.0, 2.0, 5.0]
+    );
+    assert_eq!((&a + &a).unwrap(), a.scalar_mul(2.0));
+    assert_eq!(a.hadamard_product(&a).unwrap().sum(), 91.0);
+    assert_eq!((&DynMatrix::identity(2) * &product).unwrap(), product);
+
+    let v = DynVector::from_vec(vec![1.0, 1.0, 1.0]);
+    assert_eq!(a.mat_vec_mul(&v).unwrap().as_slice(), &[6.0, 15.0]);
+    assert_eq!(v.dot(&v).unwrap(), 3.0);
+    assert_eq!((&v - &v).unwrap(), DynVector::zeros(3));
+
+    assert!(matches!(
+        &a * &a,
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        &a + &b,
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(DynMatrix::from_rows(&[vec![1.0], vec![1.0, 2.0]]).is_err());
+    assert!(DynMatrix::<f64>::from_vec(vec![1.0; 5], 2, 3).is_err());
+    assert_eq!(v.scalar_div(0.0), Err(TensorError::DivisionByZero));
+}
+
+#[test]
+fn dyn_types_convert_to_fixed_width() {
+    let m: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6];
+    let d = DynMatrix::from(&m);
+    assert_eq!(d.shape(), (2, 3));
+    assert_eq!(Matrix::<i32, 3>::try_from(&d), Ok(m));
+    assert!(Matrix::<i32, 2>::try_from(&d).is_err());
+
+    let v = Vector::from([1, 2]);
+    let dv = DynVector::from(&v);
+    assert_eq!(dv.len(), 2);
+    assert_eq!(Vector::<i32, 2>::try_from(&dv), Ok(v));
+    assert!(dv.to_vector::<3>().is_err());
+}
@@ -0,0 +1,43 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn fused_expression_matches_eager_evaluation() {
+    let a: Matrix<f64, 3> = matrix![1.0, -2.0, 3.0; 0.5, 4.0, -1.0];
+    let b: Matrix<f64, 3> = matrix![2.0, 2.0, 0.0; 1.0, -1.0, 3.0];
+    let c: Matrix<f64, 3> = matrix![0.0, 1.0, 5.0; 2.0, 2.0, 2.0];
+
+    let eager = ((a.scalar_mul(2.0) + b.hadamard_product(&c).unwrap()).unwrap() - c.clone())
+        .unwrap()
+        .map(|x: f64| x.abs());
+    let fused = (a.lazy().scalar_mul(2.0) + b.lazy().hadamard(c.lazy()) - c.lazy())
+        .map(f64::abs)
+        .eval()
+        .unwrap();
+    assert_eq!(fused, eager);
+
+    let m: Matrix<i32, 2> = matrix![1, 5; -3, 4];
+    let n: Matrix<i32, 2> = matrix![2, 2; 7, -4];
+    let max = m.lazy().zip_with(n.lazy(), |x, y| x.max(y)).eval().unwrap();
+    assert_eq!(max, matrix![2, 5; 7, 4]);
+}
+
+#[test]
+fn shape_mismatch_is_reported_on_eval() {
+    let a: Matrix<i32, 2> = matrix![1, 2; 3, 4];
+    let b: Matrix<i32, 2> = matrix![1, 2];
+    let expr = a.lazy() + a.lazy().hadamard(b.lazy());
+    assert_eq!(
+        expr.eval(),
+        Err(TensorError::DimensionMismatch {
+            expected: "2x2".to_string(),
+            found: "1x2".to_string(),
+            operation: "Matrix hadamard product".to_string(),
+        })
+    );
+    assert_eq!(
+        Matrix::<i32, 2>::new(0).lazy().eval().unwrap().shape(),
+        (0, 2)
+    );
+}
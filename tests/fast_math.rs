@@ -0,0 +1,42 @@
+#![cfg(feature = "fast-math")]
+
+use tensor_algebra_in_rust::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+#[test]
+fn unrolled_kernels_match_scalar_results() {
+    // Integer-valued floats keep every partial sum exact in any order
+    let a: Vec<f64> = (0..23).map(|i| (i % 7) as f64 - 3.0).collect();
+    let b: Vec<f64> = (0..23).map(|i| (i % 5) as f64 * 0.5).collect();
+    let expected: f64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+    assert_eq!(f64::dot_slices(&a, &b), expected);
+
+    let a32: Vec<f32> = a.iter().map(|&x| x as f32).collect();
+    let b32: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+    assert_eq!(f32::dot_slices(&a32, &b32), expected as f32);
+    assert_eq!(f32::dot_slices(&a32[..3], &b32[..3]), -2.0);
+
+    let mut acc = a.clone();
+    f64::axpy_slices(&mut acc, 2.0, &b);
+    for ((&r, &x), &y) in acc.iter().zip(&a).zip(&b) {
+        assert_eq!(r, x + 2.0 * y);
+    }
+
+    let v = Vector::<f64, 9>::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(v.dot(&v), 285.0);
+
+    let mut m = Matrix::<f64, 8>::new(6);
+    for i in 0..6 {
+        for j in 0..8 {
+            m[i][j] = (i * j % 5) as f64;
+        }
+    }
+    let gram: Matrix<f64, 6> = m.gram().unwrap();
+    assert_eq!(gram[2][3], m[2].dot(&m[3]));
+
+    // Products accumulate without Kahan compensation, so the ones are
+    // absorbed by 1e16 as in a naive sum
+    let a = Matrix::<f64, 8>::from_fn(1, |_, j| [1e16, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1e16][j]);
+    let b = Matrix::<f64, 9>::from_fn(8, |_, _| 1.0);
+    let c = (&a * &b).unwrap();
+    assert!(c[0].iter().all(|&x| x == 0.0), "got {:?}", c[0]);
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "fixed")]
+
+use fixed::types::{I16F16, I8F8};
+use tensor_algebra_in_rust::linalg::Norm;
+use tensor_algebra_in_rust::tensor::{AllowedFloatTypes, AllowedNumericTypes, Matrix, Vector};
+use tensor_algebra_in_rust::{impl_algebra_tests, matrix, vector};
+
+impl_algebra_tests!(i16f16_laws, I16F16, tolerance = 1e-4);
+
+fn fx(x: f64) -> I16F16 {
+    I16F16::from_f64(x)
+}
+
+#[test]
+fn fixed_conversions_saturate_and_abs_is_exact() {
+    assert_eq!(fx(1.5).to_f64(), 1.5);
+    assert_eq!(I8F8::from_f64(1e9), I8F8::MAX);
+    assert_eq!(I8F8::from_f64(-1e9), I8F8::MIN);
+    assert_eq!(I8F8::from_f64(f64::NAN), I8F8::ZERO);
+    assert_eq!(fx(-2.25).abs(), fx(2.25));
+    assert_eq!(I16F16::epsilon(), I16F16::DELTA);
+}
+
+#[test]
+fn fixed_matrices_multiply_deterministically() {
+    let a: Matrix<I16F16, 2> = matrix![fx(0.5), fx(-1.0); fx(2.0), fx(0.25)];
+    let product = (&a * &a).unwrap();
+    assert_eq!(product, matrix![fx(-1.75), fx(-0.75); fx(1.5), fx(-1.9375)]);
+
+    let v: Vector<I16F16, 2> = vector![fx(3.0), fx(4.0)];
+    assert_eq!(v.norm(Norm::L2), fx(5.0));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::{AllowedFloatTypes, AllowedNumericTypes, Matrix};
+
+#[test]
+fn half_types_convert_and_multiply() {
+    assert_eq!(f16::from_f64(1.5).to_f64(), 1.5);
+    assert_eq!(bf16::from_f64(-2.0).abs(), bf16::from_f64(2.0));
+    assert!(f16::zero().is_zero());
+    assert_eq!(
+        AllowedFloatTypes::sqrt(f16::from_f64(9.0)),
+        f16::from_f64(3.0)
+    );
+
+    let a: Matrix<f64, 2> = matrix![1.0, 2.0; 3.0, 4.0];
+    let weights: Matrix<f16, 2> = a.map(f16::from_f64);
+    let product = (&weights * &weights).unwrap();
+    assert_eq!(product.map(f16::to_f64), matrix![7.0, 10.0; 15.0, 22.0]);
+
+    let bf: Matrix<bf16, 2> = a.map(bf16::from_f64);
+    assert_eq!(
+        (bf.clone() + bf).unwrap().map(bf16::to_f64),
+        a.scalar_mul(2.0)
+    );
+}
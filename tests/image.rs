@@ -0,0 +1,170 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::image::{label_connected_components, Connectivity, Interpolation};
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+use tensor_algebra_in_rust::vector;
+
+#[test]
+fn resize_bilinear_up_and_down() {
+    let m: Matrix<f64, 2> = matrix![0.0, 1.0; 2.0, 3.0];
+    let up = m.resize_bilinear::<4>(4).unwrap();
+    assert_eq!(up.shape(), (4, 4));
+    assert_eq!(up[0], vector![0.0, 0.25, 0.75, 1.0]);
+    assert_eq!(up[3], vector![2.0, 2.25, 2.75, 3.0]);
+
+    // Downsampling by two averages each 2x2 block of a linear ramp
+    let ramp: Matrix<f64, 4> = matrix![0.0, 1.0, 2.0, 3.0; 4.0, 5.0, 6.0, 7.0];
+    let down = ramp.resize_bilinear::<2>(1).unwrap();
+    assert_eq!(down[0], vector![2.5, 4.5]);
+
+    assert!(Matrix::<f64, 2>::new(0).resize_bilinear::<2>(2).is_err());
+    assert_eq!(m.resize_bilinear::<3>(0).unwrap().shape(), (0, 3));
+}
+
+#[test]
+fn rotate_and_warp() {
+    let m: Matrix<f64, 3> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+
+    let r90 = m.rotate_degrees(90.0, Interpolation::Nearest).unwrap();
+    assert_eq!(r90, matrix![3.0, 6.0, 9.0; 2.0, 5.0, 8.0; 1.0, 4.0, 7.0]);
+    let r360 = m.rotate_degrees(360.0, Interpolation::Bilinear).unwrap();
+    assert!(r360.approx_eq(&m, 1e-9, 0.0));
+    for theta in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(matches!(
+            m.rotate_degrees(theta, Interpolation::Nearest),
+            Err(TensorError::InvalidOperation(_))
+        ));
+    }
+
+    // Translation one column to the right; the first column comes from outside
+    let shift = matrix![1.0, 0.0, 1.0; 0.0, 1.0, 0.0];
+    let shifted = m.warp_affine(&shift, Interpolation::Bilinear).unwrap();
+    assert_eq!(shifted[1], vector![0.0, 4.0, 5.0]);
+
+    // Half-pixel translation interpolates between neighbours
+    let half = matrix![1.0, 0.0, -0.5; 0.0, 1.0, 0.0];
+    let blended = m.warp_affine(&half, Interpolation::Bilinear).unwrap();
+    assert_eq!(blended[0][0], 1.5);
+
+    let singular = matrix![1.0, 1.0, 0.0; 1.0, 1.0, 0.0];
+    assert!(m.warp_affine(&singular, Interpolation::Nearest).is_err());
+    let wrong_rows = Matrix::<f64, 3>::new(3);
+    assert!(matches!(
+        m.warp_affine(&wrong_rows, Interpolation::Nearest),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn integral_image_and_box_filter() {
+    let m: Matrix<u32, 3> = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let sat = m.integral_image::<u32>();
+    assert_eq!(sat[0], vector![1, 3, 6]);
+    assert_eq!(sat[2], vector![12, 27, 45]);
+
+    // Radius 0 is the identity; radius 1 averages the clipped 3x3 window
+    assert_eq!(m.box_filter::<u32>(0).unwrap(), m);
+    let f: Matrix<f64, 3> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+    let b = f.box_filter::<f64>(1).unwrap();
+    assert_eq!(b[1][1], 5.0);
+    assert_eq!(b[0][0], (1.0 + 2.0 + 4.0 + 5.0) / 4.0);
+    assert_eq!(b[2][1], (4.0 + 5.0 + 6.0 + 7.0 + 8.0 + 9.0) / 6.0);
+
+    // Unsigned integer sums never underflow while combining rectangles
+    let u = m.box_filter::<u64>(1).unwrap();
+    assert_eq!(u[2][2], (5 + 6 + 8 + 9) / 4);
+    assert_eq!(
+        Matrix::<f64, 3>::new(0)
+            .box_filter::<f64>(2)
+            .unwrap()
+            .shape(),
+        (0, 3)
+    );
+
+    // A u8 image sums and counts its 17x17 windows in a wider type
+    let bright = Matrix::<u8, 20>::from_fn(20, |_, _| 200);
+    assert_eq!(bright.integral_image::<u32>()[19][19], 200 * 400);
+    assert!(bright
+        .box_filter::<u32>(8)
+        .unwrap()
+        .as_slice()
+        .iter()
+        .all(|&x| x == 200));
+    let dark = Matrix::<u8, 20>::new(20);
+    assert!(matches!(
+        dark.box_filter::<u8>(8),
+        Err(TensorError::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn threshold_and_morphology() {
+    let img: Matrix<f32, 5> = matrix![
+        0.1, 0.2, 0.1, 0.0, 0.9;
+        0.1, 0.8, 0.9, 0.7, 0.0;
+        0.0, 0.9, 0.9, 0.8, 0.1;
+        0.2, 0.7, 0.8, 0.9, 0.0;
+        0.0, 0.1, 0.0, 0.2, 0.1
+    ];
+    let mask = img.threshold(0.5);
+    assert_eq!(mask[0], vector![0.0, 0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(mask[2], vector![0.0, 1.0, 1.0, 1.0, 0.0]);
+
+    let cross: Matrix<f32, 3> = matrix![0.0, 1.0, 0.0; 1.0, 1.0, 1.0; 0.0, 1.0, 0.0];
+    let eroded = mask.erode(&cross).unwrap();
+    assert_eq!(eroded.map(|x| x as i32)[2], vector![0, 0, 1, 0, 0]);
+    assert_eq!(eroded[0][4], 0.0);
+
+    let dilated = eroded.dilate(&cross).unwrap();
+    assert_eq!(dilated[1], vector![0.0, 0.0, 1.0, 0.0, 0.0]);
+    assert_eq!(dilated[2], vector![0.0, 1.0, 1.0, 1.0, 0.0]);
+
+    // Opening removes the isolated corner pixel, closing never shrinks a mask
+    let opened = mask.opening(&cross).unwrap();
+    assert_eq!(opened, dilated);
+    let closed = mask.closing(&cross).unwrap();
+    for r in 0..5 {
+        for c in 0..5 {
+            assert!(closed[r][c] >= mask[r][c]);
+        }
+    }
+
+    let even = Matrix::<f32, 2>::new(2).scalar_add(1.0);
+    assert!(mask.erode(&even).is_err());
+    assert!(mask.dilate(&Matrix::<f32, 3>::new(3)).is_err());
+}
+
+#[test]
+fn connected_components_four_and_eight() {
+    let mask: Matrix<u32, 5> = matrix![
+        1, 1, 0, 0, 1;
+        0, 1, 0, 1, 1;
+        0, 0, 1, 0, 0;
+        1, 0, 0, 0, 1;
+        1, 1, 0, 1, 1
+    ];
+
+    let (four, count4) = label_connected_components(&mask, Connectivity::Four);
+    assert_eq!(count4, 5);
+    assert_eq!(four[0], vector![1, 1, 0, 0, 2]);
+    assert_eq!(four[1], vector![0, 1, 0, 2, 2]);
+    assert_eq!(four[2], vector![0, 0, 3, 0, 0]);
+    assert_eq!(four[4], vector![4, 4, 0, 5, 5]);
+
+    // Diagonal contact merges the first three blobs into one
+    let (eight, count8) = label_connected_components(&mask, Connectivity::Eight);
+    assert_eq!(count8, 3);
+    assert_eq!(eight[2][2], 1);
+    assert_eq!(eight[0][4], 1);
+    assert_eq!(eight[3][0], 2);
+    assert_eq!(eight[3][4], 3);
+
+    // U-shaped blob discovered from two separate arms resolves to one label
+    let u: Matrix<f64, 3> = matrix![1.0, 0.0, 1.0; 1.0, 0.0, 1.0; 1.0, 1.0, 1.0];
+    let (labels, count) = label_connected_components(&u, Connectivity::Four);
+    assert_eq!(count, 1);
+    assert_eq!(labels[0], vector![1, 0, 1]);
+
+    let (_, none) = label_connected_components(&Matrix::<f64, 3>::new(3), Connectivity::Eight);
+    assert_eq!(none, 0);
+}
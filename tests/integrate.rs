@@ -0,0 +1,31 @@
+use tensor_algebra_in_rust::integrate::{monte_carlo_integrate, monte_carlo_integrate_parallel};
+use tensor_algebra_in_rust::tensor::Vector;
+
+#[test]
+fn integrates_polynomial_over_box_within_error() {
+    // Integral of x * y over [0, 2] x [0, 3] = 2 * 4.5 = 9
+    let f = |v: &Vector<f64, 2>| v[0] * v[1];
+    let r = monte_carlo_integrate(f, &[(0.0, 2.0), (0.0, 3.0)], 200_000, 11).unwrap();
+    assert!(r.std_error > 0.0 && r.std_error < 0.05);
+    assert!((r.estimate - 9.0).abs() < 4.0 * r.std_error);
+
+    // Constant integrand has zero variance
+    let c = monte_carlo_integrate(|_| 2.0, &[(1.0, 4.0)], 1000, 0).unwrap();
+    assert_eq!(c.estimate, 6.0);
+    assert_eq!(c.std_error, 0.0);
+}
+
+#[test]
+fn parallel_matches_serial_exactly() {
+    let f = |v: &Vector<f64, 3>| (v[0] + v[1] * v[2]).sin();
+    let bounds = [(0.0, 1.0), (-1.0, 1.0), (0.0, 0.5)];
+    let serial = monte_carlo_integrate(f, &bounds, 50_001, 5).unwrap();
+    let parallel = monte_carlo_integrate_parallel(f, &bounds, 50_001, 5).unwrap();
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert!(monte_carlo_integrate(|_| 1.0, &[(0.0, 1.0)], 0, 0).is_err());
+    assert!(monte_carlo_integrate(|_| 1.0, &[(1.0, 0.0)], 10, 0).is_err());
+}
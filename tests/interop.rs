@@ -0,0 +1,41 @@
+#![cfg(any(feature = "glam", feature = "cgmath"))]
+
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+use tensor_algebra_in_rust::{matrix, vector};
+
+fn sample() -> Matrix<f32, 3> {
+    matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 10.0]
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_round_trips_keep_orientation() {
+    let v: glam::Vec3 = vector![1.0, 2.0, 3.0].into();
+    assert_eq!(v, glam::Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector::from(glam::Vec4::ONE), vector![1.0, 1.0, 1.0, 1.0]);
+
+    let m = sample();
+    let g = glam::Mat3::try_from(&m).unwrap();
+    assert_eq!(g.row(0), glam::Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(g * glam::Vec3::X, glam::Vec3::new(1.0, 4.0, 7.0));
+    assert_eq!(Matrix::from(g), m);
+
+    let wrong: Matrix<f32, 4> = Matrix::new(3);
+    assert!(glam::Mat4::try_from(&wrong).is_err());
+}
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn cgmath_round_trips_keep_orientation() {
+    let v: cgmath::Vector2<f32> = vector![1.0, 2.0].into();
+    assert_eq!(v, cgmath::Vector2::new(1.0, 2.0));
+    assert_eq!(Vector::from(v), vector![1.0, 2.0]);
+
+    let m = sample();
+    let c = cgmath::Matrix3::try_from(&m).unwrap();
+    assert_eq!(
+        c * cgmath::Vector3::new(1.0, 0.0, 0.0),
+        cgmath::Vector3::new(1.0, 4.0, 7.0)
+    );
+    assert_eq!(Matrix::from(c), m);
+}
@@ -0,0 +1,67 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::layout::{ColMajor, Layout, RowMajor};
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn round_trips_through_both_buffer_orders() {
+    let m: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.to_buffer(Layout::RowMajor), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(m.to_buffer(Layout::ColMajor), vec![1, 4, 2, 5, 3, 6]);
+
+    for layout in [Layout::RowMajor, Layout::ColMajor] {
+        let back = Matrix::<i32, 3>::from_buffer(2, &m.to_buffer(layout), layout).unwrap();
+        assert_eq!(back, m);
+    }
+
+    // A column-major buffer read as row-major is the transpose
+    let t =
+        Matrix::<i32, 2>::from_buffer(3, &m.to_buffer(Layout::ColMajor), Layout::RowMajor).unwrap();
+    assert_eq!(t, matrix![1, 4; 2, 5; 3, 6]);
+
+    assert!(matches!(
+        Matrix::<i32, 3>::from_buffer(2, &[1, 2, 3], Layout::ColMajor),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(Matrix::<f64, 4>::from_buffer(0, &[], Layout::ColMajor).is_ok());
+}
+
+#[test]
+fn column_major_matrices_index_and_iterate_by_position() {
+    let m: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6];
+    let mut c: Matrix<i32, 3, ColMajor> = m.to_layout();
+    assert_eq!(c.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(c.shape(), (2, 3));
+    assert_eq!(c[(1, 0)], m[(1, 0)]);
+    assert_eq!(*c.get_at(0, 2).unwrap(), 3);
+    assert!(c.get_at(2, 0).is_err());
+    assert_eq!(c.col(1).unwrap(), &[2, 5]);
+    assert!(c.col(3).is_err());
+    assert_eq!(c.iter_cols().count(), 3);
+
+    let order: Vec<_> = c.indexed_iter().map(|(index, &x)| (index, x)).collect();
+    assert_eq!(order[1], ((1, 0), 4));
+    assert!(c.indexed_iter().all(|((i, j), &x)| x == m[(i, j)]));
+
+    c[(1, 2)] = 60;
+    c.col_mut(0).unwrap()[0] = 10;
+    let back: Matrix<i32, 3, RowMajor> = c.into_layout();
+    assert_eq!(back, matrix![10, 2, 3; 4, 5, 60]);
+    assert_eq!(m.clone().into_layout::<RowMajor>(), m);
+}
+
+#[test]
+fn column_major_product_matches_row_major() {
+    let a: Matrix<f64, 3> = Matrix::from_fn(37, |i, j| (i * 3 + j) as f64 * 0.5 - 7.0);
+    let b: Matrix<f64, 2> = matrix![1.0, -2.0; 0.5, 3.0; -1.0, 4.0];
+    let expected = (&a * &b).unwrap();
+
+    let product = (&a.to_layout::<ColMajor>() * &b.to_layout::<ColMajor>()).unwrap();
+    assert_eq!(product.to_layout::<RowMajor>(), expected);
+
+    let short: Matrix<f64, 2, ColMajor> = matrix![1.0, 2.0].into_layout();
+    assert!(matches!(
+        &a.to_layout::<ColMajor>() * &short,
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
@@ -0,0 +1,281 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::linalg::{Metric, Norm};
+use tensor_algebra_in_rust::tensor::{AllowedFloatTypes, Matrix};
+use tensor_algebra_in_rust::{matrix, vector};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-10, "expected {}, got {}", b, a);
+}
+
+#[test]
+fn pinv_apply_overdetermined_least_squares() {
+    // Fit y = c0 + c1 * x through (0, 1), (1, 3), (2, 5), (3, 7.5)
+    let a = Matrix::<f64, 2>::from_vectors(vec![
+        vector![1.0, 0.0],
+        vector![1.0, 1.0],
+        vector![1.0, 2.0],
+        vector![1.0, 3.0],
+    ]);
+    let x = a.pinv_apply(&[1.0, 3.0, 5.0, 7.5]).unwrap();
+
+    // Normal equations: [4 6; 6 14] c = [16.5, 35.5]
+    assert_close(x[0], 0.9);
+    assert_close(x[1], 2.15);
+}
+
+#[test]
+fn pinv_apply_rank_deficient_and_underdetermined_are_minimum_norm() {
+    let a = Matrix::<f64, 2>::from_vectors(vec![vector![1.0, 1.0], vector![1.0, 1.0]]);
+    let cod = a.cod();
+    assert_eq!(cod.rank(), 1);
+    let x = cod.pinv_apply(&[2.0, 2.0]).unwrap();
+    assert_close(x[0], 1.0);
+    assert_close(x[1], 1.0);
+
+    // Repeated right-hand sides reuse the stored factorization
+    let x = cod.pinv_apply(&[4.0, 0.0]).unwrap();
+    assert_close(x[0], 1.0);
+    assert_close(x[1], 1.0);
+
+    let wide = Matrix::<f64, 3>::from_vectors(vec![vector![1.0, 2.0, 2.0]]);
+    let x = wide.pinv_apply(&[9.0]).unwrap();
+    assert_close(x[0], 1.0);
+    assert_close(x[1], 2.0);
+    assert_close(x[2], 2.0);
+}
+
+#[test]
+fn pinv_apply_zero_matrix_and_shape_errors() {
+    let z = Matrix::<f64, 2>::new(3);
+    assert_eq!(z.cod().rank(), 0);
+    assert_eq!(z.pinv_apply(&[1.0, 2.0, 3.0]).unwrap(), vector![0.0, 0.0]);
+
+    match z.pinv_apply(&[1.0]).unwrap_err() {
+        TensorError::DimensionMismatch { .. } => {}
+        other => panic!("Expected DimensionMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn normalize_rows_and_cols() {
+    let m = Matrix::<f64, 2>::from_vectors(vec![vector![3.0, 4.0], vector![1.0, 3.0]]);
+
+    let rows = m.normalize_rows().unwrap();
+    assert_close(rows[0][0], 0.6);
+    assert_close(rows[0][1], 0.8);
+
+    let stochastic = m.normalize_rows_by(Norm::L1).unwrap();
+    assert_close(stochastic[1][0], 0.25);
+    assert_close(stochastic[1][1], 0.75);
+
+    let cols = m.normalize_cols_by(Norm::Max).unwrap();
+    assert_close(cols[0][0], 1.0);
+    assert_close(cols[1][0], 1.0 / 3.0);
+    assert_close(cols[0][1], 4.0 / 4.0);
+    assert_close(cols[1][1], 0.75);
+
+    let zero_row = Matrix::<f64, 2>::from_vectors(vec![vector![1.0, 2.0], vector![0.0, 0.0]]);
+    assert_eq!(zero_row.normalize_rows(), Err(TensorError::DivisionByZero));
+    let zero_col = Matrix::<f64, 2>::from_vectors(vec![vector![1.0, 0.0], vector![2.0, 0.0]]);
+    assert_eq!(zero_col.normalize_cols(), Err(TensorError::DivisionByZero));
+}
+
+#[test]
+fn pairwise_distances_by_metric() {
+    let m = Matrix::<f64, 2>::from_vectors(vec![
+        vector![0.0, 0.0],
+        vector![3.0, 4.0],
+        vector![1.0, 1.0],
+    ]);
+
+    let d = m.pairwise_distances::<3>(Metric::Euclidean).unwrap();
+    assert_eq!(d[0], vector![0.0, 5.0, 2f64.sqrt()]);
+    assert_eq!(d[1][0], 5.0);
+
+    let sq = m.pairwise_distances::<3>(Metric::SquaredEuclidean).unwrap();
+    assert_eq!(sq[1][2], 13.0);
+    let l1 = m.pairwise_distances::<3>(Metric::Manhattan).unwrap();
+    assert_eq!(l1[0][1], 7.0);
+    let linf = m.pairwise_distances::<3>(Metric::Chebyshev).unwrap();
+    assert_eq!(linf[0][1], 4.0);
+
+    assert_eq!(
+        m.pairwise_distances::<3>(Metric::Cosine),
+        Err(TensorError::DivisionByZero)
+    );
+    let unit = Matrix::<f64, 2>::from_vectors(vec![vector![1.0, 0.0], vector![0.0, 2.0]]);
+    let cos = unit.pairwise_distances::<2>(Metric::Cosine).unwrap();
+    assert_close(cos[0][1], 1.0);
+
+    assert!(m.pairwise_distances::<2>(Metric::Euclidean).is_err());
+}
+
+#[test]
+fn bareiss_determinant_is_exact_for_integers() {
+    let m: Matrix<i64, 3> = matrix![2, -3, 1; 2, 0, -1; 1, 4, 5];
+    assert_eq!(m.determinant_bareiss().unwrap(), 49);
+
+    // Needs a row swap for the first pivot
+    let swapped: Matrix<i32, 3> = matrix![0, 1, 2; 1, 0, 3; 4, -3, 8];
+    assert_eq!(swapped.determinant_bareiss().unwrap(), -2);
+
+    let singular: Matrix<i32, 2> = matrix![1, 2; 2, 4];
+    assert_eq!(singular.determinant_bareiss().unwrap(), 0);
+
+    let f: Matrix<f64, 2> = matrix![0.5, 1.0; 2.0, 3.0];
+    assert_close(f.determinant_bareiss().unwrap(), -0.5);
+
+    let rect: Matrix<i32, 2> = matrix![1, 2];
+    assert!(matches!(
+        rect.determinant_bareiss(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn i128_determinant_and_matmul_beyond_i64() {
+    let big = 5_000_000_000i128;
+    let m: Matrix<i128, 2> = matrix![big, 1; 1, big];
+    // big^2 is already past i64::MAX
+    assert_eq!(m.determinant_bareiss().unwrap(), big * big - 1);
+
+    let cube = (&(&m * &m).unwrap() * &m).unwrap();
+    assert_eq!(cube[0][0], big * big * big + 3 * big);
+    assert!(cube[0][0] > i64::MAX as i128);
+
+    let u: Matrix<u128, 2> = matrix![u64::MAX as u128, 0; 0, 2];
+    let sq = (&u * &u).unwrap();
+    assert_eq!(sq[0][0], (u64::MAX as u128) * (u64::MAX as u128));
+    assert_eq!(u.determinant_bareiss().unwrap(), 2 * u64::MAX as u128);
+
+    // Swapping the rows of the identity gives -1
+    let swap: Matrix<u32, 2> = matrix![0, 1; 1, 0];
+    assert!(matches!(
+        swap.determinant_bareiss(),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    let singular: Matrix<u32, 2> = matrix![0, 1; 0, 2];
+    assert_eq!(singular.determinant_bareiss().unwrap(), 0);
+}
+
+#[test]
+fn determinant_and_solve_respect_pivot_tolerance() {
+    let a: Matrix<f64, 3> = matrix![2.0, 1.0, -1.0; -3.0, -1.0, 2.0; -2.0, 1.0, 2.0];
+    assert_close(a.determinant().unwrap(), -1.0);
+    let x = a.solve(&vector![8.0, -11.0, -3.0]).unwrap();
+    assert_close(x[0], 2.0);
+    assert_close(x[1], 3.0);
+    assert_close(x[2], -1.0);
+
+    // Rows differ only by rounding noise
+    let nearly: Matrix<f64, 2> = matrix![1.0, 2.0; 1.0, 2.0 + 1e-14];
+    assert!(nearly.determinant().unwrap() != 0.0);
+    assert!(nearly.solve(&vector![1.0, 2.0]).is_ok());
+    assert_eq!(nearly.determinant_with_tol(1e-10).unwrap(), 0.0);
+    assert!(matches!(
+        nearly.solve_with_tol(&vector![1.0, 2.0], 1e-10),
+        Err(TensorError::InvalidOperation(_))
+    ));
+
+    let exact: Matrix<f64, 2> = matrix![1.0, 2.0; 2.0, 4.0];
+    assert!(exact.solve(&vector![1.0, 2.0]).is_err());
+    assert!(1e-12f64.is_zero_with_tol(1e-10));
+
+    let rect: Matrix<f64, 2> = matrix![1.0, 2.0];
+    assert!(matches!(
+        rect.determinant(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn closed_form_determinants_match_elimination() {
+    let m4: Matrix<f64, 4> = matrix![
+        2.0, -1.0, 0.0, 3.0;
+        1.0, 4.0, -2.0, 0.5;
+        0.0, 1.0, 1.0, -1.0;
+        3.0, 0.0, 2.0, 1.0
+    ];
+    assert_close(
+        m4.determinant().unwrap(),
+        m4.determinant_with_tol(0.0).unwrap(),
+    );
+    assert_close(m4.determinant().unwrap(), m4.determinant_bareiss().unwrap());
+
+    let m3: Matrix<f64, 3> = matrix![0.0, 2.0, 1.0; 1.0, 0.0, 3.0; 4.0, 1.0, 0.0];
+    assert_close(m3.determinant().unwrap(), 25.0);
+    let m2: Matrix<f64, 2> = matrix![3.0, 8.0; 4.0, 6.0];
+    assert_close(m2.determinant().unwrap(), -14.0);
+    let m1: Matrix<f64, 1> = matrix![-7.5];
+    assert_close(m1.determinant().unwrap(), -7.5);
+
+    // Larger sizes still go through elimination
+    let mut m5 = Matrix::<f64, 5>::new(5);
+    for i in 0..5 {
+        m5[i][i] = (i + 1) as f64;
+    }
+    m5[0][4] = 1.0;
+    assert_close(m5.determinant().unwrap(), 120.0);
+}
+
+#[test]
+fn workspace_is_reused_across_solves() {
+    use tensor_algebra_in_rust::workspace::Workspace;
+
+    let a: Matrix<f64, 3> = matrix![2.0, 1.0, -1.0; -3.0, -1.0, 2.0; -2.0, 1.0, 2.0];
+    let mut ws = Workspace::new();
+    let x = a
+        .solve_in(&vector![8.0, -11.0, -3.0], 0.0, &mut ws)
+        .unwrap();
+    assert_eq!(x, a.solve(&vector![8.0, -11.0, -3.0]).unwrap());
+    let capacity = ws.capacity();
+    assert!(capacity >= 9);
+
+    for k in 0..5 {
+        let b = vector![k as f64, 1.0, -1.0];
+        let x = a.solve_in(&b, 0.0, &mut ws).unwrap();
+        let back = a.mat_vec_mul(&x).unwrap();
+        for (got, want) in back.iter().zip(b.iter()) {
+            assert_close(*got, *want);
+        }
+        assert_close(
+            a.determinant_in(0.0, &mut ws).unwrap(),
+            a.determinant().unwrap(),
+        );
+    }
+    assert_eq!(ws.capacity(), capacity);
+
+    let singular: Matrix<f64, 2> = matrix![1.0, 2.0; 2.0, 4.0];
+    assert!(singular.solve_in(&vector![1.0, 1.0], 0.0, &mut ws).is_err());
+    assert_eq!(singular.determinant_in(0.0, &mut ws).unwrap(), 0.0);
+}
+
+#[test]
+fn workspace_backs_pinv_and_products() {
+    use tensor_algebra_in_rust::workspace::Workspace;
+
+    let a: Matrix<f64, 3> = matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 1.0, 0.0, 1.0; 0.0, 1.0, 1.0];
+    let b = [1.0, 2.0, 0.5, -1.0];
+    let mut ws = Workspace::new();
+    assert_eq!(
+        a.pinv_apply_in(&b, &mut ws).unwrap(),
+        a.pinv_apply(&b).unwrap()
+    );
+    let cod = a.cod();
+    assert_eq!(
+        cod.pinv_apply_in(&b, &mut ws).unwrap(),
+        cod.pinv_apply(&b).unwrap()
+    );
+    assert!(a.pinv_apply_in(&b[..3], &mut ws).is_err());
+
+    let wide: Matrix<f64, 40> = Matrix::from_fn(40, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+    let product = wide.mat_mul_in(&wide, &mut ws).unwrap();
+    assert_eq!(product, (&wide * &wide).unwrap());
+    let capacity = ws.capacity();
+    for _ in 0..3 {
+        a.pinv_apply_in(&b, &mut ws).unwrap();
+        wide.mat_mul_in(&wide, &mut ws).unwrap();
+    }
+    assert_eq!(ws.capacity(), capacity);
+    assert!(wide.mat_mul_in(&Matrix::<f64, 2>::new(3), &mut ws).is_err());
+}
@@ -0,0 +1,85 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::mask::{Mask, RleMask};
+use tensor_algebra_in_rust::tensor::Matrix;
+use tensor_algebra_in_rust::{matrix, vector};
+
+#[test]
+fn rle_roundtrip_and_queries() {
+    let m: Matrix<u32, 4> = matrix![1, 1, 0, 0; 0, 0, 0, 1; 1, 1, 1, 0];
+    let rle = RleMask::from_matrix(&m);
+
+    // the run ending row 1 continues into row 2
+    assert_eq!(rle.runs(), &[(0, 2), (7, 4)]);
+    assert_eq!(rle.count(), 6);
+    assert_eq!(rle.shape(), (3, 4));
+    assert!(rle.get(1, 3).unwrap());
+    assert!(!rle.get(1, 2).unwrap());
+    assert!(rle.get(3, 0).is_err());
+    assert_eq!(rle.to_matrix::<u32>(), m);
+
+    let f: Matrix<f64, 4> = rle.to_matrix();
+    assert_eq!(RleMask::from_matrix(&f), rle);
+}
+
+#[test]
+fn rle_logical_ops_match_dense() {
+    let a: Matrix<i32, 3> = matrix![1, 1, 0; 0, 1, 1; 1, 0, 0];
+    let b: Matrix<i32, 3> = matrix![0, 1, 1; 0, 1, 0; 1, 1, 1];
+    let (ra, rb) = (RleMask::from_matrix(&a), RleMask::from_matrix(&b));
+
+    let dense = |f: fn(i32, i32) -> i32| a.zip_map(&b, f).unwrap();
+    assert_eq!(ra.and(&rb).unwrap().to_matrix(), dense(|x, y| x & y));
+    assert_eq!(ra.or(&rb).unwrap().to_matrix(), dense(|x, y| x | y));
+    assert_eq!(ra.xor(&rb).unwrap().to_matrix(), dense(|x, y| x ^ y));
+    assert_eq!((!&ra).to_matrix(), a.map(|x| 1 - x));
+    assert_eq!(!!ra.clone(), ra);
+
+    assert_eq!((!RleMask::<3>::new(2)).count(), 6);
+    assert!(ra.and(&RleMask::new(2)).is_err());
+}
+
+#[test]
+fn comparison_masks_drive_select_and_fill_where() {
+    let a: Matrix<f64, 3> = matrix![1.0, -2.0, 3.0; -4.0, 5.0, f64::NAN];
+    let b: Matrix<f64, 3> = matrix![0.0, 0.0, 4.0; 0.0, 6.0, 0.0];
+
+    let positive = a.mask_where(|x| x > 0.0);
+    assert_eq!(positive.count(), 3);
+    assert_eq!(positive.get(1, 1), Ok(true));
+    assert!(positive.get(2, 0).is_err());
+
+    // ReLU as a select against zeros
+    let zeros = Matrix::new(2);
+    let relu = a.select(&positive, &zeros).unwrap();
+    assert_eq!(relu, matrix![1.0, 0.0, 3.0; 0.0, 5.0, 0.0]);
+
+    let cleaned = a.fill_where(&a.mask_where(f64::is_nan), 0.0).unwrap();
+    assert_eq!(cleaned[1][2], 0.0);
+
+    let bigger = a.greater(&b).unwrap();
+    assert_eq!(bigger.to_matrix::<i32>(), matrix![1, 0, 0; 0, 0, 0]);
+    assert_eq!(a.less(&b).unwrap().and(&!&positive).unwrap().count(), 2);
+    assert!(!a.equal(&b).unwrap().any());
+    assert!(Mask::<3>::full(2, true).all());
+    assert_eq!(Mask::from_matrix(&bigger.to_matrix::<u8>()), bigger);
+
+    let short: Matrix<f64, 3> = Matrix::new(1);
+    assert!(matches!(
+        a.greater(&short),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(a.select(&Mask::full(1, true), &b).is_err());
+}
+
+#[test]
+fn vector_masks_have_one_row() {
+    let v = vector![3, -1, 4, -1, 5];
+    let negative = v.mask_where(|x| x < 0);
+    assert_eq!(negative.shape(), (1, 5));
+    assert_eq!(v.fill_where(&negative, 0).unwrap(), vector![3, 0, 4, 0, 5]);
+    assert_eq!(
+        v.select(&negative, &vector![9, 9, 9, 9, 9]).unwrap(),
+        vector![9, -1, 9, -1, 9]
+    );
+    assert!(v.fill_where(&Mask::full(2, true), 0).is_err());
+}
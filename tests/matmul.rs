@@ -1,6 +1,6 @@
 use tensor_algebra_in_rust::error::TensorError;
-use tensor_algebra_in_rust::tensor::Matrix;
-use tensor_algebra_in_rust::vector;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
 
 #[test]
 fn matrix_matmul_i32_basic() {
@@ -49,3 +49,183 @@ fn matrix_matmul_f64_kahan_stability_sanity() {
     let val = c[0][0];
     assert!(val.abs() < 1e-6, "expected ~0, got {}", val);
 }
+
+#[test]
+#[cfg(not(feature = "fast-math"))]
+fn matrix_matmul_keeps_kahan_summation_in_the_tiled_kernel() {
+    // Summed naively the two ones are absorbed by 1e16 and the result is 0
+    let a =
+        Matrix::<f64, 8>::from_vectors(vec![vector![1e16, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1e16]]);
+    let b = Matrix::<f64, 9>::from_fn(8, |_, _| 1.0);
+    let c = (&a * &b).unwrap();
+    assert!(c[0].iter().all(|&x| x == 2.0), "got {:?}", c[0]);
+}
+
+#[test]
+fn matrix_transpose_and_gram() {
+    let a = Matrix::<i32, 3>::from_vectors(vec![vector![1, 2, 3], vector![4, 5, 6]]);
+
+    let t = a.transpose::<2>().unwrap();
+    assert_eq!(t.shape(), (3, 2));
+    assert_eq!(t[2], vector![3, 6]);
+    assert!(a.transpose::<3>().is_err());
+
+    let g = a.gram::<2>().unwrap();
+    assert_eq!(g[0], vector![14, 32]);
+    assert_eq!(g[1], vector![32, 77]);
+}
+
+#[test]
+fn batched_mat_vec_mul_shared_and_per_depth() {
+    let t: Tensor<i32, 2> = tensor![[[1, 2]; [3, 4]; [5, 6]]; [[0, 1]; [1, 0]; [2, 2]]];
+
+    let shared: Matrix<i32, 3> = t.batched_mat_vec_mul(&vector![1, 1]).unwrap();
+    assert_eq!(shared, matrix![3, 7, 11; 1, 1, 4]);
+
+    let per_depth: Matrix<i32, 3> = t.batched_mat_vec_mul_each(&matrix![1, 0; 0, 2]).unwrap();
+    assert_eq!(per_depth, matrix![1, 3, 5; 2, 0, 4]);
+    assert_eq!(
+        per_depth[0].iter().copied().collect::<Vec<_>>(),
+        t.get(0)
+            .unwrap()
+            .to_matrix()
+            .mat_vec_mul(&vector![1, 0])
+            .unwrap()
+    );
+
+    assert!(matches!(
+        t.batched_mat_vec_mul::<2>(&vector![1, 1]),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(t.batched_mat_vec_mul_each::<3>(&matrix![1, 1]).is_err());
+}
+
+#[test]
+fn widening_matmul_accumulates_without_overflow() {
+    let a: Matrix<i8, 3> = matrix![127, 127, 127; -128, 1, 0];
+    let b: Matrix<i8, 2> = matrix![127, -128; 127, -128; 127, -128];
+    let wide: Matrix<i32, 2> = a.mat_mul_widening(&b).unwrap();
+    assert_eq!(
+        wide,
+        matrix![3 * 127 * 127, 3 * 127 * -128; -128 * 127 + 127, 128 * 128 - 128]
+    );
+
+    let c: Matrix<i16, 2> = matrix![i16::MAX, i16::MAX];
+    let d: Matrix<i16, 1> = matrix![i16::MAX; i16::MAX];
+    let product = c.mat_mul_widening::<i64, 1>(&d).unwrap();
+    assert_eq!(product[0][0], 2 * (i16::MAX as i64).pow(2));
+
+    let u: Matrix<u8, 2> = matrix![255, 255];
+    assert_eq!(
+        u.mat_mul_widening::<u32, 2>(&matrix![255, 1; 255, 1])
+            .unwrap(),
+        matrix![130050, 510]
+    );
+    assert!(a.mat_mul_widening::<i32, 2>(&matrix![1i8, 2]).is_err());
+}
+
+#[test]
+fn mixed_precision_matmul_rounds_once() {
+    const K: usize = 4096;
+    let mut row: Matrix<f32, K> = Matrix::new(1);
+    let mut col: Matrix<f32, 1> = Matrix::new(K);
+    for k in 0..K {
+        // Alternating large and small terms lose bits in f32 accumulation
+        row[0][k] = if k % 2 == 0 { 1.0e4 } else { 1.0e-3 };
+        col[k][0] = 1.0 + k as f32 * 1.0e-4;
+    }
+    let exact: f64 = (0..K).map(|k| row[0][k] as f64 * col[k][0] as f64).sum();
+
+    let mixed = row.mat_mul_accumulate::<f64, 1>(&col).unwrap();
+    assert_eq!(mixed[0][0], exact as f32);
+
+    let ints: Matrix<i16, 2> = matrix![300, 300];
+    let back = ints
+        .mat_mul_accumulate::<i64, 1>(&matrix![100; 100])
+        .unwrap();
+    assert_eq!(back, matrix![i16::MAX]);
+
+    // Narrowing keeps integers above 2^53 exact
+    let big: Matrix<i64, 2> = matrix![1 << 53, 1];
+    let exact = big.mat_mul_accumulate::<i128, 1>(&matrix![1; 1]).unwrap();
+    assert_eq!(exact, matrix![(1i64 << 53) + 1]);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn large_products_run_in_parallel_with_serial_results() {
+    use tensor_algebra_in_rust::tensor::AllowedNumericTypes;
+
+    // 48 x 64 x 32 multiply-adds, above the parallel threshold
+    let a = Matrix::<f64, 64>::from_fn(48, |i, k| (k as f64 + 1.0) * (i as f64).sin());
+    let b = Matrix::<f64, 32>::from_fn(64, |k, j| (k * 32 + j) as f64 + 0.5);
+    let product = (&a * &b).unwrap();
+    for i in [0, 17, 47] {
+        for j in [0, 31] {
+            // The serial kernel one element at a time, Kahan or not
+            let (mut sum, mut comp) = ([0.0], [0.0]);
+            for k in 0..64 {
+                f64::kahan_axpy_slices(&mut sum, &mut comp, b[(k, j)], &[a[(i, k)]]);
+            }
+            assert_eq!(product[(i, j)], sum[0]);
+        }
+    }
+
+    let t = Tensor::<i32, 64>::new(40, 32).map(|x| x + 1);
+    let v = tensor_algebra_in_rust::tensor::Vector::<i32, 64>::new().map(|x| x + 2);
+    let shared: Matrix<i32, 32> = t.batched_mat_vec_mul(&v).unwrap();
+    assert!(shared.as_slice().iter().all(|&x| x == 128));
+    let vecs = Matrix::<i32, 64>::from_fn(40, |d, _| d as i32);
+    let each: Matrix<i32, 32> = t.batched_mat_vec_mul_each(&vecs).unwrap();
+    assert!((0..40).all(|d| each[d].iter().all(|&x| x == 64 * d as i32)));
+}
+
+#[test]
+fn matmul_spanning_several_tiles_matches_naive_product() {
+    // 70 rows, 130-long inner dimension and 300 columns cross every tile edge
+    let mut a = Matrix::<i64, 130>::new(70);
+    for i in 0..70 {
+        for k in 0..130 {
+            a[i][k] = ((i * 7 + k * 3) % 11) as i64 - 5;
+        }
+    }
+    let mut b = Matrix::<i64, 300>::new(130);
+    for k in 0..130 {
+        for j in 0..300 {
+            b[k][j] = ((k * 5 + j) % 13) as i64 - 6;
+        }
+    }
+
+    let product = (&a * &b).unwrap();
+    for i in 0..70 {
+        for j in 0..300 {
+            let expected: i64 = (0..130).map(|k| a[i][k] * b[k][j]).sum();
+            assert_eq!(product[i][j], expected);
+        }
+    }
+}
+
+#[test]
+fn products_into_preallocated_outputs() {
+    let a: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6];
+    let b: Matrix<i32, 2> = matrix![1, 0; 0, 1; 2, -1];
+
+    // Stale contents are overwritten, not accumulated
+    let mut out: Matrix<i32, 2> = matrix![9, 9; 9, 9];
+    a.mat_mul_into(&b, &mut out).unwrap();
+    assert_eq!(out, (&a * &b).unwrap());
+    a.mat_mul_into(&b, &mut out).unwrap();
+    assert_eq!(out, matrix![7, -1; 16, -1]);
+
+    let mut short: Matrix<i32, 2> = matrix![0, 0];
+    assert!(matches!(
+        a.mat_mul_into(&b, &mut short),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(b.mat_mul_into(&b, &mut out).is_err());
+
+    let mut y = [0; 2];
+    a.mat_vec_mul_into(&vector![1, 1, 1], &mut y).unwrap();
+    assert_eq!(y.to_vec(), a.mat_vec_mul(&vector![1, 1, 1]).unwrap());
+    assert!(a.mat_vec_mul_into(&vector![1, 1, 1], &mut [0; 3]).is_err());
+}
@@ -0,0 +1,65 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::modular::Mod;
+use tensor_algebra_in_rust::tensor::{AllowedNumericTypes, Matrix};
+use tensor_algebra_in_rust::{impl_algebra_tests, matrix, vector};
+
+type F7 = Mod<7>;
+// The Mersenne prime 2^61 - 1, whose products overflow u64
+type Big = Mod<2305843009213693951>;
+
+impl_algebra_tests!(gf7_laws, Mod<7>);
+
+fn m7<const N: usize>(m: Matrix<i64, N>) -> Matrix<F7, N> {
+    m.map(|x| F7::from_f64(x as f64))
+}
+
+#[test]
+fn residues_wrap_and_divide_by_inverse() {
+    assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+    assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+    assert_eq!(F7::new(3) / F7::new(5), F7::new(2));
+    assert_eq!(F7::from_f64(-1.0), F7::new(6));
+    assert_eq!(F7::new(0).inverse(), None);
+
+    let big = Big::new(u64::MAX);
+    assert_eq!(big * big.inverse().unwrap(), Big::one());
+    assert_eq!(Big::new(2).pow(61), Big::one());
+
+    // Composite moduli invert exactly the residues coprime to them
+    type Z8 = Mod<8>;
+    assert_eq!(Z8::new(3).inverse(), Some(Z8::new(3)));
+    assert_eq!(Z8::new(7).inverse(), Some(Z8::new(7)));
+    assert_eq!(Z8::new(2).inverse(), None);
+    assert_eq!(Z8::new(4).inverse(), None);
+    assert_eq!(Mod::<12>::new(5).inverse(), Some(Mod::new(5)));
+}
+
+#[test]
+fn gf_p_determinant_rank_solve_and_inverse() {
+    let a = m7(matrix![1, 2, 3; 4, 5, 6; 0, 1, 1]);
+    // det over the integers is 3
+    assert_eq!(a.determinant_bareiss().unwrap(), F7::new(3));
+    assert_eq!(a.rank(), 3);
+
+    let x = a
+        .solve(&vector![F7::new(1), F7::new(0), F7::new(2)])
+        .unwrap();
+    assert_eq!(
+        (&a * &Matrix::from_vectors(x.iter().map(|&v| vector![v]).collect())).unwrap(),
+        matrix![F7::new(1); F7::new(0); F7::new(2)]
+    );
+
+    let inv = a.inverse().unwrap();
+    assert_eq!((&a * &inv).unwrap(), m7(matrix![1, 0, 0; 0, 1, 0; 0, 0, 1]));
+
+    // Rows 0 and 1 coincide modulo 7
+    let singular = m7(matrix![1, 2, 3; 8, 9, 10; 0, 1, 1]);
+    assert_eq!(singular.rank(), 2);
+    assert!(matches!(
+        singular.inverse(),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(singular
+        .solve(&vector![F7::new(0), F7::new(0), F7::new(0)])
+        .is_err());
+}
@@ -0,0 +1,52 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::ndtensor::NdTensor;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor, Vector};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
+
+#[test]
+fn four_axis_indexing_and_reshape() {
+    // batch x channels x height x width
+    let data: Vec<i32> = (0..48).collect();
+    let mut t = NdTensor::from_vec(data, &[2, 3, 2, 4]).unwrap();
+    assert_eq!(t.ndim(), 4);
+    assert_eq!(t.strides(), &[24, 8, 4, 1]);
+    assert_eq!(t[&[1, 2, 0, 3][..]], 43);
+
+    *t.get_mut(&[0, 0, 1, 0]).unwrap() = -1;
+    assert_eq!(t.as_slice()[4], -1);
+
+    let flat = t.reshape(&[6, 8]).unwrap();
+    assert_eq!(flat.get(&[5, 3]).unwrap(), &43);
+    assert!(matches!(
+        t.get(&[2, 0, 0, 0]),
+        Err(TensorError::OutOfBounds { .. })
+    ));
+    assert!(t.reshape(&[5, 10]).is_err());
+    assert!(NdTensor::from_vec(vec![1.0; 5], &[2, 3]).is_err());
+
+    let scalar = NdTensor::<f64>::zeros(&[]);
+    assert_eq!(scalar.len(), 1);
+    assert_eq!(scalar.map(|x| x + 2.0)[&[][..]], 2.0);
+}
+
+#[test]
+fn conversions_to_and_from_fixed_rank_types() {
+    let v = vector![1.0, 2.0, 3.0];
+    assert_eq!(NdTensor::from(&v).to_vector::<3>().unwrap(), v);
+
+    let m: Matrix<i64, 2> = matrix![1, 2; 3, 4; 5, 6];
+    let nd = NdTensor::from(&m);
+    assert_eq!(nd.shape(), &[3, 2]);
+    assert_eq!(nd.to_matrix::<2>().unwrap(), m);
+    assert!(matches!(
+        nd.to_matrix::<3>(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    let t: Tensor<i64, 2> = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    let nd = NdTensor::from(&t);
+    assert_eq!(nd.shape(), &[2, 2, 2]);
+    assert_eq!(nd.to_tensor::<2>().unwrap(), t);
+    assert!(nd.to_vector::<8>().is_err());
+    let _: Vector<i64, 8> = nd.reshape(&[8]).unwrap().to_vector().unwrap();
+}
@@ -0,0 +1,109 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::io::npy::{load_npy, read_npy, save_npy, write_npy};
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+// A version 1.0 file with the given header dictionary and payload
+fn npy_file(dict: &str, payload: &[u8]) -> Vec<u8> {
+    let mut header = dict.to_string();
+    while !(10 + header.len() + 1).is_multiple_of(64) {
+        header.push(' ');
+    }
+    header.push('\n');
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+#[test]
+fn npy_round_trips() {
+    let m: Matrix<f64, 3> = matrix![1.0, 2.5, -3.0; 4.0, 5.0, 6.0];
+    let mut bytes = Vec::new();
+    write_npy(&mut bytes, &m).unwrap();
+    assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    assert!((10 + header_len).is_multiple_of(64));
+    let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+    assert!(header.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }"));
+    assert_eq!(bytes.len(), 10 + header_len + 6 * 8);
+    assert_eq!(read_npy::<f64, 3, _>(&mut bytes.as_slice()).unwrap(), m);
+
+    let ints: Matrix<i32, 2> = matrix![1, -2; 3, 4; 5, 6];
+    let path = std::env::temp_dir().join(format!("tensor_npy_{}.npy", std::process::id()));
+    save_npy(&path, &ints).unwrap();
+    let loaded = load_npy::<i32, 2>(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), ints);
+}
+
+#[test]
+fn npy_reads_other_orders() {
+    // Big-endian, column-major 2x3 matrix [[1, 2, 3], [4, 5, 6]]
+    let payload: Vec<u8> = [1i64, 4, 2, 5, 3, 6]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let bytes = npy_file(
+        "{'descr': '>i8', 'fortran_order': True, 'shape': (2, 3), }",
+        &payload,
+    );
+    let m = read_npy::<i64, 3, _>(&mut bytes.as_slice()).unwrap();
+    assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+}
+
+#[test]
+fn npy_checks_dtype_and_shape() {
+    let m: Matrix<f32, 2> = matrix![1.0, 2.0];
+    let mut bytes = Vec::new();
+    write_npy(&mut bytes, &m).unwrap();
+
+    assert!(matches!(
+        read_npy::<f64, 2, _>(&mut bytes.as_slice()),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        read_npy::<f32, 3, _>(&mut bytes.as_slice()),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(read_npy::<f32, 2, _>(&mut &bytes[..bytes.len() - 1]).is_err());
+    assert!(read_npy::<f32, 2, _>(&mut &b"not an npy file"[..]).is_err());
+    let vector = npy_file(
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (2,), }",
+        &[0; 8],
+    );
+    assert!(read_npy::<f32, 2, _>(&mut vector.as_slice()).is_err());
+}
+
+#[test]
+fn npy_rejects_malformed_headers() {
+    let non_ascii = npy_file(
+        "{'descr': 'é8', 'fortran_order': False, 'shape': (1, 1), }",
+        &[0; 8],
+    );
+    assert!(read_npy::<f64, 1, _>(&mut non_ascii.as_slice()).is_err());
+
+    let huge = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (18446744073709551615, 2), }",
+        &[0; 16],
+    );
+    assert!(read_npy::<f64, 2, _>(&mut huge.as_slice()).is_err());
+    let overflow = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (4611686018427387904, 2), }",
+        &[0; 16],
+    );
+    assert!(read_npy::<f64, 2, _>(&mut overflow.as_slice()).is_err());
+
+    // A header length far beyond the end of the file
+    let mut long_header = b"\x93NUMPY\x02\x00".to_vec();
+    long_header.extend_from_slice(&u32::MAX.to_le_bytes());
+    long_header.extend_from_slice(b"{'descr': '<f8'");
+    assert!(read_npy::<f64, 2, _>(&mut long_header.as_slice()).is_err());
+
+    let short = npy_file(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1000000, 2), }",
+        &[0; 16],
+    );
+    assert!(read_npy::<f64, 2, _>(&mut short.as_slice()).is_err());
+}
@@ -0,0 +1,83 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::io::npz::{load_npz, read_npz, save_npz, write_npz, NpyArray};
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
+
+#[test]
+fn npz_round_trips_named_arrays() {
+    let w: Matrix<f32, 3> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let b: Matrix<f32, 3> = matrix![0.5, -0.5, 0.0];
+    let t = Tensor::from_matrices(vec![matrix![1i64, 2; 3, 4], matrix![5i64, 6; 7, 8]]).unwrap();
+    let mut bytes = Vec::new();
+    write_npz(&mut bytes, &[("weights", &w), ("bias", &b), ("stack", &t)]).unwrap();
+
+    let archive = read_npz(&mut bytes.as_slice()).unwrap();
+    assert_eq!(
+        archive.names().collect::<Vec<_>>(),
+        ["bias", "stack", "weights"]
+    );
+    assert_eq!(archive.matrix::<f32, 3>("weights").unwrap(), w);
+    assert_eq!(archive.matrix::<f32, 3>("bias").unwrap(), b);
+    assert_eq!(archive.tensor::<i64, 2>("stack").unwrap(), t);
+    assert!(matches!(
+        archive.matrix::<f32, 3>("missing"),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(archive.matrix::<f64, 3>("weights").is_err());
+
+    // The stored shape keeps the rows of a tensor with no depths
+    let empty = Tensor::<i64, 2>::new(0, 3);
+    let mut bytes = Vec::new();
+    write_npz(&mut bytes, &[("empty", &empty)]).unwrap();
+    let back = read_npz(&mut bytes.as_slice()).unwrap();
+    assert_eq!(back.tensor::<i64, 2>("empty").unwrap().shape(), (0, 3, 2));
+
+    let path = std::env::temp_dir().join(format!("tensor_npz_{}.npz", std::process::id()));
+    save_npz(&path, &[("weights", &w)]).unwrap();
+    let loaded = load_npz(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap().matrix::<f32, 3>("weights").unwrap(), w);
+}
+
+#[test]
+fn npz_rejects_corrupt_archives() {
+    let w: Matrix<f64, 2> = matrix![1.0, 2.0];
+    let mut bytes = Vec::new();
+    write_npz(&mut bytes, &[("w", &w)]).unwrap();
+    // Flip a payload byte so the checksum no longer matches
+    let last_payload = bytes.len() - 22 - 46 - "w.npy".len() - 1;
+    bytes[last_payload] ^= 0xff;
+    assert!(read_npz(&mut bytes.as_slice()).is_err());
+    assert!(read_npz(&mut &b"not a zip"[..]).is_err());
+}
+
+// An entry holding arbitrary bytes in place of a `.npy` file
+struct Raw(Vec<u8>);
+
+impl NpyArray for Raw {
+    fn write_npy_to(&self, out: &mut Vec<u8>) -> Result<(), TensorError> {
+        out.extend_from_slice(&self.0);
+        Ok(())
+    }
+}
+
+// A version 1.0 `.npy` header with no payload
+fn npy_header(dict: &str) -> Raw {
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(dict.len() as u16 + 1).to_le_bytes());
+    bytes.extend_from_slice(dict.as_bytes());
+    bytes.push(b'\n');
+    Raw(bytes)
+}
+
+#[test]
+fn npz_rejects_malformed_entries() {
+    let huge =
+        npy_header("{'descr': '<f8', 'fortran_order': False, 'shape': (4611686018427387904, 2), }");
+    let descr = npy_header("{'descr': 'é8', 'fortran_order': False, 'shape': (1, 2), }");
+    let mut bytes = Vec::new();
+    write_npz(&mut bytes, &[("huge", &huge), ("descr", &descr)]).unwrap();
+    let archive = read_npz(&mut bytes.as_slice()).unwrap();
+    assert!(archive.matrix::<f64, 2>("huge").is_err());
+    assert!(archive.matrix::<f64, 2>("descr").is_err());
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "num-traits")]
+
+use num_traits::{Num, NumCast, One, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use tensor_algebra_in_rust::tensor::{AllowedNumericTypes, Matrix, Vector};
+use tensor_algebra_in_rust::{impl_num_traits_element, matrix, vector};
+
+// A third-party-style integer type that only knows about num-traits
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Cents(i64);
+
+macro_rules! cents_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait for Cents {
+            type Output = Cents;
+            fn $method(self, rhs: Cents) -> Cents {
+                Cents(self.0 $op rhs.0)
+            }
+        }
+    };
+}
+
+cents_op!(Add, add, +);
+cents_op!(Sub, sub, -);
+cents_op!(Mul, mul, *);
+cents_op!(Div, div, /);
+cents_op!(Rem, rem, %);
+
+impl Zero for Cents {
+    fn zero() -> Self {
+        Cents(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Cents {
+    fn one() -> Self {
+        Cents(1)
+    }
+}
+
+impl Num for Cents {
+    type FromStrRadixErr = std::num::ParseIntError;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i64::from_str_radix(s, radix).map(Cents)
+    }
+}
+
+impl ToPrimitive for Cents {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.0)
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+}
+
+impl NumCast for Cents {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        n.to_i64().map(Cents)
+    }
+}
+
+impl_num_traits_element!(Cents);
+
+#[test]
+fn num_traits_types_work_as_elements() {
+    assert_eq!(Cents::from_f64(2.9), Cents(2));
+    assert_eq!(Cents::from_f64(f64::NAN), Cents(0));
+    assert_eq!(Cents(7).to_f64(), 7.0);
+
+    let v: Vector<Cents, 3> = vector![Cents(1), Cents(2), Cents(3)];
+    assert_eq!(v.dot(&v), Cents(14));
+
+    let m: Matrix<Cents, 2> = matrix![Cents(1), Cents(2); Cents(3), Cents(4)];
+    assert_eq!(
+        (&m * &m).unwrap(),
+        matrix![Cents(7), Cents(10); Cents(15), Cents(22)]
+    );
+}
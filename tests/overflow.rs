@@ -0,0 +1,63 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::overflow::Policy;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
+
+#[test]
+fn u8_image_math_saturates_or_wraps() {
+    let a: Matrix<u8, 2> = matrix![200, 10; 255, 0];
+    let b: Matrix<u8, 2> = matrix![100, 20; 1, 5];
+
+    assert_eq!(
+        a.add_policy(&b, Policy::Saturating).unwrap(),
+        matrix![255, 30; 255, 5]
+    );
+    assert_eq!(
+        a.add_policy(&b, Policy::Wrapping).unwrap(),
+        matrix![44, 30; 0, 5]
+    );
+    assert_eq!(
+        a.sub_policy(&b, Policy::Saturating).unwrap(),
+        matrix![100, 0; 254, 0]
+    );
+    assert_eq!(
+        a.scalar_mul_policy(2, Policy::Saturating).unwrap(),
+        matrix![255, 20; 255, 0]
+    );
+
+    assert!(matches!(
+        a.add_policy(&b, Policy::Checked),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    let small: Matrix<u8, 2> = matrix![1, 2; 3, 4];
+    assert_eq!(
+        small.add_policy(&small, Policy::Checked).unwrap(),
+        matrix![2, 4; 6, 8]
+    );
+}
+
+#[test]
+fn signed_vectors_and_tensors_follow_policy() {
+    let v = vector![i8::MIN, 100, -3];
+    let w = vector![1i8, 100, 4];
+    assert_eq!(
+        v.sub_policy(&w, Policy::Saturating).unwrap(),
+        vector![i8::MIN, 0, -7]
+    );
+    assert_eq!(
+        v.mul_policy(&w, Policy::Wrapping).unwrap(),
+        vector![i8::MIN, 16, -12]
+    );
+    assert!(v.mul_policy(&w, Policy::Checked).is_err());
+
+    let t: Tensor<i16, 1> = tensor![[[i16::MAX]]; [[-5]]];
+    assert_eq!(
+        t.add_policy(&t, Policy::Saturating).unwrap(),
+        tensor![[[i16::MAX]]; [[-10]]]
+    );
+    let short: Tensor<i16, 1> = tensor![[[1]]];
+    assert!(matches!(
+        t.add_policy(&short, Policy::Wrapping),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
@@ -0,0 +1,44 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::pad::PadMode;
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+use tensor_algebra_in_rust::{matrix, vector};
+
+#[test]
+fn vector_pad_modes() {
+    let v = vector![1, 2, 3];
+    let c: Vector<i32, 6> = v.pad(2, 1, PadMode::Constant(0)).unwrap();
+    assert_eq!(c, vector![0, 0, 1, 2, 3, 0]);
+    let e: Vector<i32, 6> = v.pad(2, 1, PadMode::Edge).unwrap();
+    assert_eq!(e, vector![1, 1, 1, 2, 3, 3]);
+    let r: Vector<i32, 9> = v.pad(3, 3, PadMode::Reflect).unwrap();
+    assert_eq!(r, vector![2, 3, 2, 1, 2, 3, 2, 1, 2]);
+
+    assert!(matches!(
+        v.pad::<5>(1, 2, PadMode::Edge),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn matrix_pad_modes() {
+    let m: Matrix<f64, 2> = matrix![1.0, 2.0; 3.0, 4.0];
+
+    let c: Matrix<f64, 3> = m.pad(1, 0, 0, 1, PadMode::Constant(9.0)).unwrap();
+    assert_eq!(c, matrix![9.0, 9.0, 9.0; 1.0, 2.0, 9.0; 3.0, 4.0, 9.0]);
+
+    let e: Matrix<f64, 4> = m.pad(0, 1, 1, 1, PadMode::Edge).unwrap();
+    assert_eq!(
+        e,
+        matrix![1.0, 1.0, 2.0, 2.0; 3.0, 3.0, 4.0, 4.0; 3.0, 3.0, 4.0, 4.0]
+    );
+
+    let r: Matrix<f64, 3> = m.pad(1, 0, 1, 0, PadMode::Reflect).unwrap();
+    assert_eq!(r, matrix![4.0, 3.0, 4.0; 2.0, 1.0, 2.0; 4.0, 3.0, 4.0]);
+
+    let empty: Matrix<f64, 2> = Matrix::new(0);
+    assert!(empty.pad::<2>(1, 0, 0, 0, PadMode::Reflect).is_err());
+    assert_eq!(
+        empty.pad::<2>(1, 0, 0, 0, PadMode::Constant(1.0)).unwrap(),
+        matrix![1.0, 1.0]
+    );
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "bytemuck")]
+
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+use tensor_algebra_in_rust::{matrix, vector};
+
+#[test]
+fn vectors_cast_and_matrices_round_trip_through_bytes() {
+    let rows = [vector![1.0f32, 2.0], vector![3.0, 4.0]];
+    let floats: &[f32] = bytemuck::cast_slice(&rows);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(rows[0].as_bytes(), bytemuck::bytes_of(&[1.0f32, 2.0]));
+    let zero: Vector<u16, 3> = bytemuck::Zeroable::zeroed();
+    assert_eq!(zero, vector![0, 0, 0]);
+
+    let mut m: Matrix<u32, 2> = matrix![1, 2; 3, 4];
+    assert_eq!(m.as_bytes().len(), 16);
+    m.as_bytes_mut()[..4].copy_from_slice(&9u32.to_ne_bytes());
+    assert_eq!(m[0][0], 9);
+
+    // Unaligned input is copied, not reinterpreted
+    let mut buffer = vec![0u8];
+    buffer.extend_from_slice(m.as_bytes());
+    assert_eq!(Matrix::<u32, 2>::from_bytes(&buffer[1..]), Ok(m));
+    assert!(matches!(
+        Matrix::<u32, 2>::from_bytes(&buffer[..7]),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
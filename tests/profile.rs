@@ -0,0 +1,55 @@
+#![cfg(feature = "profiling")]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::profile::{profile, reset_profile, set_profile_callback, OpStats};
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+use tensor_algebra_in_rust::workspace::Workspace;
+
+static CALLBACK_FLOPS: AtomicU64 = AtomicU64::new(0);
+
+#[test]
+fn operations_are_counted() {
+    reset_profile();
+    set_profile_callback(Some(|_, stats| {
+        CALLBACK_FLOPS.fetch_add(stats.flops, Ordering::Relaxed);
+    }));
+
+    let a: Matrix<f64, 3> = matrix![2.0, 0.0, 1.0; 1.0, 3.0, 0.0; 0.0, 1.0, 4.0];
+    let b = Matrix::<f64, 2>::new(3);
+    let mut out = Matrix::<f64, 2>::new(3);
+    (&a * &b).unwrap();
+    a.mat_mul_into(&b, &mut out).unwrap();
+    assert_eq!(
+        profile().get("matmul"),
+        OpStats {
+            calls: 2,
+            flops: 72,
+            allocations: 1,
+        }
+    );
+
+    let mut workspace = Workspace::new();
+    a.solve_in(&Vector::from([1.0, 2.0, 3.0]), 0.0, &mut workspace)
+        .unwrap();
+    a.solve_in(&Vector::from([3.0, 2.0, 1.0]), 0.0, &mut workspace)
+        .unwrap();
+    a.determinant().unwrap();
+    let snapshot = profile();
+    assert_eq!(snapshot.get("solve").calls, 2);
+    assert_eq!(snapshot.get("solve").allocations, 1);
+    assert_eq!(snapshot.get("determinant").calls, 1);
+    assert_eq!(snapshot.get("qr"), OpStats::default());
+    assert_eq!(
+        snapshot.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["determinant", "matmul", "solve"]
+    );
+    assert_eq!(
+        CALLBACK_FLOPS.load(Ordering::Relaxed),
+        snapshot.total().flops
+    );
+
+    set_profile_callback(None);
+    reset_profile();
+    assert_eq!(profile().total(), OpStats::default());
+}
@@ -0,0 +1,53 @@
+use tensor_algebra_in_rust::quasirandom::{Halton, Sobol};
+use tensor_algebra_in_rust::vector;
+
+#[test]
+fn sobol_first_points_match_reference() {
+    let mut s = Sobol::<2>::new().unwrap();
+    let m = s.points(8);
+    let expected = [
+        [0.0, 0.0],
+        [0.5, 0.5],
+        [0.75, 0.25],
+        [0.25, 0.75],
+        [0.375, 0.375],
+        [0.875, 0.875],
+        [0.625, 0.125],
+        [0.125, 0.625],
+    ];
+    for (i, e) in expected.iter().enumerate() {
+        assert_eq!(m[i], vector![e[0], e[1]]);
+    }
+
+    assert!(Sobol::<16>::new().is_ok());
+    assert!(Sobol::<17>::new().is_err());
+}
+
+#[test]
+fn sobol_points_stratify_every_dimension() {
+    // The first 2^k points hit each of the 2^k equal bins exactly once per axis
+    let m = Sobol::<16>::new().unwrap().points(64);
+    for d in 0..16 {
+        let mut seen = [false; 64];
+        for i in 0..64 {
+            let bin = (m[i][d] * 64.0) as usize;
+            assert!(!seen[bin], "dimension {} repeats bin {}", d, bin);
+            seen[bin] = true;
+        }
+    }
+}
+
+#[test]
+fn halton_uses_prime_bases() {
+    let mut h = Halton::<3>::new();
+    assert_eq!(h.next(), Some(vector![0.0, 0.0, 0.0]));
+    assert_eq!(h.next(), Some(vector![0.5, 1.0 / 3.0, 0.2]));
+    let p = h.nth(1).unwrap(); // index 3
+    let expected = [0.75, 1.0 / 9.0, 0.6];
+    for i in 0..3 {
+        assert!((p[i] - expected[i]).abs() < 1e-15);
+    }
+
+    let m = Halton::<2>::new().points(5);
+    assert_eq!(m.shape(), (5, 2));
+}
@@ -0,0 +1,34 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::ragged::RaggedMatrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn ragged_rows_reductions_and_padding() {
+    let r = RaggedMatrix::from_rows(vec![vec![1, 2, 3], vec![], vec![4, 5]]);
+    assert_eq!(r.rows(), 3);
+    assert_eq!(r.len(), 5);
+    assert_eq!(r.offsets(), &[0, 3, 3, 5]);
+    assert_eq!(r.row(2).unwrap(), &[4, 5]);
+    assert_eq!(r.max_row_len(), 3);
+    assert_eq!(
+        r.iter().map(|row| row.len()).collect::<Vec<_>>(),
+        vec![3, 0, 2]
+    );
+    assert_eq!(r.row_sums(), vec![6, 0, 9]);
+    assert_eq!(r.map(|x| x * 10).row(0).unwrap(), &[10, 20, 30]);
+
+    let (padded, mask): (Matrix<i32, 3>, _) = r.to_padded(-1).unwrap();
+    assert_eq!(padded, matrix![1, 2, 3; -1, -1, -1; 4, 5, -1]);
+    assert_eq!(mask, matrix![1, 1, 1; 0, 0, 0; 1, 1, 0]);
+
+    assert!(r.to_padded::<2>(0).is_err());
+    assert!(matches!(r.row(3), Err(TensorError::OutOfBounds { .. })));
+}
+
+#[test]
+fn ragged_new_validates_offsets() {
+    let r = RaggedMatrix::new(vec![1.0, 2.0, 3.0], vec![0, 1, 3]).unwrap();
+    assert_eq!(r.row(1).unwrap(), &[2.0, 3.0]);
+    assert!(RaggedMatrix::new(vec![1.0, 2.0], vec![0, 3]).is_err());
+}
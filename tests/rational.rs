@@ -0,0 +1,53 @@
+#![cfg(feature = "rational")]
+
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::rational::Ratio;
+use tensor_algebra_in_rust::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+fn r(n: i64, d: i64) -> Ratio<i64> {
+    Ratio::new(n, d)
+}
+
+fn from_ints(m: Matrix<i64, 3>) -> Matrix<Ratio<i64>, 3> {
+    m.map(Ratio::from_integer)
+}
+
+#[test]
+fn rational_inverse_and_determinant_are_exact() {
+    let a = from_ints(matrix![2, 1, 1; 1, 3, 2; 1, 0, 0]);
+    assert_eq!(a.determinant_bareiss().unwrap(), r(-1, 1));
+
+    let inv = a.inverse().unwrap();
+    assert_eq!(inv, from_ints(matrix![0, 0, 1; -2, 1, 3; 3, -1, -5]));
+    assert_eq!(
+        (&a * &inv).unwrap(),
+        from_ints(matrix![1, 0, 0; 0, 1, 0; 0, 0, 1])
+    );
+
+    // A Hilbert matrix is notoriously ill-conditioned in floating point
+    let hilbert: Matrix<Ratio<i64>, 3> = Matrix::from_vectors(
+        (0..3)
+            .map(|i| Vector::from(std::array::from_fn(|j| r(1, i + j as i64 + 1))))
+            .collect(),
+    );
+    assert_eq!(hilbert.determinant_bareiss().unwrap(), r(1, 2160));
+    assert_eq!(hilbert.inverse().unwrap()[1][1], r(192, 1));
+}
+
+#[test]
+fn rational_rref_and_errors() {
+    let a = from_ints(matrix![1, 2, 3; 2, 4, 7; 3, 6, 10]);
+    assert_eq!(a.rref(), from_ints(matrix![1, 2, 0; 0, 0, 1; 0, 0, 0]));
+    assert_eq!(a.determinant_bareiss().unwrap(), Ratio::zero());
+    assert!(matches!(a.inverse(), Err(TensorError::InvalidOperation(_))));
+
+    let wide: Matrix<Ratio<i32>, 3> = Matrix::new(2);
+    assert!(matches!(
+        wide.inverse(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    assert_eq!(Ratio::<i64>::from_f64(0.75), r(3, 4));
+    assert_eq!(r(1, 3).to_f64(), 1.0 / 3.0);
+}
@@ -0,0 +1,110 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+use tensor_algebra_in_rust::{matrix, vector};
+
+#[test]
+fn extrema_report_nan_instead_of_panicking() {
+    let v = vector![3.0, -1.0, 7.0, 7.0, -1.0];
+    assert_eq!(v.max_element().unwrap(), 7.0);
+    assert_eq!(v.min_element().unwrap(), -1.0);
+    // Ties resolve to the first occurrence
+    assert_eq!(v.argmax().unwrap(), 2);
+    assert_eq!(v.argmin().unwrap(), 1);
+
+    let with_nan = vector![1.0, f64::NAN, 4.0, -2.0];
+    assert!(matches!(
+        with_nan.max_element(),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert!(with_nan.argmin().is_err());
+    assert_eq!(with_nan.nanmax().unwrap(), 4.0);
+    assert_eq!(with_nan.nanargmin().unwrap(), 3);
+
+    let all_nan = vector![f64::NAN, f64::NAN];
+    assert!(all_nan.nanmax().is_err());
+    let empty: Vector<i32, 0> = Vector::new();
+    assert!(empty.max_element().is_err());
+    assert_eq!(vector![4u8, 9, 2].argmax().unwrap(), 1);
+}
+
+#[test]
+fn matrix_extrema_use_row_major_positions() {
+    let m: Matrix<f32, 3> = matrix![1.0, 5.0, -3.0; 5.0, f32::NAN, 0.0];
+    assert!(m.max().is_err());
+    assert_eq!(m.nanmax().unwrap(), 5.0);
+    assert_eq!(m.nanargmax().unwrap(), (0, 1));
+    assert_eq!(m.nanargmin().unwrap(), (0, 2));
+
+    let ints: Matrix<i64, 2> = matrix![4, -8; 12, 0];
+    assert_eq!(ints.argmax().unwrap(), (1, 0));
+    assert_eq!(ints.min().unwrap(), -8);
+    assert!(Matrix::<i64, 2>::new(0).argmin().is_err());
+}
+
+#[test]
+fn matrix_sum_and_mean() {
+    let m: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 7];
+    assert_eq!(m.sum(), 22);
+    assert_eq!(m.mean().unwrap(), 3);
+    let f: Matrix<f64, 2> = matrix![1.0, 2.0; 3.0, 4.0];
+    assert_eq!(f.mean().unwrap(), 2.5);
+    assert!(matches!(
+        Matrix::<f64, 2>::new(0).mean(),
+        Err(TensorError::InvalidOperation(_))
+    ));
+
+    // 400 elements cannot be counted in u8
+    assert!(matches!(
+        Matrix::<u8, 20>::new(20).mean(),
+        Err(TensorError::InvalidOperation(_))
+    ));
+    assert_eq!(Matrix::<u8, 15>::new(17).map(|_| 1).mean().unwrap(), 1);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn large_reductions_run_in_parallel_with_serial_results() {
+    // 40000 elements, above the parallel threshold
+    let m = Matrix::<i64, 100>::new(400).map(|_| 3);
+    assert_eq!(m.sum(), 120000);
+    assert_eq!(m.mean().unwrap(), 3);
+
+    let mut f = Matrix::<f64, 100>::new(400);
+    f[(123, 45)] = 9.0;
+    f[(300, 2)] = 9.0;
+    assert_eq!(f.max().unwrap(), 9.0);
+    f[(390, 0)] = f64::NAN;
+    f[(350, 7)] = f64::NAN;
+    assert!(f
+        .max()
+        .unwrap_err()
+        .to_string()
+        .contains("flat index 35007"));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn deterministic_parallel_sum_is_reproducible() {
+    let mut i = 0.0f64;
+    let m = Matrix::<f64, 7>::new(5000).map(|_| {
+        i += 1.0;
+        1.0 / i
+    });
+    let expected = m.par_sum_deterministic();
+    for threads in [1, 2, 3, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            let sum = pool.install(|| m.par_sum_deterministic());
+            assert_eq!(sum.to_bits(), expected.to_bits());
+        }
+    }
+    assert!((expected - m.sum()).abs() < 1e-9);
+    assert_eq!(m.par_mean_deterministic().unwrap(), expected / 35000.0);
+
+    let t = tensor_algebra_in_rust::tensor::Tensor::from_matrices(vec![m.clone(), m]).unwrap();
+    assert!((t.par_sum_deterministic() - 2.0 * expected).abs() < 1e-9);
+    assert_eq!(Matrix::<f64, 3>::new(0).par_sum_deterministic(), 0.0);
+}
@@ -0,0 +1,33 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::rolling::RollingMatrix;
+use tensor_algebra_in_rust::{matrix, vector};
+
+#[test]
+fn rolling_window_keeps_latest_rows_in_order() {
+    let mut w = RollingMatrix::<i32, 2>::new(3);
+    assert!(w.is_empty());
+    assert_eq!(w.latest(), None);
+
+    for i in 0..3 {
+        assert_eq!(w.push(vector![i, i * 10]), None);
+    }
+    assert!(w.is_full());
+    assert_eq!(w.push(vector![3, 30]), Some(vector![0, 0]));
+    assert_eq!(w.push(vector![4, 40]), Some(vector![1, 10]));
+
+    assert_eq!(w.len(), 3);
+    assert_eq!(w.as_matrix(), matrix![2, 20; 3, 30; 4, 40]);
+    assert_eq!(w.get(0).unwrap(), &vector![2, 20]);
+    assert_eq!(w.latest(), Some(&vector![4, 40]));
+    assert!(matches!(w.get(3), Err(TensorError::OutOfBounds { .. })));
+
+    w.clear();
+    assert_eq!(w.as_matrix().shape(), (0, 2));
+}
+
+#[test]
+fn zero_capacity_window_discards_pushes() {
+    let mut w = RollingMatrix::<f64, 1>::new(0);
+    assert_eq!(w.push(vector![1.0]), Some(vector![1.0]));
+    assert!(w.is_empty());
+}
@@ -0,0 +1,35 @@
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+use tensor_algebra_in_rust::vector;
+
+#[test]
+fn serial_scans() {
+    let v = vector![1, 2, 3, 4];
+    assert_eq!(v.cumsum(), vector![1, 3, 6, 10]);
+    assert_eq!(v.cumprod(), vector![1, 2, 6, 24]);
+
+    let m: Matrix<f64, 3> = matrix![1.0, 1.0, 1.0; 2.0, 0.5, 4.0];
+    assert_eq!(m.cumsum_rows(), matrix![1.0, 2.0, 3.0; 2.0, 2.5, 6.5]);
+    assert_eq!(m.cumprod_rows(), matrix![1.0, 1.0, 1.0; 2.0, 1.0, 4.0]);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_scans_match_serial() {
+    use tensor_algebra_in_rust::tensor::Vector;
+
+    // Long enough to be split into several blocks
+    let v = Vector::<i64, 20_000>::new()
+        .map(|_| 1)
+        .cumsum()
+        .map(|x| x % 7);
+    assert_eq!(v.par_cumsum(), v.cumsum());
+
+    let ones = Vector::<u64, 10_000>::new().scalar_add(1);
+    assert_eq!(ones.par_cumprod(), ones);
+
+    let m = Matrix::<i64, 10_000>::from_vectors(vec![Vector::new().scalar_add(2); 3]);
+    assert_eq!(m.par_cumsum_rows(), m.cumsum_rows());
+    let small: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(small.par_cumprod_rows(), small.cumprod_rows());
+}
@@ -0,0 +1,29 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::segment::{segmented_sum, segmented_sum_cols, segmented_sum_rows};
+use tensor_algebra_in_rust::tensor::Matrix;
+use tensor_algebra_in_rust::{matrix, vector};
+
+#[test]
+fn segmented_sums_over_packed_sequences() {
+    let v = vector![1, 2, 3, 4, 5, 6];
+    assert_eq!(segmented_sum(&v, &[0, 3, 3, 6]).unwrap(), vec![6, 0, 15]);
+    assert_eq!(segmented_sum(&v, &[0, 6]).unwrap(), vec![21]);
+
+    let m: Matrix<f64, 2> = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+    let rows = segmented_sum_rows(&m, &[0, 2, 3]).unwrap();
+    assert_eq!(rows, matrix![4.0, 6.0; 5.0, 6.0]);
+
+    let cols = segmented_sum_cols(&m, &[0, 1, 2]).unwrap();
+    assert_eq!(cols, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+}
+
+#[test]
+fn segmented_sum_rejects_bad_offsets() {
+    let v = vector![1, 2, 3];
+    for offsets in [&[][..], &[1, 3], &[0, 2], &[0, 2, 1, 3]] {
+        assert!(matches!(
+            segmented_sum(&v, offsets),
+            Err(TensorError::InvalidOperation(_))
+        ));
+    }
+}
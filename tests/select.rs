@@ -0,0 +1,58 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn top_k_per_row_matches_full_sort() {
+    let m: Matrix<f64, 6> = matrix![
+        0.1, 0.9, 0.3, 0.7, 0.5, 0.2;
+        4.0, 4.0, -1.0, 2.0, 4.0, 0.0
+    ];
+    let top = m.top_k_per_row(3).unwrap();
+    assert_eq!(top.values, vec![vec![0.9, 0.7, 0.5], vec![4.0, 4.0, 4.0]]);
+    assert_eq!(top.indices, vec![vec![1, 3, 4], vec![0, 1, 4]]);
+
+    let all = m.top_k_per_row(6).unwrap();
+    assert_eq!(all.indices[0], vec![1, 3, 4, 2, 5, 0]);
+
+    let none = m.top_k_per_row(0).unwrap();
+    assert_eq!(none.values, vec![Vec::<f64>::new(); 2]);
+}
+
+#[test]
+fn top_k_per_row_ranks_nans_last() {
+    let nan = f64::NAN;
+    let m: Matrix<f64, 6> = matrix![
+        nan, 1.0, nan, 3.0, 2.0, nan;
+        nan, nan, nan, nan, nan, nan
+    ];
+    let top = m.top_k_per_row(4).unwrap();
+    assert_eq!(top.values[0][..3], [3.0, 2.0, 1.0]);
+    assert_eq!(top.indices, vec![vec![3, 4, 1, 0], vec![0, 1, 2, 3]]);
+    assert!(top.values[1].iter().all(|x| x.is_nan()));
+
+    let all = m.top_k_per_row(6).unwrap();
+    assert_eq!(all.indices[0], vec![3, 4, 1, 0, 2, 5]);
+
+    // Long enough rows for the sorts to check their comparator
+    let wide: Matrix<f64, 64> = Matrix::from_fn(3, |i, j| {
+        if (i + j) % 3 == 0 {
+            nan
+        } else {
+            ((j * 37) % 64) as f64
+        }
+    });
+    for row in wide.top_k_per_row(50).unwrap().values {
+        assert!(row[..42].windows(2).all(|w| w[0] >= w[1]));
+        assert!(row[43..].iter().all(|x| x.is_nan()));
+    }
+}
+
+#[test]
+fn top_k_per_row_rejects_k_larger_than_row() {
+    let m: Matrix<i32, 2> = matrix![1, 2];
+    assert!(matches!(
+        m.top_k_per_row(3),
+        Err(TensorError::InvalidOperation(_))
+    ));
+}
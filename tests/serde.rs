@@ -0,0 +1,93 @@
+#![cfg(feature = "serde")]
+
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::tensor::{Matrix, Tensor, Vector};
+use tensor_algebra_in_rust::{matrix, tensor, vector};
+
+#[test]
+fn values_round_trip_through_json() {
+    let v = vector![1.5, -2.0, 3.0];
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.5,-2.0,3.0]");
+    assert_eq!(
+        serde_json::from_str::<Vector<f64, 3>>("[1.5,-2.0,3.0]").unwrap(),
+        v
+    );
+
+    let m: Matrix<i32, 2> = matrix![1, 2; 3, 4];
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, "[[1,2],[3,4]]");
+    assert_eq!(serde_json::from_str::<Matrix<i32, 2>>(&json).unwrap(), m);
+
+    let t = tensor![[[1, 2]; [3, 4]]; [[5, 6]; [7, 8]]];
+    let json = serde_json::to_string(&t).unwrap();
+    assert_eq!(serde_json::from_str::<Tensor<i32, 2>>(&json).unwrap(), t);
+
+    // Nested arrays have no room for the rows of a tensor with no depths
+    let json = serde_json::to_string(&Tensor::<i32, 2>::new(0, 3)).unwrap();
+    assert_eq!(json, "[]");
+    let back = serde_json::from_str::<Tensor<i32, 2>>(&json).unwrap();
+    assert_eq!(back.shape(), (0, 0, 2));
+    let m = Matrix::<i32, 0>::new(3);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(serde_json::from_str::<Matrix<i32, 0>>(&json).unwrap(), m);
+}
+
+#[test]
+fn deserializing_checks_shapes() {
+    let short = serde_json::from_str::<Matrix<i32, 3>>("[[1,2,3],[4,5]]").unwrap_err();
+    assert!(short.to_string().contains("invalid length 2"));
+    assert!(serde_json::from_str::<Vector<i32, 2>>("[1,2,3]").is_err());
+
+    let ragged = serde_json::from_str::<Tensor<i32, 1>>("[[[1],[2]],[[3]]]").unwrap_err();
+    assert!(ragged.to_string().contains("Tensor from_matrices"));
+}
+
+#[test]
+fn json_helpers_map_errors() {
+    let m: Matrix<f64, 2> = matrix![1.0, -0.5; 3.0, 4.0];
+    assert_eq!(m.to_json().unwrap(), "[[1.0,-0.5],[3.0,4.0]]");
+    assert_eq!(
+        Matrix::<f64, 2>::from_json(&m.to_json().unwrap()).unwrap(),
+        m
+    );
+    assert_eq!(vector![1, 2].to_json().unwrap(), "[1,2]");
+    assert_eq!(
+        Vector::<i32, 2>::from_json("[1, 2]").unwrap(),
+        vector![1, 2]
+    );
+    let t = tensor![[[1, 2]]; [[3, 4]]];
+    assert_eq!(
+        Tensor::<i32, 2>::from_json(&t.to_json().unwrap()).unwrap(),
+        t
+    );
+    for x in [f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            matrix![1.0, x].to_json(),
+            Err(TensorError::InvalidOperation(_))
+        ));
+    }
+
+    match Matrix::<i32, 2>::from_json("[[1,2],[3]]") {
+        Err(TensorError::DimensionMismatch {
+            expected,
+            found,
+            operation,
+        }) => {
+            assert_eq!((expected.as_str(), found.as_str()), ("1x2", "1x1"));
+            assert!(operation.contains("row 1"));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(
+        Vector::<i32, 3>::from_json("[1,2]"),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        Tensor::<i32, 1>::from_json("[[[1],[2]],[[3]]]"),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        Matrix::<i32, 2>::from_json("[[1,2"),
+        Err(TensorError::Other(_))
+    ));
+}
@@ -0,0 +1,28 @@
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::shared::SharedMatrix;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn clones_share_until_mutated() {
+    let m: Matrix<f64, 2> = matrix![1.0, 2.0; 3.0, 4.0];
+    let a = SharedMatrix::from(m.clone());
+    let mut b = a.clone();
+    assert!(a.ptr_eq(&b));
+    assert_eq!(a.share_count(), 2);
+
+    // Read-only operations go through Deref without copying
+    assert_eq!(b.shape(), (2, 2));
+    assert_eq!((&*a * &*b).unwrap(), (&m * &m).unwrap());
+
+    b.make_mut()[0][0] = 10.0;
+    assert!(!a.ptr_eq(&b));
+    assert_eq!(*a, m);
+    assert_eq!(b[0][0], 10.0);
+
+    // Sole owners mutate and unwrap in place
+    let before: *const Matrix<f64, 2> = &*b;
+    b.make_mut().scale_in_place(2.0);
+    assert!(std::ptr::eq(before, &*b));
+    assert_eq!(b.into_matrix(), matrix![20.0, 4.0; 6.0, 8.0]);
+    assert_eq!(a.into_matrix(), m);
+}
@@ -0,0 +1,41 @@
+use tensor_algebra_in_rust::tensor::{AllowedNumericTypes, Matrix, Vector};
+
+// Lengths chosen to leave a scalar tail after the SIMD lanes
+#[test]
+fn dot_and_axpy_kernels_handle_tails() {
+    let a: Vector<f64, 11> = Vector::from(std::array::from_fn(|i| i as f64 * 0.5));
+    let b: Vector<f64, 11> = Vector::from(std::array::from_fn(|i| 3.0 - i as f64));
+    let expected: f64 = (0..11).map(|i| i as f64 * 0.5 * (3.0 - i as f64)).sum();
+    assert!((a.dot(&b) - expected).abs() < 1e-12);
+
+    let x: [f32; 19] = std::array::from_fn(|i| i as f32);
+    let mut acc = [1.0f32; 19];
+    f32::axpy_slices(&mut acc, 2.0, &x);
+    assert_eq!(acc, std::array::from_fn(|i| 1.0 + 2.0 * i as f32));
+    assert_eq!(
+        f32::dot_slices(&x, &x),
+        (0..19).map(|i| (i * i) as f32).sum::<f32>()
+    );
+    assert_eq!(i32::dot_slices(&[1, 2, 3], &[4, 5, 6]), 32);
+}
+
+#[test]
+fn matmul_agrees_with_reference_for_wide_rows() {
+    let a: Matrix<f32, 13> = Matrix::from_vectors(
+        (0..3)
+            .map(|r| Vector::from(std::array::from_fn(|c| (r * 13 + c) as f32 * 0.25)))
+            .collect(),
+    );
+    let b: Matrix<f32, 9> = Matrix::from_vectors(
+        (0..13)
+            .map(|r| Vector::from(std::array::from_fn(|c| 1.0 - (r + c) as f32 * 0.125)))
+            .collect(),
+    );
+    let product = (&a * &b).unwrap();
+    for r in 0..3 {
+        for c in 0..9 {
+            let expected: f64 = (0..13).map(|k| a[r][k] as f64 * b[k][c] as f64).sum();
+            assert!((product[r][c] as f64 - expected).abs() < 1e-3);
+        }
+    }
+}
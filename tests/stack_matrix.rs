@@ -0,0 +1,91 @@
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::stack_matrix::{SMatrix, StackMatrix};
+use tensor_algebra_in_rust::tensor::Matrix;
+
+#[test]
+fn stack_matrix_core_ops() {
+    let a = StackMatrix::from([[1, 2, 3], [4, 5, 6]]);
+    let b = StackMatrix::from([[1, 0], [0, 1], [1, 1]]);
+
+    let p: StackMatrix<i32, 2, 2> = a * b;
+    assert_eq!(p, StackMatrix::from([[4, 5], [10, 11]]));
+    assert_eq!(a.transpose(), StackMatrix::from([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(a + a, a.scalar_mul(2));
+    assert_eq!(a - a, StackMatrix::new());
+    assert_eq!(p * StackMatrix::identity(), p);
+
+    let m: Matrix<i32, 3> = a.to_matrix();
+    assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+    assert_eq!(StackMatrix::<i32, 2, 3>::from_matrix(&m).unwrap(), a);
+    assert!(StackMatrix::<i32, 3, 3>::from_matrix(&m).is_err());
+}
+
+#[test]
+fn stack_matrix_determinant_and_inverse() {
+    let a: StackMatrix<f64, 3, 3> =
+        StackMatrix::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+    assert!((a.determinant() + 5.0).abs() < 1e-12);
+
+    let inv = a.inverse().unwrap();
+    let id = a * inv;
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((id[i][j] - expected).abs() < 1e-12);
+        }
+    }
+
+    let singular = StackMatrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(singular.inverse().is_err());
+}
+
+#[test]
+fn stack_matrix_pivot_tolerance() {
+    let nearly = StackMatrix::from([[1.0, 2.0], [1.0, 2.0 + 1e-14]]);
+    assert!(nearly.inverse().is_ok());
+    assert!(nearly.inverse_with_tol(1e-10).is_err());
+    assert_eq!(nearly.determinant_with_tol(1e-10), 0.0);
+}
+
+#[test]
+fn closed_form_inverse_for_small_sizes() {
+    let m: StackMatrix<f64, 4, 4> = StackMatrix::from([
+        [4.0, 7.0, 2.0, 0.0],
+        [3.0, 6.0, 1.0, 1.0],
+        [2.0, 5.0, 3.0, -1.0],
+        [0.0, 1.0, 0.0, 2.0],
+    ]);
+    let product = m * m.inverse().unwrap();
+    let eliminated = m.inverse_with_tol(0.0).unwrap();
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((product[i][j] - expected).abs() < 1e-12);
+            assert!((m.inverse().unwrap()[i][j] - eliminated[i][j]).abs() < 1e-12);
+        }
+    }
+    assert!((m.determinant() - m.determinant_with_tol(0.0)).abs() < 1e-12);
+
+    let two = StackMatrix::from([[2.0, 1.0], [7.0, 4.0]]);
+    assert_eq!(
+        two.inverse().unwrap(),
+        StackMatrix::from([[4.0, -1.0], [-7.0, 2.0]])
+    );
+    let singular = StackMatrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(singular.inverse().is_err());
+}
+
+#[test]
+fn smatrix_converts_with_std_traits() {
+    let s: SMatrix<i32, 2, 3> = SMatrix::from([[1, 2, 3], [4, 5, 6]]);
+    let copy = s;
+    assert_eq!(copy, s);
+
+    let m: Matrix<i32, 3> = s.into();
+    assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+    assert_eq!(SMatrix::<i32, 2, 3>::try_from(&m), Ok(s));
+    assert!(SMatrix::<i32, 3, 3>::try_from(&m).is_err());
+    assert_eq!(<[[i32; 3]; 2]>::from(s), [[1, 2, 3], [4, 5, 6]]);
+}
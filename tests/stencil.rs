@@ -0,0 +1,99 @@
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::stencil::{discrete_laplacian_1d, discrete_laplacian_2d, Boundary};
+use tensor_algebra_in_rust::tensor::Matrix;
+use tensor_algebra_in_rust::vector;
+
+fn laplacian() -> Matrix<i32, 3> {
+    matrix![0, 1, 0; 1, -4, 1; 0, 1, 0]
+}
+
+#[test]
+fn stencil_boundary_policies() {
+    let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let k = laplacian();
+
+    let zero = m.apply_stencil(&k, Boundary::Zero).unwrap();
+    assert_eq!(zero[1][1], 2 + 4 + 6 + 8 - 20);
+    assert_eq!(zero[0][0], 2 + 4 - 4);
+
+    let clamp = m.apply_stencil(&k, Boundary::Clamp).unwrap();
+    assert_eq!(clamp[0][0], 1 + 2 + 1 + 4 - 4);
+
+    let periodic = m.apply_stencil(&k, Boundary::Periodic).unwrap();
+    assert_eq!(periodic[0][0], 7 + 2 + 3 + 4 - 4);
+    assert_eq!(periodic[2][2], 6 + 8 + 7 + 3 - 36);
+
+    // A Laplacian of a constant field vanishes with periodic or clamped edges
+    let c = Matrix::<i32, 4>::from_vectors(vec![vector![2, 2, 2, 2]; 3]);
+    assert_eq!(
+        c.apply_stencil(&k, Boundary::Periodic).unwrap(),
+        Matrix::new(3)
+    );
+    assert_eq!(
+        c.apply_stencil(&k, Boundary::Clamp).unwrap(),
+        Matrix::new(3)
+    );
+}
+
+#[test]
+fn stencil_steps_match_repeated_application() {
+    let m: Matrix<f64, 4> = matrix![0.0, 0.0, 0.0, 0.0; 0.0, 1.0, 0.0, 0.0; 0.0, 0.0, 0.0, 0.0];
+    let k = matrix![0.0, 0.1, 0.0; 0.1, 0.6, 0.1; 0.0, 0.1, 0.0];
+
+    let mut expected = m.clone();
+    for _ in 0..5 {
+        expected = expected.apply_stencil(&k, Boundary::Periodic).unwrap();
+    }
+    let mut stepped = m.clone();
+    stepped
+        .apply_stencil_steps(&k, Boundary::Periodic, 5)
+        .unwrap();
+    assert_eq!(stepped, expected);
+
+    // Periodic diffusion conserves mass
+    let total: f64 = (0..3).map(|r| stepped[r].iter().sum::<f64>()).sum();
+    assert!((total - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn stencil_shape_errors() {
+    let m = matrix![1, 2; 3, 4];
+    let bad_kernel = Matrix::<i32, 3>::new(2);
+    assert!(m.apply_stencil(&bad_kernel, Boundary::Zero).is_err());
+
+    let mut out = Matrix::<i32, 2>::new(3);
+    assert!(m
+        .apply_stencil_into(&laplacian(), Boundary::Zero, &mut out)
+        .is_err());
+}
+
+#[test]
+fn laplacian_operators_and_gradient() {
+    let l1 = discrete_laplacian_1d::<f64, 4>();
+    assert_eq!(l1[0], vector![-2.0, 1.0, 0.0, 0.0]);
+    assert_eq!(l1[2], vector![0.0, 1.0, -2.0, 1.0]);
+
+    // 3 columns x 2 rows grid
+    let l2 = discrete_laplacian_2d::<f64, 6>(3, 2).unwrap();
+    assert_eq!(l2[0], vector![-4.0, 1.0, 0.0, 1.0, 0.0, 0.0]);
+    assert_eq!(l2[4], vector![0.0, 1.0, 0.0, 1.0, -4.0, 1.0]);
+    assert!(discrete_laplacian_2d::<f64, 6>(4, 2).is_err());
+
+    // Operator and stencil agree on a flattened field with zero boundaries
+    let field: Matrix<f64, 3> = matrix![1.0, 2.0, 4.0; 3.0, 5.0, 8.0];
+    let k = matrix![0.0, 1.0, 0.0; 1.0, -4.0, 1.0; 0.0, 1.0, 0.0];
+    let via_stencil = field.apply_stencil(&k, Boundary::Zero).unwrap();
+    let flat = vector![1.0, 2.0, 4.0, 3.0, 5.0, 8.0];
+    let via_operator = l2.mat_vec_mul(&flat).unwrap();
+    for y in 0..2 {
+        for x in 0..3 {
+            assert_eq!(via_operator[y * 3 + x], via_stencil[y][x]);
+        }
+    }
+
+    let (dx, dy) = field.gradient();
+    assert_eq!(dx[0], vector![1.0, 2.0, 0.0]);
+    assert_eq!(dx[1], vector![2.0, 3.0, 0.0]);
+    assert_eq!(dy[0], vector![2.0, 3.0, 4.0]);
+    assert_eq!(dy[1], vector![0.0, 0.0, 0.0]);
+}
@@ -0,0 +1,81 @@
+use std::io::Cursor;
+use tensor_algebra_in_rust::distributions::Uniform;
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::io::binary::{read_binary, write_binary, Endian};
+use tensor_algebra_in_rust::stream::matmul_streamed;
+use tensor_algebra_in_rust::tensor::Matrix;
+
+fn to_bytes<const N: usize>(m: &Matrix<f64, N>, endian: Endian) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    write_binary(&mut bytes, m, endian).unwrap();
+    bytes
+}
+
+#[test]
+fn streamed_product_matches_in_memory_product() {
+    let dist = Uniform::new(-1.0, 1.0).unwrap();
+    let a = Matrix::<f64, 3>::random_from(&dist, 7, 11).unwrap();
+    let b = Matrix::<f64, 2>::random_from(&dist, 3, 12).unwrap();
+    let expected = (&a * &b).unwrap();
+
+    for (block_rows, endian) in [
+        (1, Endian::Little),
+        (2, Endian::Big),
+        (3, Endian::Native),
+        (10, Endian::Little),
+    ] {
+        let mut out = Vec::new();
+        let rows = matmul_streamed::<_, _, _, 3, 2>(
+            Cursor::new(to_bytes(&a, endian)),
+            Cursor::new(to_bytes(&b, Endian::Big)),
+            &mut out,
+            block_rows,
+        )
+        .unwrap();
+        assert_eq!(rows, 7);
+        let c: Matrix<f64, 2> = read_binary(&mut out.as_slice()).unwrap();
+        assert_eq!(c.shape(), (7, 2));
+        for (x, y) in c.as_slice().iter().zip(expected.as_slice()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn streamed_product_checks_headers_and_lengths() {
+    let a = Matrix::<f64, 3>::new(2);
+    let b = Matrix::<f64, 2>::new(3);
+    let run = |a: Vec<u8>, b: Vec<u8>| {
+        matmul_streamed::<_, _, _, 3, 2>(Cursor::new(a), Cursor::new(b), Vec::new(), 2)
+    };
+
+    // Truncated data, a B with the wrong row count, and extra rows of B
+    let mut short_a = to_bytes(&a, Endian::Little);
+    short_a.truncate(short_a.len() - 4);
+    assert!(run(short_a, to_bytes(&b, Endian::Little)).is_err());
+    for rows in [2, 4] {
+        assert!(matches!(
+            run(
+                to_bytes(&a, Endian::Little),
+                to_bytes(&Matrix::<f64, 2>::new(rows), Endian::Little)
+            ),
+            Err(TensorError::DimensionMismatch { .. })
+        ));
+    }
+    assert!(matches!(
+        run(
+            to_bytes(&Matrix::<f64, 4>::new(2), Endian::Little),
+            to_bytes(&b, Endian::Little)
+        ),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+
+    // Headerless data and other element types are rejected
+    assert!(run(vec![0; 3 * 8], to_bytes(&b, Endian::Little)).is_err());
+    let mut ints = Vec::new();
+    write_binary(&mut ints, &Matrix::<i64, 3>::new(2), Endian::Little).unwrap();
+    assert!(matches!(
+        run(ints, to_bytes(&b, Endian::Little)),
+        Err(TensorError::InvalidOperation(_))
+    ));
+}
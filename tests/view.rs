@@ -0,0 +1,91 @@
+use tensor_algebra_in_rust::error::TensorError;
+use tensor_algebra_in_rust::matrix;
+use tensor_algebra_in_rust::tensor::{Matrix, Vector};
+
+#[test]
+fn rows_cols_and_submatrices_borrow() {
+    let m: Matrix<i32, 3> = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+
+    assert_eq!(m.row(1).unwrap().to_vec(), vec![4, 5, 6]);
+    let col = m.col(2).unwrap();
+    assert_eq!(col.to_vec(), vec![3, 6, 9]);
+    assert_eq!(col.get(1), Some(6));
+    assert_eq!(col.get(3), None);
+    assert_eq!(m.row(0).unwrap().dot(&col).unwrap(), 42);
+    assert_eq!((m.col(0).unwrap() + col).unwrap(), vec![4, 10, 16]);
+    assert_eq!((col - m.col(1).unwrap()).unwrap(), vec![1, 1, 1]);
+    assert_eq!((col * col).unwrap(), vec![9, 36, 81]);
+
+    let sub = m.submatrix::<2, _>(1.., 1).unwrap();
+    assert_eq!(sub.shape(), (2, 2));
+    assert_eq!(sub.to_matrix(), matrix![5, 6; 8, 9]);
+    assert_eq!(sub.col(0).unwrap().to_vec(), vec![5, 8]);
+    assert_eq!(
+        sub.row(1).unwrap().to_vector::<2>().unwrap(),
+        Vector::from([8, 9])
+    );
+
+    let top = m.submatrix::<2, _>(..2, 0).unwrap();
+    assert_eq!((top + sub).unwrap(), matrix![6, 8; 12, 14]);
+    assert_eq!((sub - top).unwrap(), matrix![4, 4; 4, 4]);
+    assert_eq!(top.hadamard_product(&sub).unwrap(), matrix![5, 12; 32, 45]);
+    assert_eq!(top.scalar_mul(2), matrix![2, 4; 8, 10]);
+    assert_eq!(
+        top.mat_mul(&sub).unwrap(),
+        (&top.to_matrix() * &sub.to_matrix()).unwrap()
+    );
+}
+
+#[test]
+fn view_bounds_and_shapes_are_checked() {
+    let m: Matrix<f64, 3> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+    assert!(matches!(m.row(2), Err(TensorError::OutOfBounds { .. })));
+    assert!(matches!(m.col(3), Err(TensorError::OutOfBounds { .. })));
+    assert!(matches!(
+        m.submatrix::<2, _>(.., 2),
+        Err(TensorError::OutOfBounds { .. })
+    ));
+    assert!(matches!(
+        m.submatrix::<1, _>(1..3, 0),
+        Err(TensorError::OutOfBounds { .. })
+    ));
+    assert!(matches!(
+        m.row(0).unwrap() + m.col(0).unwrap(),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    let (a, b) = (m.submatrix::<3, _>(..1, 0).unwrap(), m.view());
+    assert!(matches!(a + b, Err(TensorError::DimensionMismatch { .. })));
+    assert!(matches!(
+        a.mat_mul(&b),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn col_mut_updates_in_place() {
+    let mut m: Matrix<f64, 3> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+    let mut col = m.col_mut(1).unwrap();
+    assert_eq!(col.len(), 2);
+    col.scale(2.0);
+    col.axpy(-1.0, &[1.0, 1.0]).unwrap();
+    *col.get_mut(0).unwrap() += 0.5;
+    assert_eq!(col.to_vec(), vec![3.5, 9.0]);
+    assert_eq!(m, matrix![1.0, 3.5, 3.0; 4.0, 9.0, 6.0]);
+
+    // Copying one column into another
+    let first = m.col(0).unwrap().to_vec();
+    m.col_mut(2).unwrap().assign(&first).unwrap();
+    m.col_mut(0).unwrap().fill(0.0);
+    assert_eq!(m, matrix![0.0, 3.5, 1.0; 0.0, 9.0, 4.0]);
+
+    assert!(matches!(m.col_mut(3), Err(TensorError::OutOfBounds { .. })));
+    assert!(matches!(
+        m.col_mut(0).unwrap().axpy(1.0, &[1.0]),
+        Err(TensorError::DimensionMismatch { .. })
+    ));
+    let mut empty: Matrix<f64, 3> = Matrix::new(0);
+    assert!(empty.col_mut(2).unwrap().is_empty());
+    empty.col_mut(2).unwrap().fill(1.0);
+}